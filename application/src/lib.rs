@@ -1,27 +1,170 @@
 pub mod port {
+    pub mod certificates {
+        pub mod certificate_parser_port;
+    }
     pub mod db {
         pub mod banks {
             pub mod bank_repository_port;
         }
         pub mod commerces {
+            pub mod alias_type_repository_port;
             pub mod commerce_repository_port;
         }
+        pub mod countries {
+            pub mod country_repository_port;
+        }
+        pub mod stats {
+            pub mod bank_stats_repository_port;
+        }
+        pub mod users {
+            pub mod user_repository_port;
+        }
+    }
+    pub mod notifications {
+        pub mod notification_port;
     }
 }
 pub mod service {
+    pub mod banks {
+        pub mod apply_due_bank_status_changes_service;
+        pub mod bank_code_format;
+        pub mod change_bank_status_service;
+        pub mod create_bank_service;
+        pub mod get_bank_service;
+        pub mod list_bank_certificates_service;
+        pub mod list_banks_service;
+        pub mod schedule_bank_status_change_service;
+        pub mod update_bank_service;
+        pub mod upload_bank_certificate_service;
+
+        #[cfg(test)]
+        pub mod tests {
+            pub mod mocks;
+            pub mod apply_due_bank_status_changes_service_test;
+            pub mod bank_code_format_test;
+            pub mod change_bank_status_service_test;
+            pub mod create_bank_service_test;
+            pub mod get_bank_service_test;
+            pub mod list_bank_certificates_service_test;
+            pub mod list_banks_service_test;
+            pub mod schedule_bank_status_change_service_test;
+            pub mod update_bank_service_test;
+            pub mod upload_bank_certificate_service_test;
+        }
+    }
     pub mod commerces{
+        pub mod add_commerce_account_service;
+        pub mod alias_type_catalog;
+        pub mod authorize_bank_commerce_access_service;
+        pub mod change_commerce_account_service;
+        pub mod change_commerce_status_service;
+        pub mod commerce_lookup;
+        pub mod create_commerce_branch_service;
         pub mod create_commerce_service;
+        pub mod get_commerce_service;
+        pub mod get_commerce_status_history_service;
+        pub mod list_commerce_branches_service;
+        pub mod list_commerces_service;
+        pub mod promote_commerce_account_service;
+        pub mod remove_commerce_account_service;
+        pub mod resolve_creditor_alias_service;
+        pub mod update_commerce_service;
         pub mod validate_commerce_to_store_service;
+        pub mod validate_commerce_to_update_service;
 
         #[cfg(test)]
         pub mod tests {
+            pub mod mocks;
+            pub mod add_commerce_account_service_test;
+            pub mod alias_type_catalog_test;
+            pub mod authorize_bank_commerce_access_service_test;
+            pub mod change_commerce_account_service_test;
+            pub mod change_commerce_status_service_test;
+            pub mod create_commerce_branch_service_test;
             pub mod create_commerce_service_test;
+            pub mod get_commerce_service_test;
+            pub mod get_commerce_status_history_service_test;
+            pub mod list_commerce_branches_service_test;
+            pub mod list_commerces_service_test;
+            pub mod promote_commerce_account_service_test;
+            pub mod remove_commerce_account_service_test;
+            pub mod resolve_creditor_alias_service_test;
+            pub mod update_commerce_service_test;
+            pub mod validate_commerce_to_store_service_test;
+            pub mod validate_commerce_to_update_service_test;
+        }
+    }
+    pub mod notifications {
+        pub mod notify_bank_service;
+
+        #[cfg(test)]
+        pub mod tests {
+            pub mod mocks;
+            pub mod notify_bank_service_test;
+        }
+    }
+    pub mod stats {
+        pub mod get_bank_stats_service;
+
+        #[cfg(test)]
+        pub mod tests {
+            pub mod mocks;
+            pub mod get_bank_stats_service_test;
+        }
+    }
+    pub mod users {
+        pub mod check_debtor_status_service;
+        pub mod create_user_service;
+        pub mod validate_user_to_store_service;
+
+        #[cfg(test)]
+        pub mod tests {
+            pub mod mocks;
+            pub mod check_debtor_status_service_test;
+            pub mod create_user_service_test;
+            pub mod validate_user_to_store_service_test;
         }
     }
 }
 pub mod use_case {
+    pub mod banks {
+        pub mod apply_due_bank_status_changes_use_case;
+        pub mod change_bank_status_use_case;
+        pub mod create_bank_use_case;
+        pub mod get_bank_use_case;
+        pub mod list_bank_certificates_use_case;
+        pub mod list_banks_use_case;
+        pub mod schedule_bank_status_change_use_case;
+        pub mod update_bank_use_case;
+        pub mod upload_bank_certificate_use_case;
+    }
     pub mod commerces {
+        pub mod add_commerce_account_use_case;
+        pub mod authorize_bank_commerce_access_use_case;
+        pub mod change_commerce_account_use_case;
+        pub mod change_commerce_status_use_case;
+        pub mod create_commerce_branch_use_case;
         pub mod create_commerce_use_case;
+        pub mod get_commerce_use_case;
+        pub mod get_commerce_status_history_use_case;
+        pub mod list_commerce_branches_use_case;
+        pub mod list_commerces_use_case;
+        pub mod promote_commerce_account_use_case;
+        pub mod remove_commerce_account_use_case;
+        pub mod resolve_creditor_alias_use_case;
+        pub mod update_commerce_use_case;
         pub mod validate_commerce_to_store;
+        pub mod validate_commerce_to_update;
     }
-}
+    pub mod notifications {
+        pub mod notify_bank_use_case;
+    }
+    pub mod stats {
+        pub mod get_bank_stats_use_case;
+    }
+    pub mod users {
+        pub mod check_debtor_status_use_case;
+        pub mod create_user_use_case;
+        pub mod validate_user_to_store;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use domain::model::certificate_details::CertificateDetails;
+
+pub trait CertificateParserPort {
+    // None when the text is not a single well formed PEM certificate
+    fn parse_certificate(&self, certificate_pem: &str) -> Option<CertificateDetails>;
+}
\ No newline at end of file
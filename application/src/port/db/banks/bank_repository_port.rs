@@ -1,8 +1,37 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::exception::database_error::DatabaseError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::certificate_details::CertificateDetails;
 
 #[async_trait]
 pub trait BankRepositoryPort {
-    async fn validate_if_bank_exists_exists_by_bank_code(&self, bank_code: &String)
-                                                         -> Result<bool, DatabaseError>;
+    // Banks are returned whatever their status, callers decide whether a disabled bank is valid
+    async fn find_bank_by_code(&self, bank_code: &str) -> Result<Option<Bank>, DatabaseError>;
+    async fn find_banks(&self) -> Result<Vec<Bank>, DatabaseError>;
+    async fn create_bank(&self, bank: &Bank) -> Result<Bank, DatabaseError>;
+    async fn update_bank(&self, bank: &Bank) -> Result<Option<Bank>, DatabaseError>;
+    // Suspends or restores the commerces settling into the bank in the same change
+    async fn change_bank_status(&self, bank_code: &str, current_status: &BankStatus,
+                                new_status: &BankStatus, changed_by: &str)
+        -> Result<Option<Bank>, DatabaseError>;
+    // Stores the certificate as the bank's current one, the certificates already valid keep
+    // working until retire_previous_at so clients can rotate without downtime
+    async fn add_bank_certificate(&self, bank_code: &str, certificate_pem: &str,
+                                  details: &CertificateDetails, retire_previous_at: &DateTime<Utc>)
+        -> Result<Option<BankCertificate>, DatabaseError>;
+    async fn find_valid_bank_certificates(&self, bank_code: &str, at: &DateTime<Utc>)
+        -> Result<Vec<BankCertificate>, DatabaseError>;
+    // Returns None when the bank does not exist
+    async fn schedule_bank_status_change(&self, schedule: &BankStatusSchedule)
+        -> Result<Option<BankStatusSchedule>, DatabaseError>;
+    // Changes not processed yet whose effective date is reached, oldest first
+    async fn find_due_bank_status_schedules(&self, at: &DateTime<Utc>)
+        -> Result<Vec<BankStatusSchedule>, DatabaseError>;
+    // Processed changes are not picked again, applied is false when the change was rejected
+    async fn complete_bank_status_schedule(&self, schedule_id: &i64, applied: &bool)
+        -> Result<(), DatabaseError>;
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::alias_type::AliasType;
+
+#[async_trait]
+pub trait AliasTypeRepositoryPort {
+    async fn find_alias_types(&self) -> Result<Vec<AliasType>, DatabaseError>;
+}
\ No newline at end of file
@@ -1,13 +1,47 @@
 use async_trait::async_trait;
 use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
 use domain::model::commerce::Commerce;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_status_history::CommerceStatusHistory;
 
 #[async_trait]
 pub trait CommerceRepositoryPort {
     async fn create_commerce(&self, commerce: &Commerce) -> Result<Commerce, DatabaseError>;
-    async fn commerce_does_not_exist_by_ruc_and_alias(&self, ruc: &String, alias: &String)
+    async fn commerce_does_not_exist_by_alias(&self, alias: &str)
         -> Result<bool, DatabaseError>;
-    async fn commerce_exists_by_ruc_or_legal_business_name(&self, ruc: &String,
-                                                           legal_business_name: &String)
+    async fn commerce_exists_by_ruc_or_legal_business_name(&self, ruc: &str,
+                                                           legal_business_name: &str)
         -> Result<bool, DatabaseError>;
+    async fn commerce_exists_by_ruc_or_legal_business_name_excluding(&self, commerce_id: &i64,
+                                                                     ruc: &str,
+                                                                     legal_business_name: &str)
+        -> Result<bool, DatabaseError>;
+    async fn find_commerce_by_id(&self, commerce_id: &i64) -> Result<Option<Commerce>, DatabaseError>;
+    // Prefers the active commerce when an inactive one still holds the same alias
+    async fn find_commerce_by_alias(&self, alias: &str) -> Result<Option<Commerce>, DatabaseError>;
+    async fn find_active_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn find_commerces(&self, filter: &CommerceFilter, cursor: &Option<i64>, limit: &i64)
+        -> Result<CommercePage, DatabaseError>;
+    async fn update_commerce(&self, commerce: &Commerce) -> Result<Commerce, DatabaseError>;
+    async fn change_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn add_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn remove_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn promote_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn find_commerce_branches(&self, parent_commerce_id: &i64)
+        -> Result<Vec<Commerce>, DatabaseError>;
+    // When cascading, the active branches of the commerce get the new status in the same change
+    async fn change_commerce_status(&self, commerce_id: &i64, current_status: &CommerceStatus,
+                                    new_status: &CommerceStatus, reason: &str,
+                                    changed_by: &str, cascade_to_branches: &bool)
+        -> Result<Option<Commerce>, DatabaseError>;
+    async fn find_commerce_status_history(&self, commerce_id: &i64)
+        -> Result<Vec<CommerceStatusHistory>, DatabaseError>;
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::country::Country;
+
+#[async_trait]
+pub trait CountryRepositoryPort {
+    async fn find_country_by_code(&self, country_code: &i32)
+        -> Result<Option<Country>, DatabaseError>;
+}
\ No newline at end of file
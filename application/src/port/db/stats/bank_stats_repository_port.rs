@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::bank_stats_filter::BankStatsFilter;
+
+#[async_trait]
+pub trait BankStatsRepositoryPort {
+    // Aggregated by bank, commerce status, alias type and registration month
+    async fn find_bank_commerce_stats(&self, filter: &BankStatsFilter)
+        -> Result<Vec<BankCommerceStats>, DatabaseError>;
+}
\ No newline at end of file
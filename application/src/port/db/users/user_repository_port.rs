@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::user::User;
+
+// PHONE aliases are stored and looked up in their canonical E.164 form
+#[async_trait]
+pub trait UserRepositoryPort {
+    async fn create_user(&self, user: &User) -> Result<User, DatabaseError>;
+    async fn user_does_not_exist_by_alias(&self, alias: &str) -> Result<bool, DatabaseError>;
+    async fn find_user_by_alias(&self, alias: &str) -> Result<Option<User>, DatabaseError>;
+}
\ No newline at end of file
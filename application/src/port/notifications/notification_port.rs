@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::notification::Notification;
+
+#[async_trait]
+pub trait NotificationPort {
+    // Implementations should hand the message off quickly, delivery happens in the background
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
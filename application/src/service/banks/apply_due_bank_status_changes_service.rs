@@ -0,0 +1,76 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::apply_due_bank_status_changes_use_case::ApplyDueBankStatusChangesUseCase;
+use crate::use_case::banks::change_bank_status_use_case::ChangeBankStatusUseCase;
+
+pub struct ApplyDueBankStatusChangesService<BR: BankRepositoryPort, SB: ChangeBankStatusUseCase> {
+    bank_repository: Arc<BR>,
+    change_bank_status_use_case: Arc<SB>
+}
+
+impl<BR: BankRepositoryPort, SB: ChangeBankStatusUseCase> ApplyDueBankStatusChangesService<BR, SB> {
+    pub fn new(bank_repository: Arc<BR>, change_bank_status_use_case: Arc<SB>) -> Self {
+        Self {
+            bank_repository,
+            change_bank_status_use_case
+        }
+    }
+
+    async fn complete_schedule(&self, schedule: &BankStatusSchedule, applied: bool) {
+        if let Err(e) = self.bank_repository
+            .complete_bank_status_schedule(&schedule.schedule_id, &applied)
+            .await {
+            error!("There was an error in the database. Error is: {:?}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, SB> ApplyDueBankStatusChangesUseCase for ApplyDueBankStatusChangesService<BR, SB>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    SB: ChangeBankStatusUseCase + Send + Sync + 'static
+{
+    async fn process(&self, at: DateTime<Utc>) -> Result<usize, Box<dyn StdError + Send + Sync>> {
+        let schedules = match self.bank_repository.find_due_bank_status_schedules(&at).await {
+            Ok(schedules) => schedules,
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into());
+            }
+        };
+
+        let mut applied_changes = 0;
+        for schedule in schedules {
+            info!("Applying scheduled change {} of bank {} to {}", schedule.schedule_id,
+                schedule.bank_code, schedule.new_status.status_name());
+            match self.change_bank_status_use_case
+                .process(schedule.bank_code.clone(), schedule.new_status,
+                         schedule.scheduled_by.clone())
+                .await
+            {
+                Ok(_) => {
+                    self.complete_schedule(&schedule, true).await;
+                    applied_changes += 1;
+                },
+                // A change that is no longer valid is discarded, any other error is retried later
+                Err(e) if e.downcast_ref::<BankError>().is_some() => {
+                    error!("Scheduled change {} of bank {} was rejected. Error is: {:?}",
+                        schedule.schedule_id, schedule.bank_code, e);
+                    self.complete_schedule(&schedule, false).await;
+                },
+                Err(e) => {
+                    error!("Scheduled change {} of bank {} will be retried. Error is: {:?}",
+                        schedule.schedule_id, schedule.bank_code, e);
+                }
+            }
+        }
+        Ok(applied_changes)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::sync::OnceLock;
+use regex::Regex;
+use tracing::error;
+use domain::exception::user_error::UserError;
+
+// Participant bank codes are three digits unless the deployment configures another pattern
+pub const DEFAULT_BANK_CODE_PATTERN: &str = "^[0-9]{3}$";
+
+static BANK_CODE_FORMAT: OnceLock<BankCodeFormat> = OnceLock::new();
+
+pub struct BankCodeFormat {
+    regex: Regex
+}
+
+impl BankCodeFormat {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self { regex: Regex::new(pattern)? })
+    }
+
+    pub fn is_valid(&self, bank_code: &str) -> bool {
+        self.regex.is_match(bank_code)
+    }
+}
+
+// Must be called before the first validation, returns false when a format was already in use
+pub fn configure_bank_code_format(bank_code_format: BankCodeFormat) -> bool {
+    BANK_CODE_FORMAT.set(bank_code_format).is_ok()
+}
+
+pub(crate) fn bank_code_format() -> &'static BankCodeFormat {
+    BANK_CODE_FORMAT.get_or_init(|| BankCodeFormat::new(DEFAULT_BANK_CODE_PATTERN).unwrap())
+}
+
+pub fn validate_debtor_bank_code_format(bank_code: &str) -> Result<(), UserError> {
+    if bank_code.trim().is_empty() {
+        error!("Debtor bank code is empty");
+        return Err(UserError::bank_code_null_or_empty_for_debtor());
+    }
+    if !bank_code_format().is_valid(bank_code) {
+        error!("Invalid debtor bank code: {}", bank_code);
+        return Err(UserError::bank_code_error_value());
+    }
+    Ok(())
+}
\ No newline at end of file
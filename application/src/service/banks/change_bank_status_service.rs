@@ -0,0 +1,71 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::change_bank_status_use_case::ChangeBankStatusUseCase;
+
+pub struct ChangeBankStatusService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> ChangeBankStatusService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+
+    async fn find_current_bank(&self, bank_code: &str)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        match self.bank_repository.find_bank_by_code(bank_code).await {
+            Ok(Some(bank)) => Ok(bank),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> ChangeBankStatusUseCase for ChangeBankStatusService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String, new_status: BankStatus, changed_by: String)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        let current_bank = self.find_current_bank(&bank_code).await?;
+        let current_status = current_bank.bank_status;
+        if !current_status.can_transition_to(&new_status) {
+            error!("Bank {} cannot change status from {} to {}", bank_code,
+                current_status.status_name(), new_status.status_name());
+            return Err(BankError::status_transition_not_allowed().into());
+        }
+
+        info!("Changing status of bank {} from {} to {} by {}", bank_code,
+            current_status.status_name(), new_status.status_name(), changed_by);
+        match self.bank_repository
+            .change_bank_status(&bank_code, &current_status, &new_status, &changed_by)
+            .await
+        {
+            Ok(Some(bank)) => Ok(bank),
+            Ok(None) => {
+                // The status changed between the read and the update
+                error!("Bank {} is no longer {}", bank_code, current_status.status_name());
+                Err(BankError::status_transition_not_allowed().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::service::banks::bank_code_format::bank_code_format;
+use crate::service::commerces::validate_commerce_to_store_service::is_invalid_ruc;
+use crate::use_case::banks::create_bank_use_case::CreateBankUseCase;
+
+// Lengths follow the columns of the banks table
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_MAIL_LENGTH: usize = 50;
+lazy_static! {
+    static ref MAIL_REGEX: Regex = {
+        Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap()
+    };
+
+    static ref PHONE_REGEX: Regex = {
+        Regex::new(r"^\+?[0-9]{7,14}$").unwrap()
+    };
+}
+
+pub struct CreateBankService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> CreateBankService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+
+    async fn validate_bank_code_is_unique(&self, bank_code: &str)
+        -> Result<(), Box<dyn StdError + Send + Sync>> {
+        match self.bank_repository.find_bank_by_code(bank_code).await {
+            Ok(None) => Ok(()),
+            Ok(Some(_)) => {
+                error!("Bank code '{}' already exists", bank_code);
+                Err(BankError::bank_code_already_exists().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> CreateBankUseCase for CreateBankService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank: Bank) -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        let mut bank_to_store = normalize_bank_fields(bank);
+        info!("Validating bank field formats for bank {:?}", bank_to_store);
+        validate_bank_field_formats(&bank_to_store)?;
+        self.validate_bank_code_is_unique(&bank_to_store.bank_code).await?;
+
+        // New banks go through onboarding before they can settle payments
+        bank_to_store.bank_status = BankStatus::Pending;
+        info!("Creating bank: {:?}", bank_to_store);
+        match self.bank_repository.create_bank(&bank_to_store).await {
+            Ok(bank_created) => Ok(bank_created),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+pub(crate) fn normalize_bank_fields(bank: Bank) -> Bank {
+    Bank {
+        bank_name: bank.bank_name.trim().to_string(),
+        bank_code: bank.bank_code.trim().to_string(),
+        contact_name: bank.contact_name.trim().to_string(),
+        contact_mail: bank.contact_mail.trim().to_lowercase(),
+        notification_mail: bank.notification_mail.trim().to_lowercase(),
+        contact_phone: bank.contact_phone.trim().to_string(),
+        bank_ruc: bank.bank_ruc.trim().to_string(),
+        ..bank
+    }
+}
+
+pub(crate) fn validate_bank_field_formats(bank: &Bank) -> Result<(), BankError> {
+    // Same format the commerce, account and debtor validations expect
+    if !bank_code_format().is_valid(&bank.bank_code) {
+        error!("Invalid bank code: {}", bank.bank_code);
+        return Err(BankError::not_valid_bank_code());
+    }
+    if is_invalid_name(&bank.bank_name) {
+        error!("Invalid bank name: {}", bank.bank_name);
+        return Err(BankError::not_valid_bank_name());
+    }
+    if is_invalid_name(&bank.contact_name) {
+        error!("Invalid contact name: {}", bank.contact_name);
+        return Err(BankError::not_valid_contact_name());
+    }
+    if is_invalid_mail(&bank.contact_mail) || is_invalid_mail(&bank.notification_mail) {
+        error!("Invalid contact mail: {} or notification mail: {}", bank.contact_mail,
+            bank.notification_mail);
+        return Err(BankError::not_valid_bank_mail());
+    }
+    if !PHONE_REGEX.is_match(&bank.contact_phone) {
+        error!("Invalid bank phone: {}", bank.contact_phone);
+        return Err(BankError::not_valid_bank_phone());
+    }
+    if is_invalid_ruc(&bank.bank_ruc) {
+        error!("Invalid bank RUC: {}", bank.bank_ruc);
+        return Err(BankError::not_valid_bank_ruc());
+    }
+    Ok(())
+}
+
+fn is_invalid_name(name: &str) -> bool {
+    name.is_empty() || name.len() > MAX_NAME_LENGTH
+}
+
+fn is_invalid_mail(mail: &str) -> bool {
+    mail.len() > MAX_MAIL_LENGTH || !MAIL_REGEX.is_match(mail)
+}
\ No newline at end of file
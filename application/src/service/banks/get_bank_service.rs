@@ -0,0 +1,41 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::get_bank_use_case::GetBankUseCase;
+
+pub struct GetBankService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> GetBankService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> GetBankUseCase for GetBankService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String) -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        info!("Looking for bank with code: {}", bank_code);
+        match self.bank_repository.find_bank_by_code(&bank_code).await {
+            Ok(Some(bank)) => Ok(bank),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_certificate::BankCertificate;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::list_bank_certificates_use_case::ListBankCertificatesUseCase;
+
+pub struct ListBankCertificatesService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> ListBankCertificatesService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> ListBankCertificatesUseCase for ListBankCertificatesService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String)
+        -> Result<Vec<BankCertificate>, Box<dyn StdError + Send + Sync>> {
+        match self.bank_repository.find_bank_by_code(&bank_code).await {
+            Ok(Some(_)) => {},
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                return Err(BankError::not_found().into());
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into());
+            }
+        }
+
+        info!("Listing valid certificates of bank {}", bank_code);
+        match self.bank_repository.find_valid_bank_certificates(&bank_code, &Utc::now()).await {
+            Ok(certificates) => Ok(certificates),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::model::bank::Bank;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::list_banks_use_case::ListBanksUseCase;
+
+pub struct ListBanksService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> ListBanksService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> ListBanksUseCase for ListBanksService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self) -> Result<Vec<Bank>, Box<dyn StdError + Send + Sync>> {
+        info!("Listing registered banks");
+        match self.bank_repository.find_banks().await {
+            Ok(banks) => Ok(banks),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
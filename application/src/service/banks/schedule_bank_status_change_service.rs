@@ -0,0 +1,61 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::schedule_bank_status_change_use_case::ScheduleBankStatusChangeUseCase;
+
+pub struct ScheduleBankStatusChangeService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> ScheduleBankStatusChangeService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> ScheduleBankStatusChangeUseCase for ScheduleBankStatusChangeService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String, new_status: BankStatus,
+                     effective_at: DateTime<Utc>, scheduled_by: String)
+        -> Result<BankStatusSchedule, Box<dyn StdError + Send + Sync>> {
+        if effective_at <= Utc::now() {
+            error!("Status change of bank {} is effective at {}, which is not in the future",
+                bank_code, effective_at);
+            return Err(BankError::not_valid_effective_date().into());
+        }
+        // The bank may go through other states before the date, so the transition itself is
+        // checked when the change is applied. Only PENDING can never be reached again.
+        if new_status == BankStatus::Pending {
+            error!("Bank {} cannot be scheduled to go back to {}", bank_code,
+                new_status.status_name());
+            return Err(BankError::status_transition_not_allowed().into());
+        }
+
+        info!("Scheduling status of bank {} to change to {} at {} by {}", bank_code,
+            new_status.status_name(), effective_at, scheduled_by);
+        let schedule = BankStatusSchedule::new(0, bank_code.clone(), new_status, effective_at,
+                                               scheduled_by);
+        match self.bank_repository.schedule_bank_status_change(&schedule).await {
+            Ok(Some(schedule_stored)) => Ok(schedule_stored),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use chrono::{TimeZone, Utc};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use crate::service::banks::apply_due_bank_status_changes_service::ApplyDueBankStatusChangesService;
+use crate::use_case::banks::apply_due_bank_status_changes_use_case::ApplyDueBankStatusChangesUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, create_bank_status_schedule_for_test,
+                                          MockBankRepo, MockChangeBankStatus};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository_with_due_schedule() -> MockBankRepo {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_due_bank_status_schedules()
+            .returning(|_| Ok(vec![create_bank_status_schedule_for_test()]))
+            .times(1);
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_process_applies_due_change() {
+        let mut repository = repository_with_due_schedule();
+        repository
+            .expect_complete_bank_status_schedule()
+            .with(eq(7), eq(true))
+            .returning(|_, _| Ok(()))
+            .times(1);
+        let mut change_bank_status = MockChangeBankStatus::new();
+        change_bank_status
+            .expect_process()
+            .with(eq(String::from("001")), eq(BankStatus::Enabled), eq(String::from("ops")))
+            .returning(|_, _, _| Ok(create_bank_for_test()))
+            .times(1);
+
+        let service = ApplyDueBankStatusChangesService::new(Arc::new(repository),
+                                                            Arc::new(change_bank_status));
+
+        let applied = service.process(Utc.with_ymd_and_hms(2025, 9, 1, 9, 0, 0).unwrap()).await;
+
+        assert_eq!(applied.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_process_rejected_change_is_discarded() {
+        let mut repository = repository_with_due_schedule();
+        repository
+            .expect_complete_bank_status_schedule()
+            .with(eq(7), eq(false))
+            .returning(|_, _| Ok(()))
+            .times(1);
+        let mut change_bank_status = MockChangeBankStatus::new();
+        change_bank_status
+            .expect_process()
+            .returning(|_, _, _| Err(BankError::status_transition_not_allowed().into()))
+            .times(1);
+
+        let service = ApplyDueBankStatusChangesService::new(Arc::new(repository),
+                                                            Arc::new(change_bank_status));
+
+        let applied = service.process(Utc::now()).await;
+
+        assert_eq!(applied.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error_is_retried() {
+        let mut repository = repository_with_due_schedule();
+        repository.expect_complete_bank_status_schedule().times(0);
+        let mut change_bank_status = MockChangeBankStatus::new();
+        change_bank_status
+            .expect_process()
+            .returning(|_, _, _| Err(database_error_for_test().into()))
+            .times(1);
+
+        let service = ApplyDueBankStatusChangesService::new(Arc::new(repository),
+                                                            Arc::new(change_bank_status));
+
+        let applied = service.process(Utc::now()).await;
+
+        assert_eq!(applied.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_error_finding_due_changes() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_due_bank_status_schedules()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+        let mut change_bank_status = MockChangeBankStatus::new();
+        change_bank_status.expect_process().times(0);
+
+        let service = ApplyDueBankStatusChangesService::new(Arc::new(repository),
+                                                            Arc::new(change_bank_status));
+
+        let result = service.process(Utc::now()).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
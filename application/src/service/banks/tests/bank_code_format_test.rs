@@ -0,0 +1,50 @@
+use domain::exception::user_error::UserError;
+use crate::service::banks::bank_code_format::{validate_debtor_bank_code_format, BankCodeFormat,
+                                              DEFAULT_BANK_CODE_PATTERN};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_format_accepts_three_digits() {
+        let bank_code_format = BankCodeFormat::new(DEFAULT_BANK_CODE_PATTERN).unwrap();
+
+        assert!(bank_code_format.is_valid("001"));
+        assert!(!bank_code_format.is_valid("01"));
+        assert!(!bank_code_format.is_valid("0001"));
+        assert!(!bank_code_format.is_valid("BOA"));
+    }
+
+    #[test]
+    fn test_custom_format() {
+        let bank_code_format = BankCodeFormat::new("^[A-Z]{3}[0-9]{3}$").unwrap();
+
+        assert!(bank_code_format.is_valid("BOA001"));
+        assert!(!bank_code_format.is_valid("001"));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(BankCodeFormat::new("^[0-9").is_err());
+    }
+
+    #[test]
+    fn test_validate_debtor_bank_code_success() {
+        assert!(validate_debtor_bank_code_format(&String::from("841")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_debtor_bank_code_empty() {
+        let error = validate_debtor_bank_code_format(&String::from(" ")).unwrap_err();
+
+        assert_eq!(error.get_code(), UserError::bank_code_null_or_empty_for_debtor().get_code());
+    }
+
+    #[test]
+    fn test_validate_debtor_bank_code_invalid_format() {
+        let error = validate_debtor_bank_code_format(&String::from("84-1")).unwrap_err();
+
+        assert_eq!(error.get_code(), "ERR-087");
+    }
+}
\ No newline at end of file
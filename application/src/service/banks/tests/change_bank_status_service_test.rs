@@ -0,0 +1,148 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use crate::service::banks::change_bank_status_service::ChangeBankStatusService;
+use crate::use_case::banks::change_bank_status_use_case::ChangeBankStatusUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_disable_success() {
+        let mut disabled_bank = create_bank_for_test();
+        disabled_bank.bank_status = BankStatus::Suspended;
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_change_bank_status()
+            .with(eq(String::from("001")), eq(BankStatus::Enabled), eq(BankStatus::Suspended),
+                  eq(String::from("ops")))
+            .returning(move |_, _, _, _| Ok(Some(disabled_bank.clone())))
+            .times(1);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let result = service
+            .process(String::from("001"), BankStatus::Suspended, String::from("ops"))
+            .await;
+
+        assert_eq!(result.unwrap().bank_status, BankStatus::Suspended);
+    }
+
+    #[tokio::test]
+    async fn test_process_enable_success() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| {
+                let mut disabled_bank = create_bank_for_test();
+                disabled_bank.bank_status = BankStatus::Suspended;
+                Ok(Some(disabled_bank))
+            })
+            .times(1);
+        repository
+            .expect_change_bank_status()
+            .with(eq(String::from("001")), eq(BankStatus::Suspended), eq(BankStatus::Enabled),
+                  eq(String::from("ops")))
+            .returning(|_, _, _, _| Ok(Some(create_bank_for_test())))
+            .times(1);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let result = service
+            .process(String::from("001"), BankStatus::Enabled, String::from("ops"))
+            .await;
+
+        assert_eq!(result.unwrap().bank_status, BankStatus::Enabled);
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_change_bank_status().times(0);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("999"), BankStatus::Suspended, String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_transition_not_allowed() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository.expect_change_bank_status().times(0);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("001"), BankStatus::Enabled, String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-108");
+    }
+
+    #[tokio::test]
+    async fn test_process_status_changed_concurrently() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_change_bank_status()
+            .returning(|_, _, _, _| Ok(None))
+            .times(1);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("001"), BankStatus::Suspended, String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-108");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_change_bank_status()
+            .returning(|_, _, _, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ChangeBankStatusService::new(Arc::new(repository));
+
+        let result = service
+            .process(String::from("001"), BankStatus::Suspended, String::from("ops"))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
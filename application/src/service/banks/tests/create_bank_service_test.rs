@@ -0,0 +1,102 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use crate::service::banks::create_bank_service::CreateBankService;
+use crate::use_case::banks::create_bank_use_case::CreateBankUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut input_bank = create_bank_for_test();
+        input_bank.contact_mail = String::from("  John.Doe@BOA.com ");
+        input_bank.bank_status = BankStatus::Suspended;
+        let mut expected_bank = create_bank_for_test();
+        expected_bank.bank_status = BankStatus::Pending;
+        let created_bank = expected_bank.clone();
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(None))
+            .times(1);
+        repository
+            .expect_create_bank()
+            .with(eq(expected_bank))
+            .returning(move |_| Ok(created_bank.clone()))
+            .times(1);
+
+        let service = CreateBankService::new(Arc::new(repository));
+
+        let result = service.process(input_bank).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().bank_status, BankStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_code_already_exists() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository.expect_create_bank().times(0);
+
+        let service = CreateBankService::new(Arc::new(repository));
+
+        let error = service.process(create_bank_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-102");
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_fields() {
+        let invalid_banks = [
+            ("ERR-087", { let mut bank = create_bank_for_test(); bank.bank_code = String::from("00-1"); bank }),
+            ("ERR-087", { let mut bank = create_bank_for_test(); bank.bank_code = String::from("BANK1"); bank }),
+            ("ERR-104", { let mut bank = create_bank_for_test(); bank.bank_name = String::from(" "); bank }),
+            ("ERR-121", { let mut bank = create_bank_for_test(); bank.contact_name = String::from(" "); bank }),
+            ("ERR-105", { let mut bank = create_bank_for_test(); bank.notification_mail = String::from("boa.com"); bank }),
+            ("ERR-106", { let mut bank = create_bank_for_test(); bank.contact_phone = String::from("12-34"); bank }),
+            ("ERR-107", { let mut bank = create_bank_for_test(); bank.bank_ruc = String::from("RUC1"); bank }),
+        ];
+
+        for (expected_code, invalid_bank) in invalid_banks {
+            let mut repository = MockBankRepo::new();
+            repository.expect_find_bank_by_code().times(0);
+            repository.expect_create_bank().times(0);
+
+            let service = CreateBankService::new(Arc::new(repository));
+
+            let error = service.process(invalid_bank).await.unwrap_err();
+
+            assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), expected_code);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository
+            .expect_create_bank()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = CreateBankService::new(Arc::new(repository));
+
+        let result = service.process(create_bank_for_test()).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
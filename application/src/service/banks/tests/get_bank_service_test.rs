@@ -0,0 +1,58 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use crate::service::banks::get_bank_service::GetBankService;
+use crate::use_case::banks::get_bank_use_case::GetBankUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+
+        let service = GetBankService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001")).await;
+
+        assert_eq!(result.unwrap(), create_bank_for_test());
+    }
+
+    #[tokio::test]
+    async fn test_process_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = GetBankService::new(Arc::new(repository));
+
+        let error = service.process(String::from("999")).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = GetBankService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001")).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use crate::service::banks::list_bank_certificates_service::ListBankCertificatesService;
+use crate::use_case::banks::list_bank_certificates_use_case::ListBankCertificatesUseCase;
+use crate::service::banks::tests::mocks::{create_bank_certificate_for_test, create_bank_for_test,
+                                          MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let certificate = create_bank_certificate_for_test();
+        let expected = vec![certificate.clone()];
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .withf(|bank_code, _| bank_code == "001")
+            .returning(move |_, _| Ok(vec![certificate.clone()]))
+            .times(1);
+
+        let service = ListBankCertificatesService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001")).await;
+
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_find_valid_bank_certificates().times(0);
+
+        let service = ListBankCertificatesService::new(Arc::new(repository));
+
+        let error = service.process(String::from("999")).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .returning(|_, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ListBankCertificatesService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001")).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
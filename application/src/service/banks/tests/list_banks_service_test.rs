@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::service::banks::list_banks_service::ListBanksService;
+use crate::use_case::banks::list_banks_use_case::ListBanksUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(|| Ok(vec![create_bank_for_test()]))
+            .times(1);
+
+        let service = ListBanksService::new(Arc::new(repository));
+
+        let result = service.process().await;
+
+        assert_eq!(result.unwrap(), vec![create_bank_for_test()]);
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(|| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ListBanksService::new(Arc::new(repository));
+
+        let result = service.process().await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
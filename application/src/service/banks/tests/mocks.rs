@@ -0,0 +1,114 @@
+use std::error::Error;
+use mockall::mock;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::certificate_details::CertificateDetails;
+use crate::port::certificates::certificate_parser_port::CertificateParserPort;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::change_bank_status_use_case::ChangeBankStatusUseCase;
+
+// Mocks shared by the bank and commerce service tests
+mock! {
+    pub BankRepo {}
+
+    #[async_trait]
+    impl BankRepositoryPort for BankRepo {
+        async fn find_bank_by_code(&self, bank_code: &str) -> Result<Option<Bank>, DatabaseError>;
+
+        async fn find_banks(&self) -> Result<Vec<Bank>, DatabaseError>;
+
+        async fn create_bank(&self, bank: &Bank) -> Result<Bank, DatabaseError>;
+
+        async fn update_bank(&self, bank: &Bank) -> Result<Option<Bank>, DatabaseError>;
+
+        async fn change_bank_status(&self, bank_code: &str, current_status: &BankStatus,
+                                    new_status: &BankStatus, changed_by: &str)
+        -> Result<Option<Bank>, DatabaseError>;
+
+        async fn add_bank_certificate(&self, bank_code: &str, certificate_pem: &str,
+                                      details: &CertificateDetails, retire_previous_at: &DateTime<Utc>)
+        -> Result<Option<BankCertificate>, DatabaseError>;
+
+        async fn find_valid_bank_certificates(&self, bank_code: &str, at: &DateTime<Utc>)
+        -> Result<Vec<BankCertificate>, DatabaseError>;
+
+        async fn schedule_bank_status_change(&self, schedule: &BankStatusSchedule)
+        -> Result<Option<BankStatusSchedule>, DatabaseError>;
+
+        async fn find_due_bank_status_schedules(&self, at: &DateTime<Utc>)
+        -> Result<Vec<BankStatusSchedule>, DatabaseError>;
+
+        async fn complete_bank_status_schedule(&self, schedule_id: &i64, applied: &bool)
+        -> Result<(), DatabaseError>;
+    }
+}
+
+mock! {
+    pub ChangeBankStatus {}
+
+    #[async_trait]
+    impl ChangeBankStatusUseCase for ChangeBankStatus {
+        async fn process(&self, bank_code: String, new_status: BankStatus, changed_by: String)
+        -> Result<Bank, Box<dyn Error + Send + Sync>>;
+    }
+}
+
+mock! {
+    pub CertificateParser {}
+
+    impl CertificateParserPort for CertificateParser {
+        fn parse_certificate(&self, certificate_pem: &str) -> Option<CertificateDetails>;
+    }
+}
+
+pub fn create_bank_for_test() -> Bank {
+    Bank::new(
+        String::from("Bank of America"),
+        String::from("001"),
+        String::from("John Doe"),
+        String::from("john.doe@boa.com"),
+        String::from("notifications@boa.com"),
+        String::from("1234567890"),
+        String::from("12345678901"),
+        BankStatus::Enabled,
+        String::new()
+    )
+}
+
+pub fn create_certificate_details_for_test() -> CertificateDetails {
+    CertificateDetails::new(
+        String::from("CN=api.boa.com, O=Bank of America"),
+        String::from("AB:CD:EF"),
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2099, 1, 1, 0, 0, 0).unwrap()
+    )
+}
+
+pub fn create_bank_certificate_for_test() -> BankCertificate {
+    let details = create_certificate_details_for_test();
+    BankCertificate::new(
+        1,
+        String::from("001"),
+        details.subject,
+        details.fingerprint,
+        details.not_before,
+        details.not_after,
+        Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        None
+    )
+}
+
+pub fn create_bank_status_schedule_for_test() -> BankStatusSchedule {
+    BankStatusSchedule::new(
+        7,
+        String::from("001"),
+        BankStatus::Enabled,
+        Utc.with_ymd_and_hms(2025, 9, 1, 9, 0, 0).unwrap(),
+        String::from("ops")
+    )
+}
\ No newline at end of file
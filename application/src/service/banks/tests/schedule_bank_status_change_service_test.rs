@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use crate::service::banks::schedule_bank_status_change_service::ScheduleBankStatusChangeService;
+use crate::use_case::banks::schedule_bank_status_change_use_case::ScheduleBankStatusChangeUseCase;
+use crate::service::banks::tests::mocks::MockBankRepo;
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let effective_at = Utc::now() + Duration::days(1);
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_schedule_bank_status_change()
+            .withf(move |schedule| schedule.bank_code == "001"
+                && schedule.new_status == BankStatus::Enabled
+                && schedule.effective_at == effective_at
+                && schedule.scheduled_by == "ops")
+            .returning(|schedule| {
+                let mut schedule_stored = schedule.clone();
+                schedule_stored.schedule_id = 7;
+                Ok(Some(schedule_stored))
+            })
+            .times(1);
+
+        let service = ScheduleBankStatusChangeService::new(Arc::new(repository));
+
+        let schedule = service
+            .process(String::from("001"), BankStatus::Enabled, effective_at, String::from("ops"))
+            .await
+            .unwrap();
+
+        assert_eq!(schedule.schedule_id, 7);
+        assert_eq!(schedule.effective_at, effective_at);
+    }
+
+    #[tokio::test]
+    async fn test_process_effective_date_in_the_past() {
+        let mut repository = MockBankRepo::new();
+        repository.expect_schedule_bank_status_change().times(0);
+
+        let service = ScheduleBankStatusChangeService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("001"), BankStatus::Enabled, Utc::now() - Duration::hours(1),
+                     String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-113");
+    }
+
+    #[tokio::test]
+    async fn test_process_back_to_pending_not_allowed() {
+        let mut repository = MockBankRepo::new();
+        repository.expect_schedule_bank_status_change().times(0);
+
+        let service = ScheduleBankStatusChangeService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("001"), BankStatus::Pending, Utc::now() + Duration::days(1),
+                     String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-108");
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_schedule_bank_status_change()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = ScheduleBankStatusChangeService::new(Arc::new(repository));
+
+        let error = service
+            .process(String::from("999"), BankStatus::Enabled, Utc::now() + Duration::days(1),
+                     String::from("ops"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_schedule_bank_status_change()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ScheduleBankStatusChangeService::new(Arc::new(repository));
+
+        let result = service
+            .process(String::from("001"), BankStatus::Enabled, Utc::now() + Duration::days(1),
+                     String::from("ops"))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
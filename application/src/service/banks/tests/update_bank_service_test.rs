@@ -0,0 +1,98 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+use crate::service::banks::update_bank_service::UpdateBankService;
+use crate::use_case::banks::update_bank_use_case::UpdateBankUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut bank_update = create_bank_for_test();
+        bank_update.bank_code = String::new();
+        bank_update.bank_name = String::from("Bank of America Peru");
+        bank_update.bank_status = BankStatus::Enabled;
+        let mut expected_bank = create_bank_for_test();
+        expected_bank.bank_name = String::from("Bank of America Peru");
+        expected_bank.bank_status = BankStatus::Suspended;
+        let updated_bank = expected_bank.clone();
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| {
+                let mut current_bank = create_bank_for_test();
+                current_bank.bank_status = BankStatus::Suspended;
+                Ok(Some(current_bank))
+            })
+            .times(1);
+        repository
+            .expect_update_bank()
+            .with(eq(expected_bank))
+            .returning(move |_| Ok(Some(updated_bank.clone())))
+            .times(1);
+
+        let service = UpdateBankService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001"), bank_update).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_update_bank().times(0);
+
+        let service = UpdateBankService::new(Arc::new(repository));
+
+        let error = service.process(String::from("999"), create_bank_for_test()).await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_code_cannot_be_modified() {
+        let mut bank_update = create_bank_for_test();
+        bank_update.bank_code = String::from("002");
+
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository.expect_update_bank().times(0);
+
+        let service = UpdateBankService::new(Arc::new(repository));
+
+        let error = service.process(String::from("001"), bank_update).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-103");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = UpdateBankService::new(Arc::new(repository));
+
+        let result = service.process(String::from("001"), create_bank_for_test()).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
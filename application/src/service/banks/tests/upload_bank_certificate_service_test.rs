@@ -0,0 +1,246 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use domain::exception::bank_error::BankError;
+use crate::service::banks::upload_bank_certificate_service::UploadBankCertificateService;
+use crate::use_case::banks::upload_bank_certificate_use_case::UploadBankCertificateUseCase;
+use crate::service::banks::tests::mocks::{create_bank_certificate_for_test, create_bank_for_test,
+                                          create_certificate_details_for_test, MockBankRepo,
+                                          MockCertificateParser};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pem_for_test() -> String {
+        String::from("-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n")
+    }
+
+    #[tokio::test]
+    async fn test_process_success_with_default_cutover() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .returning(|_, _| Ok(vec![]))
+            .times(1);
+        repository
+            .expect_add_bank_certificate()
+            .withf(|bank_code, pem, details, retire_previous_at| {
+                let expected = Utc::now() + Duration::hours(72);
+                bank_code == "001"
+                    && *pem == pem_for_test()
+                    && *details == create_certificate_details_for_test()
+                    && (expected - *retire_previous_at).num_seconds().abs() < 60
+            })
+            .returning(|_, _, _, _| Ok(Some(create_bank_certificate_for_test())))
+            .times(1);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .with(eq(pem_for_test()))
+            .returning(|_| Some(create_certificate_details_for_test()))
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let result = service.process(String::from("001"), pem_for_test(), None).await;
+
+        assert_eq!(result.unwrap().bank_code, "001");
+    }
+
+    #[tokio::test]
+    async fn test_process_success_with_custom_cutover() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .returning(|_, _| Ok(vec![create_bank_certificate_for_test()]))
+            .times(1);
+        repository
+            .expect_add_bank_certificate()
+            .withf(|_, _, _, retire_previous_at| {
+                let expected = Utc::now() + Duration::hours(2);
+                (expected - *retire_previous_at).num_seconds().abs() < 60
+            })
+            .returning(|_, _, _, _| Ok(Some(create_bank_certificate_for_test())))
+            .times(1);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .returning(|_| {
+                let mut details = create_certificate_details_for_test();
+                details.fingerprint = String::from("01:02:03");
+                Some(details)
+            })
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let result = service.process(String::from("001"), pem_for_test(), Some(2)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_add_bank_certificate().times(0);
+
+        let mut parser = MockCertificateParser::new();
+        parser.expect_parse_certificate().times(0);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let error = service
+            .process(String::from("999"), pem_for_test(), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_malformed_certificate() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository.expect_add_bank_certificate().times(0);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .returning(|_| None)
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let error = service
+            .process(String::from("001"), String::from("not a certificate"), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-109");
+    }
+
+    #[tokio::test]
+    async fn test_process_expired_certificate() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository.expect_add_bank_certificate().times(0);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .returning(|_| {
+                let mut details = create_certificate_details_for_test();
+                details.not_after = Utc::now() - Duration::days(1);
+                Some(details)
+            })
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let error = service
+            .process(String::from("001"), pem_for_test(), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-110");
+    }
+
+    #[tokio::test]
+    async fn test_process_certificate_already_registered() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .returning(|_, _| Ok(vec![create_bank_certificate_for_test()]))
+            .times(1);
+        repository.expect_add_bank_certificate().times(0);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .returning(|_| Some(create_certificate_details_for_test()))
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let error = service
+            .process(String::from("001"), pem_for_test(), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-111");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        repository
+            .expect_find_valid_bank_certificates()
+            .returning(|_, _| Ok(vec![]))
+            .times(1);
+        repository
+            .expect_add_bank_certificate()
+            .returning(|_, _, _, _| Err(database_error_for_test()))
+            .times(1);
+
+        let mut parser = MockCertificateParser::new();
+        parser
+            .expect_parse_certificate()
+            .returning(|_| Some(create_certificate_details_for_test()))
+            .times(1);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let result = service.process(String::from("001"), pem_for_test(), None).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_cutover_over_maximum_does_not_overflow() {
+        let mut repository = MockBankRepo::new();
+        repository.expect_find_bank_by_code().times(0);
+        repository.expect_add_bank_certificate().times(0);
+        let mut parser = MockCertificateParser::new();
+        parser.expect_parse_certificate().times(0);
+
+        let service = UploadBankCertificateService::new(Arc::new(repository), Arc::new(parser));
+
+        let error = service
+            .process(String::from("001"), pem_for_test(), Some(u32::MAX))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-118");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::service::banks::create_bank_service::{normalize_bank_fields, validate_bank_field_formats};
+use crate::use_case::banks::update_bank_use_case::UpdateBankUseCase;
+
+pub struct UpdateBankService<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>
+}
+
+impl<BR: BankRepositoryPort> UpdateBankService<BR> {
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository
+        }
+    }
+
+    async fn find_current_bank(&self, bank_code: &str)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        match self.bank_repository.find_bank_by_code(bank_code).await {
+            Ok(Some(bank)) => Ok(bank),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR> UpdateBankUseCase for UpdateBankService<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String, bank: Bank)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        let current_bank = self.find_current_bank(&bank_code).await?;
+
+        // Accounts keep a copy of the bank code, so it cannot change once the bank is registered
+        let mut bank_to_update = normalize_bank_fields(bank);
+        if !bank_to_update.bank_code.is_empty() && bank_to_update.bank_code != current_bank.bank_code {
+            error!("Bank code '{}' cannot be changed to '{}'", current_bank.bank_code,
+                bank_to_update.bank_code);
+            return Err(BankError::bank_code_cannot_be_modified().into());
+        }
+        bank_to_update.bank_code = current_bank.bank_code;
+        bank_to_update.bank_status = current_bank.bank_status;
+        bank_to_update.ocp_cert_mtls = current_bank.ocp_cert_mtls;
+
+        info!("Validating bank field formats for bank {:?}", bank_to_update);
+        validate_bank_field_formats(&bank_to_update)?;
+        info!("Updating bank: {:?}", bank_to_update);
+        match self.bank_repository.update_bank(&bank_to_update).await {
+            Ok(Some(updated_bank)) => Ok(updated_bank),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
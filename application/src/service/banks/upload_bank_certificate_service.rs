@@ -0,0 +1,108 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use crate::port::certificates::certificate_parser_port::CertificateParserPort;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::use_case::banks::upload_bank_certificate_use_case::UploadBankCertificateUseCase;
+
+// Hours the previous certificates stay valid after a new one is uploaded
+pub const DEFAULT_CUTOVER_HOURS: u32 = 72;
+// Longest cutover a bank can ask for, 30 days
+pub const MAX_CUTOVER_HOURS: u32 = 720;
+
+pub struct UploadBankCertificateService<BR: BankRepositoryPort, CP: CertificateParserPort> {
+    bank_repository: Arc<BR>,
+    certificate_parser: Arc<CP>
+}
+
+impl<BR: BankRepositoryPort, CP: CertificateParserPort> UploadBankCertificateService<BR, CP> {
+    pub fn new(bank_repository: Arc<BR>, certificate_parser: Arc<CP>) -> Self {
+        Self {
+            bank_repository,
+            certificate_parser
+        }
+    }
+
+    async fn find_current_bank(&self, bank_code: &str)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+        match self.bank_repository.find_bank_by_code(bank_code).await {
+            Ok(Some(bank)) => Ok(bank),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CP> UploadBankCertificateUseCase for UploadBankCertificateService<BR, CP>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CP: CertificateParserPort + Send + Sync + 'static
+{
+    async fn process(&self, bank_code: String, certificate_pem: String, cutover_hours: Option<u32>)
+        -> Result<BankCertificate, Box<dyn StdError + Send + Sync>> {
+        let cutover = cutover_hours.unwrap_or(DEFAULT_CUTOVER_HOURS);
+        if cutover > MAX_CUTOVER_HOURS {
+            error!("Cutover of {} hours for bank {} exceeds the maximum of {}", cutover, bank_code,
+                MAX_CUTOVER_HOURS);
+            return Err(BankError::not_valid_cutover_hours().into());
+        }
+        self.find_current_bank(&bank_code).await?;
+
+        let details = match self.certificate_parser.parse_certificate(&certificate_pem) {
+            Some(details) => details,
+            None => return Err(BankError::not_valid_certificate().into())
+        };
+        let now = Utc::now();
+        if details.is_expired_at(&now) {
+            error!("Certificate {} for bank {} expired at {}", details.fingerprint, bank_code,
+                details.not_after);
+            return Err(BankError::certificate_expired().into());
+        }
+
+        let valid_certificates = match self.bank_repository
+            .find_valid_bank_certificates(&bank_code, &now)
+            .await
+        {
+            Ok(certificates) => certificates,
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into());
+            }
+        };
+        if valid_certificates.iter().any(|c| c.fingerprint == details.fingerprint) {
+            error!("Certificate {} is already registered for bank {}", details.fingerprint,
+                bank_code);
+            return Err(BankError::certificate_already_registered().into());
+        }
+
+        let retire_previous_at = now + Duration::hours(cutover as i64);
+        info!("Uploading certificate {} for bank {}, previous certificates retire at {}",
+            details.fingerprint, bank_code, retire_previous_at);
+        match self.bank_repository
+            .add_bank_certificate(&bank_code, &certificate_pem, &details, &retire_previous_at)
+            .await
+        {
+            Ok(Some(certificate)) => Ok(certificate),
+            Ok(None) => {
+                error!("Bank with code '{}' does not exist", bank_code);
+                Err(BankError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::service::commerces::validate_commerce_to_store_service::{validate_account_number_format,
+                                                                    validate_bank_code_format,
+                                                                    validate_creditor_bank_is_active};
+use crate::use_case::commerces::add_commerce_account_use_case::AddCommerceAccountUseCase;
+
+pub struct AddCommerceAccountService<BR: BankRepositoryPort, CR: CommerceRepositoryPort> {
+    bank_repository: Arc<BR>,
+    commerce_repository: Arc<CR>
+}
+
+impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> AddCommerceAccountService<BR, CR> {
+    pub fn new(bank_repository: Arc<BR>, commerce_repository: Arc<CR>) -> Self {
+        Self {
+            bank_repository,
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CR> AddCommerceAccountUseCase for AddCommerceAccountService<BR, CR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, account: Account)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let mut account = Account::new(0, account.account_number.trim().to_string(),
+                                       account.bank_code.trim().to_string(), 0);
+        info!("Validating new account format for commerce {}", commerce_id);
+        validate_account_number_format(&account.account_number)?;
+        validate_bank_code_format(&account.bank_code)?;
+
+        find_existing_commerce(self.commerce_repository.as_ref(), commerce_id).await?;
+        account.bank_id = validate_creditor_bank_is_active(self.bank_repository.as_ref(),
+                                                           &account.bank_code).await?.bank_id;
+
+        info!("Adding settlement account {:?} to commerce {}", account, commerce_id);
+        match self.commerce_repository.add_commerce_account(&commerce_id, &account).await {
+            Ok(Some(commerce)) => Ok(commerce),
+            Ok(None) => {
+                error!("Commerce with id '{}' does not exist", commerce_id);
+                Err(CommerceError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use regex::Regex;
+use domain::model::alias_type::AliasType;
+use domain::model::party_kind::PartyKind;
+
+pub const PHONE_ALIAS_TYPE: i64 = 1;
+pub const COMMERCE_ALIAS_TYPE: i64 = 2;
+
+static ALIAS_TYPE_CATALOG: OnceLock<AliasTypeCatalog> = OnceLock::new();
+
+// Alias types accepted by the directory with their validation rules, keyed by alias type id
+pub struct AliasTypeCatalog {
+    alias_types: HashMap<i64, (AliasType, Regex)>
+}
+
+impl AliasTypeCatalog {
+    pub fn new(alias_types: Vec<AliasType>) -> Result<Self, regex::Error> {
+        let alias_types = alias_types.into_iter()
+            .map(|alias_type| {
+                let regex = Regex::new(&alias_type.pattern)?;
+                Ok((alias_type.id, (alias_type, regex)))
+            })
+            .collect::<Result<HashMap<i64, (AliasType, Regex)>, regex::Error>>()?;
+        Ok(Self { alias_types })
+    }
+
+    // Same rules as the rows seeded in the alias_type table
+    pub fn default_alias_types() -> Vec<AliasType> {
+        vec![
+            AliasType::new(PHONE_ALIAS_TYPE, String::from("PHONE"),
+                           String::from(r"^\+[1-9][0-9]{7,14}$"), 9, 16, vec![PartyKind::User]),
+            AliasType::new(COMMERCE_ALIAS_TYPE, String::from("COMMERCE"),
+                           String::from("^@[A-Za-z0-9]+$"), 4, 26, vec![PartyKind::Commerce]),
+        ]
+    }
+
+    pub fn is_allowed(&self, alias_type_id: i64, party_kind: PartyKind) -> bool {
+        self.alias_types.get(&alias_type_id)
+            .is_some_and(|(alias_type, _)| alias_type.allows(party_kind))
+    }
+
+    pub fn is_valid_alias(&self, alias_type_id: i64, alias: &str) -> bool {
+        self.alias_types.get(&alias_type_id)
+            .is_some_and(|(alias_type, regex)| alias_type.has_valid_length(alias)
+                && regex.is_match(alias))
+    }
+}
+
+// Must be called before the first validation, returns false when a catalog was already in use
+pub fn configure_alias_type_catalog(alias_type_catalog: AliasTypeCatalog) -> bool {
+    ALIAS_TYPE_CATALOG.set(alias_type_catalog).is_ok()
+}
+
+pub(crate) fn alias_type_catalog() -> &'static AliasTypeCatalog {
+    ALIAS_TYPE_CATALOG.get_or_init(|| {
+        AliasTypeCatalog::new(AliasTypeCatalog::default_alias_types()).unwrap()
+    })
+}
\ No newline at end of file
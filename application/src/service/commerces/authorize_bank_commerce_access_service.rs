@@ -0,0 +1,55 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+
+pub struct AuthorizeBankCommerceAccessService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> AuthorizeBankCommerceAccessService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> AuthorizeBankCommerceAccessUseCase for AuthorizeBankCommerceAccessService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    // The check runs before the write and outside its transaction. That is safe because the
+    // settling bank of a commerce never changes: accounts can only be created, added or moved
+    // into the calling bank, which is already the owner, so promoting or rotating accounts keeps
+    // the commerce with the same bank. Bank status changes suspend commerces but never move them
+    async fn process(&self, calling_bank_code: &str, commerce_id: i64)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        info!("Checking that bank {} settles commerce {}", calling_bank_code, commerce_id);
+        let commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                              commerce_id).await?;
+        if commerce.account.bank_code != *calling_bank_code {
+            error!("Commerce {} settles into bank {}, not into calling bank {}", commerce_id,
+                commerce.account.bank_code, calling_bank_code);
+            return Err(BankError::commerce_belongs_to_another_bank().into());
+        }
+        Ok(commerce)
+    }
+
+    fn authorize_account(&self, calling_bank_code: &str, account: &Account)
+        -> Result<(), Box<dyn StdError + Send + Sync>> {
+        if account.bank_code != *calling_bank_code {
+            error!("Account {} belongs to bank {}, not to calling bank {}",
+                account.account_number, account.bank_code, calling_bank_code);
+            return Err(BankError::commerce_belongs_to_another_bank().into());
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
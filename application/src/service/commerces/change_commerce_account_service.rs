@@ -0,0 +1,75 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::{find_existing_commerce,
+                                                 validate_accounts_can_change};
+use crate::service::commerces::validate_commerce_to_store_service::{validate_account_number_format,
+                                                                    validate_bank_code_format,
+                                                                    validate_creditor_bank_is_active};
+use crate::use_case::commerces::change_commerce_account_use_case::ChangeCommerceAccountUseCase;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+
+pub struct ChangeCommerceAccountService<BR: BankRepositoryPort, CR: CommerceRepositoryPort,
+    NB: NotifyBankUseCase> {
+    bank_repository: Arc<BR>,
+    commerce_repository: Arc<CR>,
+    notify_bank_use_case: Arc<NB>
+}
+
+impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort, NB: NotifyBankUseCase>
+ChangeCommerceAccountService<BR, CR, NB> {
+    pub fn new(bank_repository: Arc<BR>, commerce_repository: Arc<CR>,
+               notify_bank_use_case: Arc<NB>) -> Self {
+        Self {
+            bank_repository,
+            commerce_repository,
+            notify_bank_use_case
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CR, NB> ChangeCommerceAccountUseCase for ChangeCommerceAccountService<BR, CR, NB>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static,
+    NB: NotifyBankUseCase + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, account: Account)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let mut account = Account::new(0, account.account_number.trim().to_string(),
+                                       account.bank_code.trim().to_string(), 0);
+        info!("Validating new account format for commerce {}", commerce_id);
+        validate_account_number_format(&account.account_number)?;
+        validate_bank_code_format(&account.bank_code)?;
+
+        let current_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                      commerce_id).await?;
+        validate_accounts_can_change(&current_commerce)?;
+        account.bank_id = validate_creditor_bank_is_active(self.bank_repository.as_ref(),
+                                                           &account.bank_code).await?.bank_id;
+
+        info!("Changing settlement account of commerce {} to {:?}", commerce_id, account);
+        match self.commerce_repository.change_commerce_account(&commerce_id, &account).await {
+            Ok(Some(commerce)) => {
+                self.notify_bank_use_case.process(CommerceEvent::AccountChanged, &commerce).await;
+                Ok(commerce)
+            },
+            Ok(None) => {
+                error!("Commerce with id '{}' does not exist", commerce_id);
+                Err(CommerceError::not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
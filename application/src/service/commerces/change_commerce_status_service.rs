@@ -0,0 +1,155 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use domain::model::commerce_status::CommerceStatus;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::service::commerces::validate_commerce_to_store_service::validate_creditor_bank_is_active;
+use crate::use_case::commerces::change_commerce_status_use_case::ChangeCommerceStatusUseCase;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+
+const MAX_REASON_LENGTH: usize = 400;
+
+pub struct ChangeCommerceStatusService<BR: BankRepositoryPort, CR: CommerceRepositoryPort,
+    NB: NotifyBankUseCase> {
+    bank_repository: Arc<BR>,
+    commerce_repository: Arc<CR>,
+    notify_bank_use_case: Arc<NB>
+}
+
+impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort, NB: NotifyBankUseCase>
+ChangeCommerceStatusService<BR, CR, NB> {
+    pub fn new(bank_repository: Arc<BR>, commerce_repository: Arc<CR>,
+               notify_bank_use_case: Arc<NB>) -> Self {
+        Self {
+            bank_repository,
+            commerce_repository,
+            notify_bank_use_case
+        }
+    }
+
+    async fn validate_parent_allows_status(&self, parent_commerce_id: i64,
+                                           new_status: &CommerceStatus)
+        -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let parent_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                     parent_commerce_id).await?;
+        if *new_status == CommerceStatus::Active
+            && parent_commerce.commerce_status != CommerceStatus::Active {
+            error!("Branch cannot be reactivated while parent commerce {} is {}",
+                parent_commerce_id, parent_commerce.commerce_status.status_name());
+            return Err(CommerceError::status_transition_not_allowed().into());
+        }
+        Ok(())
+    }
+
+    // Branches are read before the change because afterwards the ones that followed the parent
+    // cannot be told apart from those that were already inactive
+    async fn find_branches_to_cascade(&self, commerce_id: i64, current_status: &CommerceStatus)
+        -> Result<Vec<Commerce>, Box<dyn StdError + Send + Sync>> {
+        match self.commerce_repository.find_commerce_branches(&commerce_id).await {
+            Ok(branches) => Ok(branches.into_iter()
+                .filter(|branch| branch.commerce_status == *current_status)
+                .collect()),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+
+    // Aliases are only unique among active commerces, so another commerce may have taken the
+    // alias while this one was inactive
+    async fn validate_alias_is_free(&self, commerce: &Commerce)
+        -> Result<(), Box<dyn StdError + Send + Sync>> {
+        match self.commerce_repository.commerce_does_not_exist_by_alias(&commerce.alias).await {
+            Ok(true) => Ok(()),
+            Ok(false) => {
+                error!("Commerce {} cannot be reactivated, alias '{}' is used by another commerce",
+                    commerce.commerce_id, commerce.alias);
+                Err(CommerceError::alias_already_exists().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CR, NB> ChangeCommerceStatusUseCase for ChangeCommerceStatusService<BR, CR, NB>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static,
+    NB: NotifyBankUseCase + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, new_status: CommerceStatus, reason: String,
+                     changed_by: String)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let reason = reason.trim().to_string();
+        if reason.is_empty() || reason.len() > MAX_REASON_LENGTH {
+            error!("Invalid reason to change status of commerce {}: '{}'", commerce_id, reason);
+            return Err(CommerceError::status_reason_is_empty_or_null().into());
+        }
+
+        let current_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                      commerce_id).await?;
+        let current_status = current_commerce.commerce_status;
+        if !current_status.can_transition_to(&new_status) {
+            error!("Commerce {} cannot change status from {} to {}", commerce_id,
+                current_status.status_name(), new_status.status_name());
+            return Err(CommerceError::status_transition_not_allowed().into());
+        }
+        if let Some(parent_commerce_id) = current_commerce.parent_commerce_id {
+            self.validate_parent_allows_status(parent_commerce_id, &new_status).await?;
+        }
+        if new_status == CommerceStatus::Active {
+            self.validate_alias_is_free(&current_commerce).await?;
+            // The bank cascade only moves active commerces, so a commerce reactivated while its
+            // settling bank is not enabled would never be suspended with it
+            validate_creditor_bank_is_active(self.bank_repository.as_ref(),
+                                             &current_commerce.account.bank_code).await?;
+        }
+
+        // Deactivating a parent commerce deactivates its branches, reactivation is one by one
+        let cascade_to_branches = new_status == CommerceStatus::Inactive
+            && !current_commerce.is_branch();
+        let cascaded_branches = if cascade_to_branches {
+            self.find_branches_to_cascade(commerce_id, &current_status).await?
+        } else {
+            Vec::new()
+        };
+        info!("Changing status of commerce {} from {} to {} by {} because: {}", commerce_id,
+            current_status.status_name(), new_status.status_name(), changed_by, reason);
+        match self.commerce_repository
+            .change_commerce_status(&commerce_id, &current_status, &new_status, &reason, &changed_by,
+                                    &cascade_to_branches)
+            .await
+        {
+            Ok(Some(commerce)) => {
+                if new_status == CommerceStatus::Inactive {
+                    self.notify_bank_use_case.process(CommerceEvent::Deactivated, &commerce).await;
+                    for mut branch in cascaded_branches {
+                        branch.commerce_status = new_status;
+                        self.notify_bank_use_case.process(CommerceEvent::Deactivated, &branch).await;
+                    }
+                }
+                Ok(commerce)
+            },
+            Ok(None) => {
+                // The status changed between reading and updating the commerce
+                error!("Commerce {} is no longer {}", commerce_id, current_status.status_name());
+                Err(CommerceError::status_transition_not_allowed().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
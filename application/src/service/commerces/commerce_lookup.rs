@@ -0,0 +1,33 @@
+use std::error::Error as StdError;
+use tracing::error;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_status::CommerceStatus;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+
+// Shared by the commerce services that act on a commerce given by its id
+pub(crate) async fn find_existing_commerce<CR: CommerceRepositoryPort>(commerce_repository: &CR,
+                                                                       commerce_id: i64)
+    -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+    match commerce_repository.find_commerce_by_id(&commerce_id).await {
+        Ok(Some(commerce)) => Ok(commerce),
+        Ok(None) => {
+            error!("Commerce with id '{}' does not exist", commerce_id);
+            Err(CommerceError::not_found().into())
+        },
+        Err(e) => {
+            error!("There was an error in the database. Error is: {:?}", e);
+            Err(e.into())
+        }
+    }
+}
+
+// A suspended commerce is restored when every bank of its accounts is enabled again, so the
+// accounts that tie it to a suspended bank cannot be rotated or removed meanwhile
+pub(crate) fn validate_accounts_can_change(commerce: &Commerce) -> Result<(), CommerceError> {
+    if commerce.commerce_status == CommerceStatus::Suspended {
+        error!("Accounts of commerce {} cannot change while it is suspended", commerce.commerce_id);
+        return Err(CommerceError::accounts_locked_while_suspended());
+    }
+    Ok(())
+}
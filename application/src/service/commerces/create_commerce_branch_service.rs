@@ -0,0 +1,82 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use domain::model::commerce_status::CommerceStatus;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::create_commerce_branch_use_case::CreateCommerceBranchUseCase;
+use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+
+pub struct CreateCommerceBranchService<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort,
+    NB: NotifyBankUseCase> {
+    validate_commerce_to_store_use_case: Arc<VC>,
+    commerce_repository: Arc<CR>,
+    notify_bank_use_case: Arc<NB>
+}
+
+impl<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort, NB: NotifyBankUseCase>
+CreateCommerceBranchService<VC, CR, NB> {
+    pub fn new(validate_commerce_to_store_use_case: Arc<VC>, commerce_repository: Arc<CR>,
+               notify_bank_use_case: Arc<NB>) -> Self {
+        Self {
+            validate_commerce_to_store_use_case,
+            commerce_repository,
+            notify_bank_use_case
+        }
+    }
+
+    async fn find_parent_commerce(&self, parent_commerce_id: i64)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let parent_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                     parent_commerce_id).await?;
+
+        if parent_commerce.is_branch() {
+            error!("Commerce {} is already a branch", parent_commerce_id);
+            return Err(CommerceError::branch_cannot_have_branches().into());
+        }
+        if parent_commerce.commerce_status != CommerceStatus::Active {
+            error!("Parent commerce {} is not active", parent_commerce_id);
+            return Err(CommerceError::commerce_inactive().into());
+        }
+        Ok(parent_commerce)
+    }
+}
+
+#[async_trait]
+impl<VC, CR, NB> CreateCommerceBranchUseCase for CreateCommerceBranchService<VC, CR, NB>
+where
+    VC: ValidateCommerceToStore + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static,
+    NB: NotifyBankUseCase + Send + Sync + 'static
+{
+    async fn process(&self, parent_commerce_id: i64, branch: Commerce)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let parent_commerce = self.find_parent_commerce(parent_commerce_id).await?;
+
+        // Branches report under the parent, so they always carry its RUC and legal business name
+        let mut branch = branch;
+        branch.ruc = parent_commerce.ruc;
+        branch.legal_business_name = parent_commerce.legal_business_name;
+        branch.parent_commerce_id = Some(parent_commerce_id);
+
+        info!("Validating branch: {:?}", branch);
+        let valid_branch = self.validate_commerce_to_store_use_case
+            .process(branch).await?;
+        info!("Creating branch of commerce {}: {:?}", parent_commerce_id, valid_branch);
+        match self.commerce_repository.create_commerce(&valid_branch).await {
+            Ok(branch_created) => {
+                self.notify_bank_use_case.process(CommerceEvent::Created, &branch_created).await;
+                Ok(branch_created)
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -3,20 +3,27 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use tracing::{info};
 use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
 use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
 use crate::use_case::commerces::create_commerce_use_case::CreateCommerceUseCase;
 use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
 
-pub struct CreateCommerceService<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort> {
+pub struct CreateCommerceService<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort,
+    NB: NotifyBankUseCase> {
     validate_commerce_to_store_use_case: Arc<VC>,
-    commerce_repository: Arc<CR>
+    commerce_repository: Arc<CR>,
+    notify_bank_use_case: Arc<NB>
 }
 
-impl<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort> CreateCommerceService<VC, CR> {
-    pub fn new(validate_commerce_to_store_use_case: Arc<VC>, commerce_repository: Arc<CR>) -> Self {
+impl<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort, NB: NotifyBankUseCase>
+CreateCommerceService<VC, CR, NB> {
+    pub fn new(validate_commerce_to_store_use_case: Arc<VC>, commerce_repository: Arc<CR>,
+               notify_bank_use_case: Arc<NB>) -> Self {
         Self {
             validate_commerce_to_store_use_case,
-            commerce_repository
+            commerce_repository,
+            notify_bank_use_case
         }
     }
     async fn create_commerce(&self, valid_commerce: &Commerce) -> Result<Commerce, Box<dyn StdError + Send + Sync>>{
@@ -28,11 +35,12 @@ impl<VC: ValidateCommerceToStore, CR: CommerceRepositoryPort> CreateCommerceServ
 }
 
 #[async_trait]
-impl<VC, CR> CreateCommerceUseCase
-for CreateCommerceService<VC, CR>
+impl<VC, CR, NB> CreateCommerceUseCase
+for CreateCommerceService<VC, CR, NB>
 where
     VC: ValidateCommerceToStore + Send + Sync + 'static,
-    CR: CommerceRepositoryPort + Send + Sync + 'static
+    CR: CommerceRepositoryPort + Send + Sync + 'static,
+    NB: NotifyBankUseCase + Send + Sync + 'static
 {
     async fn process(&self, commerce: Commerce) -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
 
@@ -40,6 +48,8 @@ where
         let valid_commerce = self.validate_commerce_to_store_use_case
             .process(commerce).await?;
         info!("Creating commerce: {:?}", valid_commerce);
-        self.create_commerce(&valid_commerce).await
+        let commerce_created = self.create_commerce(&valid_commerce).await?;
+        self.notify_bank_use_case.process(CommerceEvent::Created, &commerce_created).await;
+        Ok(commerce_created)
     }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::info;
+use domain::model::commerce::Commerce;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::get_commerce_use_case::GetCommerceUseCase;
+
+pub struct GetCommerceService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> GetCommerceService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> GetCommerceUseCase for GetCommerceService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64) -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        info!("Looking for commerce with id: {}", commerce_id);
+        find_existing_commerce(self.commerce_repository.as_ref(), commerce_id).await
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::model::commerce_status_history::CommerceStatusHistory;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::get_commerce_status_history_use_case::GetCommerceStatusHistoryUseCase;
+
+pub struct GetCommerceStatusHistoryService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> GetCommerceStatusHistoryService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> GetCommerceStatusHistoryUseCase for GetCommerceStatusHistoryService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64)
+        -> Result<Vec<CommerceStatusHistory>, Box<dyn StdError + Send + Sync>> {
+        info!("Looking for status history of commerce with id: {}", commerce_id);
+        find_existing_commerce(self.commerce_repository.as_ref(), commerce_id).await?;
+
+        match self.commerce_repository.find_commerce_status_history(&commerce_id).await {
+            Ok(commerce_status_history) => Ok(commerce_status_history),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
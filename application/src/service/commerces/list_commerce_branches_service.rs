@@ -0,0 +1,40 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::model::commerce::Commerce;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::list_commerce_branches_use_case::ListCommerceBranchesUseCase;
+
+pub struct ListCommerceBranchesService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> ListCommerceBranchesService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> ListCommerceBranchesUseCase for ListCommerceBranchesService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, parent_commerce_id: i64)
+        -> Result<Vec<Commerce>, Box<dyn StdError + Send + Sync>> {
+        info!("Looking for branches of commerce with id: {}", parent_commerce_id);
+        find_existing_commerce(self.commerce_repository.as_ref(), parent_commerce_id).await?;
+
+        match self.commerce_repository.find_commerce_branches(&parent_commerce_id).await {
+            Ok(branches) => Ok(branches),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::use_case::commerces::list_commerces_use_case::ListCommercesUseCase;
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct ListCommercesService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> ListCommercesService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> ListCommercesUseCase for ListCommercesService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, filter: CommerceFilter, cursor: Option<i64>, limit: Option<i64>)
+        -> Result<CommercePage, Box<dyn StdError + Send + Sync>> {
+        let filter = normalize_filter(filter);
+        let page_size = page_size(limit);
+        info!("Listing commerces with filter {:?} after cursor {:?} and page size {}", filter,
+            cursor, page_size);
+        match self.commerce_repository.find_commerces(&filter, &cursor, &page_size).await {
+            Ok(commerce_page) => Ok(commerce_page),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+fn page_size(limit: Option<i64>) -> i64 {
+    match limit {
+        Some(limit) if limit > 0 => limit.min(MAX_PAGE_SIZE),
+        _ => DEFAULT_PAGE_SIZE
+    }
+}
+
+// Blank filters are treated as absent so "?bankCode=" does not match only empty bank codes
+fn normalize_filter(filter: CommerceFilter) -> CommerceFilter {
+    CommerceFilter::new(
+        normalize_text(filter.bank_code),
+        normalize_text(filter.status_name).map(|status_name| status_name.to_uppercase()),
+        filter.alias_type,
+        normalize_text(filter.ruc_prefix),
+        normalize_text(filter.legal_business_name),
+    )
+}
+
+fn normalize_text(value: Option<String>) -> Option<String> {
+    value
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
\ No newline at end of file
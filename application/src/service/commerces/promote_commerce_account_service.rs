@@ -0,0 +1,64 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::service::commerces::validate_commerce_to_store_service::validate_creditor_bank_is_active;
+use crate::use_case::commerces::promote_commerce_account_use_case::PromoteCommerceAccountUseCase;
+
+pub struct PromoteCommerceAccountService<BR: BankRepositoryPort, CR: CommerceRepositoryPort> {
+    bank_repository: Arc<BR>,
+    commerce_repository: Arc<CR>
+}
+
+impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> PromoteCommerceAccountService<BR, CR> {
+    pub fn new(bank_repository: Arc<BR>, commerce_repository: Arc<CR>) -> Self {
+        Self {
+            bank_repository,
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CR> PromoteCommerceAccountUseCase for PromoteCommerceAccountService<BR, CR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, account_id: i64)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let current_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                      commerce_id).await?;
+        let Some(account) = current_commerce.find_account(account_id) else {
+            error!("Account {} does not belong to commerce {}", account_id, commerce_id);
+            return Err(CommerceError::account_not_found().into());
+        };
+        if current_commerce.account.account_id == account_id {
+            info!("Account {} is already the primary account of commerce {}", account_id,
+                commerce_id);
+            return Ok(current_commerce);
+        }
+        // Alias resolution and the bank cascade follow the primary account, so its bank has to
+        // be enabled like when the account was added
+        validate_creditor_bank_is_active(self.bank_repository.as_ref(), &account.bank_code).await?;
+
+        info!("Promoting settlement account {} to primary for commerce {}", account_id, commerce_id);
+        match self.commerce_repository.promote_commerce_account(&commerce_id, &account_id).await {
+            Ok(Some(commerce)) => Ok(commerce),
+            Ok(None) => {
+                // The account was removed between reading and updating the commerce
+                error!("Account {} can no longer be promoted for commerce {}", account_id, commerce_id);
+                Err(CommerceError::account_not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
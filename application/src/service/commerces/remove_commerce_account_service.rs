@@ -0,0 +1,57 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::{find_existing_commerce,
+                                                 validate_accounts_can_change};
+use crate::use_case::commerces::remove_commerce_account_use_case::RemoveCommerceAccountUseCase;
+
+pub struct RemoveCommerceAccountService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> RemoveCommerceAccountService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<CR> RemoveCommerceAccountUseCase for RemoveCommerceAccountService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, account_id: i64)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let current_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                      commerce_id).await?;
+        validate_accounts_can_change(&current_commerce)?;
+        if current_commerce.find_account(account_id).is_none() {
+            error!("Account {} does not belong to commerce {}", account_id, commerce_id);
+            return Err(CommerceError::account_not_found().into());
+        }
+        if current_commerce.account.account_id == account_id {
+            error!("Account {} is the primary account of commerce {}", account_id, commerce_id);
+            return Err(CommerceError::primary_account_cannot_be_removed().into());
+        }
+
+        info!("Removing settlement account {} from commerce {}", account_id, commerce_id);
+        match self.commerce_repository.remove_commerce_account(&commerce_id, &account_id).await {
+            Ok(Some(commerce)) => Ok(commerce),
+            Ok(None) => {
+                // The account was removed or promoted between reading and updating the commerce
+                error!("Account {} can no longer be removed from commerce {}", account_id, commerce_id);
+                Err(CommerceError::account_not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
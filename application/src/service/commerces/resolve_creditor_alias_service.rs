@@ -0,0 +1,81 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::user_error::UserError;
+use domain::model::commerce::Commerce;
+use domain::model::creditor_user_info::CreditorUserInfo;
+use domain::model::status_creditor_user_info::StatusCreditorUserInfo;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::validate_commerce_to_store_service::validate_creditor_bank_is_active;
+use crate::use_case::commerces::resolve_creditor_alias_use_case::ResolveCreditorAliasUseCase;
+
+pub struct ResolveCreditorAliasService<BR: BankRepositoryPort, CR: CommerceRepositoryPort> {
+    bank_repository: Arc<BR>,
+    commerce_repository: Arc<CR>
+}
+
+impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> ResolveCreditorAliasService<BR, CR> {
+    pub fn new(bank_repository: Arc<BR>, commerce_repository: Arc<CR>) -> Self {
+        Self {
+            bank_repository,
+            commerce_repository
+        }
+    }
+
+    // An inactive alias can be registered again by another commerce, so the inactive holders
+    // are only looked up once no active commerce answers for the alias
+    async fn inactive_or_unknown_alias_error(&self, alias: &str) -> Box<dyn StdError + Send + Sync> {
+        match self.commerce_repository.find_commerce_by_alias(alias).await {
+            Ok(Some(commerce)) => {
+                error!("Creditor alias '{}' is {}", alias, commerce.commerce_status.status_name());
+                CommerceError::commerce_inactive().into()
+            },
+            Ok(None) => {
+                error!("Creditor alias '{}' does not exist", alias);
+                UserError::creditor_not_found().into()
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                e.into()
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, CR> ResolveCreditorAliasUseCase for ResolveCreditorAliasService<BR, CR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, status_creditor_user_info: StatusCreditorUserInfo)
+        -> Result<CreditorUserInfo, Box<dyn StdError + Send + Sync>> {
+        if status_creditor_user_info.alias_value.trim().trim_start_matches('@').is_empty() {
+            error!("Creditor alias is empty");
+            return Err(UserError::creditor_null().into());
+        }
+        let alias = Commerce::canonical_alias(&status_creditor_user_info.alias_value);
+        info!("Resolving creditor alias: {}", alias);
+        let commerce = match self.commerce_repository.find_active_commerce_by_alias(&alias).await {
+            Ok(Some(commerce)) => commerce,
+            Ok(None) => return Err(self.inactive_or_unknown_alias_error(&alias).await),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into())
+            }
+        };
+        // A commerce settling into a disabled bank cannot receive payments either
+        validate_creditor_bank_is_active(self.bank_repository.as_ref(), &commerce.account.bank_code)
+            .await?;
+        info!("Creditor alias '{}' resolved to commerce {}", alias, commerce.commerce_id);
+        Ok(CreditorUserInfo::new(
+            commerce.alias,
+            commerce.legal_business_name,
+            commerce.account.bank_code,
+            commerce.commerce_status
+        ))
+    }
+}
\ No newline at end of file
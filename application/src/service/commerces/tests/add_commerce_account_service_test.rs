@@ -0,0 +1,154 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
+use crate::service::commerces::add_commerce_account_service::AddCommerceAccountService;
+use crate::use_case::commerces::add_commerce_account_use_case::AddCommerceAccountUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_account_for_test() -> Account {
+        Account::new(
+            0,
+            String::from("550e8400-e29b-41d4-a716-446655440000"),
+            String::from("002"),
+            0
+        )
+    }
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut commerce_with_new_account = create_commerce_for_test();
+        commerce_with_new_account.accounts.push(Account::new(
+            60,
+            String::from("550e8400-e29b-41d4-a716-446655440000"),
+            String::from("002"),
+            2
+        ));
+        let expected_commerce = commerce_with_new_account.clone();
+
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| {
+                let mut bank = create_bank_for_test();
+                bank.bank_id = 2;
+                Ok(Some(bank))
+            })
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        commerce_repository
+            .expect_add_commerce_account()
+            .with(eq(50), eq(Account { bank_id: 2, ..new_account_for_test() }))
+            .returning(move |_, _| Ok(Some(commerce_with_new_account.clone())))
+            .times(1);
+
+        let service = AddCommerceAccountService::new(Arc::new(bank_repository),
+                                                     Arc::new(commerce_repository));
+
+        let result = service.process(50, new_account_for_test()).await.unwrap();
+
+        assert_eq!(result, expected_commerce);
+        assert_eq!(result.account.account_id, 59);
+        assert_eq!(result.accounts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_account_format() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository.expect_find_commerce_by_id().times(0);
+        commerce_repository.expect_add_commerce_account().times(0);
+
+        let service = AddCommerceAccountService::new(Arc::new(bank_repository),
+                                                     Arc::new(commerce_repository));
+
+        let mut account = new_account_for_test();
+        account.account_number = String::from("12345");
+        let error = service.process(50, account).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), CommerceError::not_valid_account_format().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_bank_code_format() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository.expect_find_commerce_by_id().times(0);
+        commerce_repository.expect_add_commerce_account().times(0);
+
+        let service = AddCommerceAccountService::new(Arc::new(bank_repository),
+                                                     Arc::new(commerce_repository));
+
+        let mut account = new_account_for_test();
+        account.bank_code = String::from("02");
+        let error = service.process(50, account).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), "ERR-087");
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        commerce_repository.expect_add_commerce_account().times(0);
+
+        let service = AddCommerceAccountService::new(Arc::new(bank_repository),
+                                                     Arc::new(commerce_repository));
+
+        let error = service.process(50, new_account_for_test()).await.unwrap_err();
+
+        let bank_error = error.downcast_ref::<BankError>().unwrap();
+        assert_eq!(bank_error.get_code(), BankError::creditor_bank_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        commerce_repository
+            .expect_add_commerce_account()
+            .returning(|_, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = AddCommerceAccountService::new(Arc::new(bank_repository),
+                                                     Arc::new(commerce_repository));
+
+        let error = service.process(50, new_account_for_test()).await.unwrap_err();
+
+        assert!(error.downcast_ref::<DatabaseError>().is_some());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use domain::model::alias_type::AliasType;
+use domain::model::party_kind::PartyKind;
+use crate::service::commerces::alias_type_catalog::{AliasTypeCatalog, COMMERCE_ALIAS_TYPE,
+                                                    PHONE_ALIAS_TYPE};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_catalog() -> AliasTypeCatalog {
+        AliasTypeCatalog::new(AliasTypeCatalog::default_alias_types()).unwrap()
+    }
+
+    #[test]
+    fn test_default_party_kinds() {
+        let alias_type_catalog = default_catalog();
+
+        assert!(alias_type_catalog.is_allowed(COMMERCE_ALIAS_TYPE, PartyKind::Commerce));
+        assert!(!alias_type_catalog.is_allowed(COMMERCE_ALIAS_TYPE, PartyKind::User));
+        assert!(alias_type_catalog.is_allowed(PHONE_ALIAS_TYPE, PartyKind::User));
+        assert!(!alias_type_catalog.is_allowed(PHONE_ALIAS_TYPE, PartyKind::Commerce));
+        assert!(!alias_type_catalog.is_allowed(3, PartyKind::User));
+    }
+
+    #[test]
+    fn test_default_commerce_alias_limits() {
+        let alias_type_catalog = default_catalog();
+
+        assert!(alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "@aliasTest"));
+        assert!(alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "@abc"));
+        assert!(alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, &format!("@{}", "a".repeat(25))));
+        assert!(!alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "@ab"));
+        assert!(!alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, &format!("@{}", "a".repeat(26))));
+        assert!(!alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "@alias test"));
+        // Commerce aliases are validated once the "@" is added
+        assert!(!alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "aliasTest"));
+    }
+
+    #[test]
+    fn test_default_phone_alias_pattern() {
+        let alias_type_catalog = default_catalog();
+
+        assert!(alias_type_catalog.is_valid_alias(PHONE_ALIAS_TYPE, "+51999888777"));
+        assert!(!alias_type_catalog.is_valid_alias(PHONE_ALIAS_TYPE, "51999888777"));
+        assert!(!alias_type_catalog.is_valid_alias(COMMERCE_ALIAS_TYPE, "+51999888777"));
+    }
+
+    #[test]
+    fn test_new_alias_type_from_table() {
+        let mut alias_types = AliasTypeCatalog::default_alias_types();
+        alias_types.push(AliasType::new(3, String::from("EMAIL"),
+                                        String::from("^[^@ ]+@[^@ ]+$"), 5, 100,
+                                        vec![PartyKind::User, PartyKind::Commerce]));
+        let alias_type_catalog = AliasTypeCatalog::new(alias_types).unwrap();
+
+        assert!(alias_type_catalog.is_allowed(3, PartyKind::User));
+        assert!(alias_type_catalog.is_allowed(3, PartyKind::Commerce));
+        assert!(alias_type_catalog.is_valid_alias(3, "john.doe@boa.com"));
+        assert!(!alias_type_catalog.is_valid_alias(3, "john.doe"));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let alias_types = vec![AliasType::new(3, String::from("BROKEN"), String::from("^[0-9"),
+                                              1, 10, vec![PartyKind::User])];
+
+        assert!(AliasTypeCatalog::new(alias_types).is_err());
+    }
+}
\ No newline at end of file
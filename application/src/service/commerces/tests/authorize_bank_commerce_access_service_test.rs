@@ -0,0 +1,93 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::account::Account;
+use crate::service::commerces::authorize_bank_commerce_access_service::AuthorizeBankCommerceAccessService;
+use crate::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(repository));
+
+        let result = service.process(&String::from("841"), 50).await;
+
+        assert_eq!(result.unwrap(), create_commerce_for_test());
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_of_another_bank() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(repository));
+
+        let error = service.process(&String::from("001"), 50).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-112");
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(repository));
+
+        let error = service.process(&String::from("841"), 404).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(repository));
+
+        let result = service.process(&String::from("841"), 50).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_authorize_account_success() {
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(MockCommerceRepo::new()));
+        let account = Account::new(0, String::from("0011223344"), String::from("841"), 0);
+
+        assert!(service.authorize_account(&String::from("841"), &account).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_account_of_another_bank() {
+        let service = AuthorizeBankCommerceAccessService::new(Arc::new(MockCommerceRepo::new()));
+        let account = Account::new(0, String::from("0011223344"), String::from("001"), 0);
+
+        let error = service.authorize_account(&String::from("841"), &account).unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-112");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,431 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank_status::BankStatus;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_event::CommerceEvent;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::change_commerce_status_service::ChangeCommerceStatusService;
+use crate::use_case::commerces::change_commerce_status_use_case::ChangeCommerceStatusUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              notify_bank_for_test, MockCommerceRepo,
+                                              MockNotifyBank};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_deactivate_success() {
+        let mut deactivated_commerce = create_commerce_for_test();
+        deactivated_commerce.commerce_status = CommerceStatus::Inactive;
+        let expected_commerce = deactivated_commerce.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .with(eq(50))
+            .returning(|_| Ok(vec![]))
+            .times(1);
+        repository
+            .expect_change_commerce_status()
+            .with(eq(50), eq(CommerceStatus::Active), eq(CommerceStatus::Inactive),
+                  eq(String::from("Requested by the merchant")), eq(String::from("001")), eq(true))
+            .returning(move |_, _, _, _, _, _| Ok(Some(deactivated_commerce.clone())))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 1)));
+
+        let result = service
+            .process(50, CommerceStatus::Inactive, String::from(" Requested by the merchant "),
+                     String::from("001"))
+            .await;
+
+        assert_eq!(result.unwrap(), expected_commerce);
+    }
+
+    #[tokio::test]
+    async fn test_process_deactivate_notifies_cascaded_branches() {
+        let mut deactivated_commerce = create_commerce_for_test();
+        deactivated_commerce.commerce_status = CommerceStatus::Inactive;
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .with(eq(50))
+            .returning(|_| {
+                let mut active_branch = create_commerce_for_test();
+                active_branch.commerce_id = 51;
+                active_branch.parent_commerce_id = Some(50);
+                let mut inactive_branch = active_branch.clone();
+                inactive_branch.commerce_id = 52;
+                inactive_branch.commerce_status = CommerceStatus::Inactive;
+                Ok(vec![active_branch, inactive_branch])
+            })
+            .times(1);
+        repository
+            .expect_change_commerce_status()
+            .returning(move |_, _, _, _, _, _| Ok(Some(deactivated_commerce.clone())))
+            .times(1);
+        let mut notify_bank = MockNotifyBank::new();
+        notify_bank
+            .expect_process()
+            .withf(|event, commerce| *event == CommerceEvent::Deactivated
+                && commerce.commerce_id == 50)
+            .return_const(())
+            .times(1);
+        notify_bank
+            .expect_process()
+            .withf(|event, commerce| *event == CommerceEvent::Deactivated
+                && commerce.commerce_id == 51 && commerce.commerce_status == CommerceStatus::Inactive)
+            .return_const(())
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank));
+
+        let result = service
+            .process(50, CommerceStatus::Inactive, String::from("Closed"), String::from("001"))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_transition_not_allowed() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository.expect_change_commerce_status().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Active, String::from("Reactivation"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-098");
+    }
+
+    #[tokio::test]
+    async fn test_process_reactivate_suspended_commerce_not_allowed() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| {
+                let mut suspended_commerce = create_commerce_for_test();
+                suspended_commerce.commerce_status = CommerceStatus::Suspended;
+                Ok(Some(suspended_commerce))
+            })
+            .times(1);
+        repository.expect_change_commerce_status().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Active, String::from("Reactivation"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-098");
+    }
+
+    #[tokio::test]
+    async fn test_process_empty_reason() {
+        let mut repository = MockCommerceRepo::new();
+        repository.expect_find_commerce_by_id().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Inactive, String::from("   "),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-001");
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(404, CommerceStatus::Inactive, String::from("Closed"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_status_changed_concurrently() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .returning(|_| Ok(vec![]))
+            .times(1);
+        repository
+            .expect_change_commerce_status()
+            .returning(|_, _, _, _, _, _| Ok(None))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Inactive, String::from("Closed"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-098");
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .returning(|_| Ok(vec![]))
+            .times(1);
+        repository
+            .expect_change_commerce_status()
+            .returning(|_, _, _, _, _, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Inactive, String::from("Closed"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert!(error.downcast_ref::<DatabaseError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_process_deactivate_branch_does_not_cascade() {
+        let mut branch = create_commerce_for_test();
+        branch.parent_commerce_id = Some(10);
+        let branch_found = branch.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(move |_| Ok(Some(branch_found.clone())))
+            .times(1);
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(10))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository.expect_find_commerce_branches().times(0);
+        repository
+            .expect_change_commerce_status()
+            .with(eq(50), eq(CommerceStatus::Active), eq(CommerceStatus::Inactive),
+                  eq(String::from("Store closed")), eq(String::from("001")), eq(false))
+            .returning(move |_, _, _, _, _, _| Ok(Some(branch.clone())))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 1)));
+
+        let result = service
+            .process(50, CommerceStatus::Inactive, String::from("Store closed"),
+                     String::from("001"))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_reactivate_branch_with_inactive_parent() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut branch = create_commerce_for_test();
+                branch.parent_commerce_id = Some(10);
+                branch.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(branch))
+            })
+            .times(1);
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(10))
+            .returning(|_| {
+                let mut parent = create_commerce_for_test();
+                parent.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(parent))
+            })
+            .times(1);
+        repository.expect_change_commerce_status().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Active, String::from("Store reopened"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-098");
+    }
+
+    #[tokio::test]
+    async fn test_process_reactivate_success() {
+        let reactivated_commerce = create_commerce_for_test();
+        let expected_commerce = reactivated_commerce.clone();
+
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut inactive_commerce = create_commerce_for_test();
+                inactive_commerce.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(inactive_commerce))
+            })
+            .times(1);
+        repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+        repository
+            .expect_change_commerce_status()
+            .with(eq(50), eq(CommerceStatus::Inactive), eq(CommerceStatus::Active),
+                  eq(String::from("Store reopened")), eq(String::from("001")), eq(false))
+            .returning(move |_, _, _, _, _, _| Ok(Some(reactivated_commerce.clone())))
+            .times(1);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(bank_repository), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let result = service
+            .process(50, CommerceStatus::Active, String::from("Store reopened"),
+                     String::from("001"))
+            .await;
+
+        assert_eq!(result.unwrap(), expected_commerce);
+    }
+
+    #[tokio::test]
+    async fn test_process_reactivate_alias_taken_by_another_commerce() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut inactive_commerce = create_commerce_for_test();
+                inactive_commerce.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(inactive_commerce))
+            })
+            .times(1);
+        repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(false))
+            .times(1);
+        repository.expect_change_commerce_status().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(MockBankRepo::new()), Arc::new(repository), Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Active, String::from("Store reopened"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-008");
+    }
+
+    #[tokio::test]
+    async fn test_process_reactivate_with_suspended_bank() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| {
+                let mut suspended_bank = create_bank_for_test();
+                suspended_bank.bank_status = BankStatus::Suspended;
+                Ok(Some(suspended_bank))
+            })
+            .times(1);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut inactive_commerce = create_commerce_for_test();
+                inactive_commerce.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(inactive_commerce))
+            })
+            .times(1);
+        repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+        repository.expect_change_commerce_status().times(0);
+
+        let service = ChangeCommerceStatusService::new(
+            Arc::new(bank_repository), Arc::new(repository),
+            Arc::new(notify_bank_for_test(CommerceEvent::Deactivated, 0)));
+
+        let error = service
+            .process(50, CommerceStatus::Active, String::from("Store reopened"),
+                     String::from("001"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-088");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use domain::model::commerce_status::CommerceStatus;
+use crate::service::commerces::create_commerce_branch_service::CreateCommerceBranchService;
+use crate::use_case::commerces::create_commerce_branch_use_case::CreateCommerceBranchUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              notify_bank_for_test, MockCommerceRepo,
+                                              MockValidateCommerceUseCase};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_branch_for_test() -> Commerce {
+        let mut branch = create_commerce_for_test();
+        branch.commerce_id = 0;
+        branch.alias = String::from("branchTest");
+        branch.ruc = String::new();
+        branch.legal_business_name = String::new();
+        branch
+    }
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let parent_commerce = create_commerce_for_test();
+        let mut expected_branch = create_branch_for_test();
+        expected_branch.ruc = parent_commerce.ruc.clone();
+        expected_branch.legal_business_name = parent_commerce.legal_business_name.clone();
+        expected_branch.parent_commerce_id = Some(50);
+        let validated_branch = expected_branch.clone();
+        let created_branch = expected_branch.clone();
+
+        let mut validator = MockValidateCommerceUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(move |_| Ok(Some(parent_commerce.clone())))
+            .times(1);
+        validator
+            .expect_process()
+            .with(eq(expected_branch))
+            .returning(move |_| Ok(validated_branch.clone()))
+            .times(1);
+        repository
+            .expect_create_commerce()
+            .returning(move |_| Ok(created_branch.clone()))
+            .times(1);
+
+        let service = CreateCommerceBranchService::new(Arc::new(validator), Arc::new(repository),
+                                                       Arc::new(notify_bank_for_test(
+                                                           CommerceEvent::Created, 1)));
+
+        let result = service.process(50, create_branch_for_test()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().parent_commerce_id, Some(50));
+    }
+
+    #[tokio::test]
+    async fn test_process_parent_not_found() {
+        let mut validator = MockValidateCommerceUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(None))
+            .times(1);
+        validator.expect_process().times(0);
+        repository.expect_create_commerce().times(0);
+
+        let service = CreateCommerceBranchService::new(Arc::new(validator), Arc::new(repository),
+                                                       Arc::new(notify_bank_for_test(
+                                                           CommerceEvent::Created, 0)));
+
+        let error = service.process(50, create_branch_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_parent_is_branch() {
+        let mut validator = MockValidateCommerceUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut parent_commerce = create_commerce_for_test();
+                parent_commerce.parent_commerce_id = Some(10);
+                Ok(Some(parent_commerce))
+            })
+            .times(1);
+        validator.expect_process().times(0);
+        repository.expect_create_commerce().times(0);
+
+        let service = CreateCommerceBranchService::new(Arc::new(validator), Arc::new(repository),
+                                                       Arc::new(notify_bank_for_test(
+                                                           CommerceEvent::Created, 0)));
+
+        let error = service.process(50, create_branch_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-101");
+    }
+
+    #[tokio::test]
+    async fn test_process_parent_inactive() {
+        let mut validator = MockValidateCommerceUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| {
+                let mut parent_commerce = create_commerce_for_test();
+                parent_commerce.commerce_status = CommerceStatus::Inactive;
+                Ok(Some(parent_commerce))
+            })
+            .times(1);
+        validator.expect_process().times(0);
+        repository.expect_create_commerce().times(0);
+
+        let service = CreateCommerceBranchService::new(Arc::new(validator), Arc::new(repository),
+                                                       Arc::new(notify_bank_for_test(
+                                                           CommerceEvent::Created, 0)));
+
+        let error = service.process(50, create_branch_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-095");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut validator = MockValidateCommerceUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+        validator.expect_process().times(0);
+
+        let service = CreateCommerceBranchService::new(Arc::new(validator), Arc::new(repository),
+                                                       Arc::new(notify_bank_for_test(
+                                                           CommerceEvent::Created, 0)));
+
+        let result = service.process(50, create_branch_for_test()).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
@@ -1,48 +1,11 @@
-use std::error::Error;
 use mockall::predicate::*;
-use mockall::mock;
 use std::sync::Arc;
-use async_trait::async_trait;
-use domain::exception::database_error::DatabaseError;
-use domain::model::account::Account;
-use domain::model::commerce::Commerce;
-use domain::model::commerce_status::CommerceStatus;
-use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
-use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
+use domain::model::commerce_event::CommerceEvent;
 use crate::service::commerces::create_commerce_service::CreateCommerceService;
 use crate::use_case::commerces::create_commerce_use_case::CreateCommerceUseCase;
-
-// Create mocks for our dependencies
-mock! {
-    ValidateCommerceUseCase {}
-
-    #[async_trait]
-    impl ValidateCommerceToStore for ValidateCommerceUseCase {
-        async fn process(&self, commerce: Commerce) -> Result<Commerce, Box<dyn Error + Send + Sync>>;
-    }
-}
-
-mock! {
-    CommerceRepo {}
-
-    #[async_trait]
-    impl CommerceRepositoryPort for CommerceRepo {
-        async fn create_commerce(&self, commerce: &Commerce)
-        -> Result<Commerce, DatabaseError>;
-
-        async fn commerce_does_not_exist_by_ruc_and_alias(
-            &self,
-            ruc: &String,
-            alias: &String
-        ) -> Result<bool, DatabaseError>;
-
-        async fn commerce_exists_by_ruc_or_legal_business_name(
-            &self,
-            ruc: &String,
-            legal_business_name: &String
-        ) -> Result<bool, DatabaseError>;
-    }
-}
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              notify_bank_for_test, MockCommerceRepo,
+                                              MockValidateCommerceUseCase};
 
 #[cfg(test)]
 mod tests {
@@ -77,7 +40,8 @@ mod tests {
         // Create service with configured mocks
         let service = CreateCommerceService::new(
             Arc::new(validator),
-            Arc::new(repository)
+            Arc::new(repository),
+            Arc::new(notify_bank_for_test(CommerceEvent::Created, 1))
         );
 
         // Execute test
@@ -97,10 +61,7 @@ mod tests {
         validator
             .expect_process()
             .with(eq(input_commerce.clone()))
-            .returning(|_| Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Validation error"
-            ))))
+            .returning(|_| Err(Box::new(std::io::Error::other("Validation error"))))
             .times(1);
 
 
@@ -110,7 +71,8 @@ mod tests {
         // Create service with configured mocks
         let service = CreateCommerceService::new(
             Arc::new(validator),
-            Arc::new(repository)
+            Arc::new(repository),
+            Arc::new(notify_bank_for_test(CommerceEvent::Created, 0))
         );
 
         // Execute test
@@ -142,16 +104,14 @@ mod tests {
         repository
             .expect_create_commerce()
             .with(eq(validated_commerce_clone))
-            .returning(|_| Err(DatabaseError::Unexpected(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Database error in test"
-            )))))
+            .returning(|_| Err(database_error_for_test()))
             .times(1);
 
         // Create service with configured mocks
         let service = CreateCommerceService::new(
             Arc::new(validator),
-            Arc::new(repository)
+            Arc::new(repository),
+            Arc::new(notify_bank_for_test(CommerceEvent::Created, 0))
         );
 
         // Execute test
@@ -161,22 +121,3 @@ mod tests {
         // Add assertions about the error type/message
     }
 }
-
-fn create_commerce_for_test() -> Commerce {
-    Commerce::new(
-        50,
-        String::from("aliasTest"),
-        2,
-        String::from("legal business name test"),
-        Account::new(
-            59,
-            String::from("84102e21-01b5-4f5d-8771-36f915c4d29e"),
-            String::from("841"),
-            10
-        ),
-        String::from("123456789-9-2099"),
-        CommerceStatus::new(
-            String::from("ACTIVE"),
-        )
-    )
-}
\ No newline at end of file
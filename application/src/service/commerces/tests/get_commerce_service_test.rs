@@ -0,0 +1,64 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::database_error::DatabaseError;
+use crate::service::commerces::get_commerce_service::GetCommerceService;
+use crate::use_case::commerces::get_commerce_use_case::GetCommerceUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let stored_commerce = create_commerce_for_test();
+        let expected_commerce = stored_commerce.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(move |_| Ok(Some(stored_commerce.clone())))
+            .times(1);
+
+        let service = GetCommerceService::new(Arc::new(repository));
+
+        let result = service.process(50).await;
+
+        assert_eq!(result.unwrap(), expected_commerce);
+    }
+
+    #[tokio::test]
+    async fn test_process_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(404))
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = GetCommerceService::new(Arc::new(repository));
+
+        let error = service.process(404).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = GetCommerceService::new(Arc::new(repository));
+
+        let error = service.process(50).await.unwrap_err();
+
+        assert!(error.downcast_ref::<DatabaseError>().is_some());
+    }
+}
@@ -0,0 +1,62 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use chrono::Utc;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_status_history::CommerceStatusHistory;
+use crate::service::commerces::get_commerce_status_history_service::GetCommerceStatusHistoryService;
+use crate::use_case::commerces::get_commerce_status_history_use_case::GetCommerceStatusHistoryUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let commerce_status_history = vec![CommerceStatusHistory::new(
+            1,
+            50,
+            CommerceStatus::Active,
+            CommerceStatus::Inactive,
+            String::from("Closed"),
+            String::from("001"),
+            Utc::now()
+        )];
+        let expected_history = commerce_status_history.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_status_history()
+            .with(eq(50))
+            .returning(move |_| Ok(commerce_status_history.clone()))
+            .times(1);
+
+        let service = GetCommerceStatusHistoryService::new(Arc::new(repository));
+
+        let result = service.process(50).await;
+
+        assert_eq!(result.unwrap(), expected_history);
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_find_commerce_status_history().times(0);
+
+        let service = GetCommerceStatusHistoryService::new(Arc::new(repository));
+
+        let error = service.process(404).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+}
@@ -0,0 +1,77 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use crate::service::commerces::list_commerce_branches_service::ListCommerceBranchesService;
+use crate::use_case::commerces::list_commerce_branches_use_case::ListCommerceBranchesUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut branch = create_commerce_for_test();
+        branch.commerce_id = 51;
+        branch.parent_commerce_id = Some(50);
+        let branches = vec![branch];
+        let expected_branches = branches.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .with(eq(50))
+            .returning(move |_| Ok(branches.clone()))
+            .times(1);
+
+        let service = ListCommerceBranchesService::new(Arc::new(repository));
+
+        let result = service.process(50).await;
+
+        assert_eq!(result.unwrap(), expected_branches);
+    }
+
+    #[tokio::test]
+    async fn test_process_parent_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_find_commerce_branches().times(0);
+
+        let service = ListCommerceBranchesService::new(Arc::new(repository));
+
+        let error = service.process(50).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository
+            .expect_find_commerce_branches()
+            .with(eq(50))
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ListCommerceBranchesService::new(Arc::new(repository));
+
+        let result = service.process(50).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::database_error::DatabaseError;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
+use crate::service::commerces::list_commerces_service::ListCommercesService;
+use crate::use_case::commerces::list_commerces_use_case::ListCommercesUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let commerce_page = CommercePage::new(vec![create_commerce_for_test()], Some(50), 3);
+        let expected_page = commerce_page.clone();
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerces()
+            .with(eq(CommerceFilter::new(Some(String::from("841")), None, Some(2), None, None)),
+                  eq(Some(10)), eq(5))
+            .returning(move |_, _, _| Ok(commerce_page.clone()))
+            .times(1);
+
+        let service = ListCommercesService::new(Arc::new(repository));
+
+        let result = service
+            .process(CommerceFilter::new(Some(String::from("841")), None, Some(2), None, None),
+                     Some(10), Some(5))
+            .await;
+
+        assert_eq!(result.unwrap(), expected_page);
+    }
+
+    #[tokio::test]
+    async fn test_process_normalizes_filter_and_page_size() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerces()
+            .with(eq(CommerceFilter::new(None, Some(String::from("ACTIVE")), None,
+                                         Some(String::from("1234")), None)),
+                  eq(None), eq(100))
+            .returning(|_, _, _| Ok(CommercePage::new(vec![], None, 0)))
+            .times(1);
+
+        let service = ListCommercesService::new(Arc::new(repository));
+
+        let result = service
+            .process(CommerceFilter::new(Some(String::from("  ")), Some(String::from("active")),
+                                         None, Some(String::from(" 1234 ")), Some(String::new())),
+                     None, Some(1000))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_uses_default_page_size() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerces()
+            .with(always(), eq(None), eq(20))
+            .returning(|_, _, _| Ok(CommercePage::new(vec![], None, 0)))
+            .times(1);
+
+        let service = ListCommercesService::new(Arc::new(repository));
+
+        let result = service.process(CommerceFilter::default(), None, Some(0)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerces()
+            .returning(|_, _, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = ListCommercesService::new(Arc::new(repository));
+
+        let error = service.process(CommerceFilter::default(), None, None).await.unwrap_err();
+
+        assert!(error.downcast_ref::<DatabaseError>().is_some());
+    }
+}
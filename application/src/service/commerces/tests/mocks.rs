@@ -0,0 +1,149 @@
+use std::error::Error;
+use mockall::mock;
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_status_history::CommerceStatusHistory;
+use domain::model::commerce_update::CommerceUpdate;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
+use crate::use_case::commerces::validate_commerce_to_update::ValidateCommerceToUpdate;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+
+// Mocks shared by the commerce service tests
+mock! {
+    pub ValidateCommerceUseCase {}
+
+    #[async_trait]
+    impl ValidateCommerceToStore for ValidateCommerceUseCase {
+        async fn process(&self, commerce: Commerce) -> Result<Commerce, Box<dyn Error + Send + Sync>>;
+    }
+}
+
+mock! {
+    pub ValidateCommerceToUpdateUseCase {}
+
+    #[async_trait]
+    impl ValidateCommerceToUpdate for ValidateCommerceToUpdateUseCase {
+        async fn process(&self, current_commerce: &Commerce, commerce_update: CommerceUpdate)
+        -> Result<Commerce, Box<dyn Error + Send + Sync>>;
+    }
+}
+
+mock! {
+    pub NotifyBank {}
+
+    #[async_trait]
+    impl NotifyBankUseCase for NotifyBank {
+        async fn process(&self, commerce_event: CommerceEvent, commerce: &Commerce);
+    }
+}
+
+mock! {
+    pub CommerceRepo {}
+
+    #[async_trait]
+    impl CommerceRepositoryPort for CommerceRepo {
+        async fn create_commerce(&self, commerce: &Commerce)
+        -> Result<Commerce, DatabaseError>;
+
+        async fn commerce_does_not_exist_by_alias(&self, alias: &str)
+        -> Result<bool, DatabaseError>;
+
+        async fn commerce_exists_by_ruc_or_legal_business_name(
+            &self,
+            ruc: &str,
+            legal_business_name: &str
+        ) -> Result<bool, DatabaseError>;
+
+        async fn commerce_exists_by_ruc_or_legal_business_name_excluding(
+            &self,
+            commerce_id: &i64,
+            ruc: &str,
+            legal_business_name: &str
+        ) -> Result<bool, DatabaseError>;
+
+        async fn find_commerce_by_id(&self, commerce_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn find_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn find_active_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn find_commerces(
+            &self,
+            filter: &CommerceFilter,
+            cursor: &Option<i64>,
+            limit: &i64
+        ) -> Result<CommercePage, DatabaseError>;
+
+        async fn update_commerce(&self, commerce: &Commerce)
+        -> Result<Commerce, DatabaseError>;
+
+        async fn change_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn add_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn remove_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn promote_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn change_commerce_status(
+            &self,
+            commerce_id: &i64,
+            current_status: &CommerceStatus,
+            new_status: &CommerceStatus,
+            reason: &str,
+            changed_by: &str,
+            cascade_to_branches: &bool
+        ) -> Result<Option<Commerce>, DatabaseError>;
+
+        async fn find_commerce_branches(&self, parent_commerce_id: &i64)
+        -> Result<Vec<Commerce>, DatabaseError>;
+
+        async fn find_commerce_status_history(&self, commerce_id: &i64)
+        -> Result<Vec<CommerceStatusHistory>, DatabaseError>;
+    }
+}
+
+pub fn create_commerce_for_test() -> Commerce {
+    Commerce::new(
+        50,
+        String::from("aliasTest"),
+        2,
+        String::from("legal business name test"),
+        Account::new(
+            59,
+            String::from("84102e21-01b5-4f5d-8771-36f915c4d29e"),
+            String::from("841"),
+            10
+        ),
+        String::from("123456789-9-2099"),
+        CommerceStatus::Active
+    )
+}
+
+pub fn notify_bank_for_test(commerce_event: CommerceEvent, times: usize) -> MockNotifyBank {
+    let mut notify_bank = MockNotifyBank::new();
+    notify_bank
+        .expect_process()
+        .withf(move |event, _| *event == commerce_event)
+        .return_const(())
+        .times(times);
+    notify_bank
+}
+
+pub fn database_error_for_test() -> DatabaseError {
+    DatabaseError::Unexpected(Box::new(std::io::Error::other("Database error in test")))
+}
@@ -0,0 +1,158 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
+use domain::model::bank_status::BankStatus;
+use domain::model::commerce::Commerce;
+use crate::service::commerces::promote_commerce_account_service::PromoteCommerceAccountService;
+use crate::use_case::commerces::promote_commerce_account_use_case::PromoteCommerceAccountUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secondary_account() -> Account {
+        Account::new(
+            60,
+            String::from("550e8400-e29b-41d4-a716-446655440000"),
+            String::from("002"),
+            2
+        )
+    }
+
+    fn commerce_with_two_accounts() -> Commerce {
+        let mut commerce = create_commerce_for_test();
+        commerce.accounts.push(secondary_account());
+        commerce
+    }
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut promoted_commerce = commerce_with_two_accounts();
+        promoted_commerce.account = secondary_account();
+        let expected_commerce = promoted_commerce.clone();
+
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository
+            .expect_promote_commerce_account()
+            .with(eq(50), eq(60))
+            .returning(move |_, _| Ok(Some(promoted_commerce.clone())))
+            .times(1);
+
+        let service = PromoteCommerceAccountService::new(Arc::new(bank_repository),
+                                                         Arc::new(repository));
+
+        let result = service.process(50, 60).await.unwrap();
+
+        assert_eq!(result, expected_commerce);
+    }
+
+    #[tokio::test]
+    async fn test_process_already_primary() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository.expect_promote_commerce_account().times(0);
+
+        let service = PromoteCommerceAccountService::new(Arc::new(bank_repository),
+                                                         Arc::new(repository));
+
+        let result = service.process(50, 59).await.unwrap();
+
+        assert_eq!(result.account.account_id, 59);
+    }
+
+    #[tokio::test]
+    async fn test_process_account_not_found() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        repository.expect_promote_commerce_account().times(0);
+
+        let service = PromoteCommerceAccountService::new(Arc::new(bank_repository),
+                                                         Arc::new(repository));
+
+        let error = service.process(50, 60).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), CommerceError::account_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_enabled() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| {
+                let mut bank = create_bank_for_test();
+                bank.bank_status = BankStatus::Suspended;
+                Ok(Some(bank))
+            })
+            .times(1);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository.expect_promote_commerce_account().times(0);
+
+        let service = PromoteCommerceAccountService::new(Arc::new(bank_repository),
+                                                         Arc::new(repository));
+
+        let error = service.process(50, 60).await.unwrap_err();
+
+        let bank_error = error.downcast_ref::<BankError>().unwrap();
+        assert_eq!(bank_error.get_code(), BankError::creditor_bank_not_active().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository
+            .expect_promote_commerce_account()
+            .returning(|_, _| Err(database_error_for_test()))
+            .times(1);
+
+        let service = PromoteCommerceAccountService::new(Arc::new(bank_repository),
+                                                         Arc::new(repository));
+
+        let error = service.process(50, 60).await.unwrap_err();
+
+        assert!(error.downcast_ref::<DatabaseError>().is_some());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_status::CommerceStatus;
+use crate::service::commerces::remove_commerce_account_service::RemoveCommerceAccountService;
+use crate::use_case::commerces::remove_commerce_account_use_case::RemoveCommerceAccountUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commerce_with_two_accounts() -> Commerce {
+        let mut commerce = create_commerce_for_test();
+        commerce.accounts.push(Account::new(
+            60,
+            String::from("550e8400-e29b-41d4-a716-446655440000"),
+            String::from("002"),
+            2
+        ));
+        commerce
+    }
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository
+            .expect_remove_commerce_account()
+            .with(eq(50), eq(60))
+            .returning(|_, _| Ok(Some(create_commerce_for_test())))
+            .times(1);
+
+        let service = RemoveCommerceAccountService::new(Arc::new(repository));
+
+        let result = service.process(50, 60).await.unwrap();
+
+        assert_eq!(result.accounts.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_process_primary_account_cannot_be_removed() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository.expect_remove_commerce_account().times(0);
+
+        let service = RemoveCommerceAccountService::new(Arc::new(repository));
+
+        let error = service.process(50, 59).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(),
+                   CommerceError::primary_account_cannot_be_removed().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_account_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(commerce_with_two_accounts())))
+            .times(1);
+        repository.expect_remove_commerce_account().times(0);
+
+        let service = RemoveCommerceAccountService::new(Arc::new(repository));
+
+        let error = service.process(50, 99).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), CommerceError::account_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_suspended_commerce() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| {
+                let mut commerce = commerce_with_two_accounts();
+                commerce.commerce_status = CommerceStatus::Suspended;
+                Ok(Some(commerce))
+            })
+            .times(1);
+        repository.expect_remove_commerce_account().times(0);
+
+        let service = RemoveCommerceAccountService::new(Arc::new(repository));
+
+        let error = service.process(50, 60).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(),
+                   CommerceError::accounts_locked_while_suspended().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_not_found() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_remove_commerce_account().times(0);
+
+        let service = RemoveCommerceAccountService::new(Arc::new(repository));
+
+        let error = service.process(99, 60).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), CommerceError::not_found().get_code());
+    }
+}
\ No newline at end of file
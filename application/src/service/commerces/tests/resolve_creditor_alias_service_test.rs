@@ -0,0 +1,223 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::bank_status::BankStatus;
+use domain::exception::user_error::UserError;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::status_creditor_user_info::StatusCreditorUserInfo;
+use crate::service::commerces::resolve_creditor_alias_service::ResolveCreditorAliasService;
+use crate::use_case::commerces::resolve_creditor_alias_use_case::ResolveCreditorAliasUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut stored_commerce = create_commerce_for_test();
+        stored_commerce.alias = String::from("@aliasTest");
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .with(eq(String::from("@aliasTest")))
+            .returning(move |_| Ok(Some(stored_commerce.clone())))
+            .times(1);
+        repository.expect_find_commerce_by_alias().times(0);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let creditor = service
+            .process(StatusCreditorUserInfo::new(String::from("aliasTest")))
+            .await
+            .unwrap();
+
+        assert_eq!(creditor.alias_value, "@aliasTest");
+        assert_eq!(creditor.legal_business_name, "le*** bu****** na** te**");
+        assert_eq!(creditor.bank_code, "841");
+        assert_eq!(creditor.commerce_status, CommerceStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_with_at_sign_is_not_prefixed_twice() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .with(eq(String::from("@aliasTest")))
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let result = service
+            .process(StatusCreditorUserInfo::new(String::from("@aliasTest")))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_empty_alias() {
+        let mut repository = MockCommerceRepo::new();
+        repository.expect_find_active_commerce_by_alias().times(0);
+        repository.expect_find_commerce_by_alias().times(0);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let error = service
+            .process(StatusCreditorUserInfo::new(String::from(" @ ")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(), "ERR-081");
+    }
+
+    #[tokio::test]
+    async fn test_process_unknown_alias() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository
+            .expect_find_commerce_by_alias()
+            .returning(|_| Ok(None))
+            .times(1);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let error = service
+            .process(StatusCreditorUserInfo::new(String::from("unknown")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(), "ERR-035");
+    }
+
+    #[tokio::test]
+    async fn test_process_inactive_commerce() {
+        let mut inactive_commerce = create_commerce_for_test();
+        inactive_commerce.commerce_status = CommerceStatus::Inactive;
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .returning(|_| Ok(None))
+            .times(1);
+        repository
+            .expect_find_commerce_by_alias()
+            .returning(move |_| Ok(Some(inactive_commerce.clone())))
+            .times(1);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let error = service
+            .process(StatusCreditorUserInfo::new(String::from("aliasTest")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-095");
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_held_by_inactive_and_active_commerces() {
+        // The alias was registered again after its first commerce was deactivated
+        let mut active_commerce = create_commerce_for_test();
+        active_commerce.commerce_id = 51;
+        active_commerce.alias = String::from("@aliasTest");
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .with(eq(String::from("@aliasTest")))
+            .returning(move |_| Ok(Some(active_commerce.clone())))
+            .times(1);
+        repository.expect_find_commerce_by_alias().times(0);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let creditor = service
+            .process(StatusCreditorUserInfo::new(String::from("aliasTest")))
+            .await
+            .unwrap();
+
+        assert_eq!(creditor.alias_value, "@aliasTest");
+        assert_eq!(creditor.commerce_status, CommerceStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_process_disabled_bank() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| {
+                let mut bank = create_bank_for_test();
+                bank.bank_status = BankStatus::Suspended;
+                Ok(Some(bank))
+            })
+            .times(1);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let error = service
+            .process(StatusCreditorUserInfo::new(String::from("aliasTest")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-088");
+    }
+
+    #[tokio::test]
+    async fn test_process_unknown_bank() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let service = ResolveCreditorAliasService::new(Arc::new(bank_repository), Arc::new(repository));
+
+        let error = service
+            .process(StatusCreditorUserInfo::new(String::from("aliasTest")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce_update::CommerceUpdate;
+use crate::service::commerces::update_commerce_service::UpdateCommerceService;
+use crate::use_case::commerces::update_commerce_use_case::UpdateCommerceUseCase;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test,
+                                              MockCommerceRepo, MockValidateCommerceToUpdateUseCase};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let current_commerce = create_commerce_for_test();
+        let mut validated_commerce = create_commerce_for_test();
+        validated_commerce.legal_business_name = String::from("new legal business name");
+        let validated_commerce_clone = validated_commerce.clone();
+        let commerce_update = CommerceUpdate::new(None, None,
+                                                  Some(String::from("new legal business name")), None);
+
+        let mut validator = MockValidateCommerceToUpdateUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+
+        repository
+            .expect_find_commerce_by_id()
+            .with(eq(50))
+            .returning(move |_| Ok(Some(current_commerce.clone())))
+            .times(1);
+        validator
+            .expect_process()
+            .with(eq(create_commerce_for_test()), eq(commerce_update.clone()))
+            .returning(move |_, _| Ok(validated_commerce.clone()))
+            .times(1);
+        repository
+            .expect_update_commerce()
+            .with(eq(validated_commerce_clone.clone()))
+            .returning(|commerce| Ok(commerce.clone()))
+            .times(1);
+
+        let service = UpdateCommerceService::new(Arc::new(validator), Arc::new(repository));
+
+        let result = service.process(50, commerce_update).await;
+
+        assert_eq!(result.unwrap(), validated_commerce_clone);
+    }
+
+    #[tokio::test]
+    async fn test_process_commerce_not_found() {
+        let mut validator = MockValidateCommerceToUpdateUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(None))
+            .times(1);
+        validator.expect_process().times(0);
+        repository.expect_update_commerce().times(0);
+
+        let service = UpdateCommerceService::new(Arc::new(validator), Arc::new(repository));
+
+        let error = service.process(404, CommerceUpdate::default()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-096");
+    }
+
+    #[tokio::test]
+    async fn test_process_validation_error() {
+        let mut validator = MockValidateCommerceToUpdateUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        validator
+            .expect_process()
+            .returning(|_, _| Err(CommerceError::ruc_cannot_be_modified().into()))
+            .times(1);
+        repository.expect_update_commerce().times(0);
+
+        let service = UpdateCommerceService::new(Arc::new(validator), Arc::new(repository));
+
+        let result = service.process(50, CommerceUpdate::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_repository_error() {
+        let mut validator = MockValidateCommerceToUpdateUseCase::new();
+        let mut repository = MockCommerceRepo::new();
+
+        repository
+            .expect_find_commerce_by_id()
+            .returning(|_| Ok(Some(create_commerce_for_test())))
+            .times(1);
+        validator
+            .expect_process()
+            .returning(|_, _| Ok(create_commerce_for_test()))
+            .times(1);
+        repository
+            .expect_update_commerce()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = UpdateCommerceService::new(Arc::new(validator), Arc::new(repository));
+
+        let result = service.process(50, CommerceUpdate::default()).await;
+
+        assert!(result.is_err());
+    }
+}
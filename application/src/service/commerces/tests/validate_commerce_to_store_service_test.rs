@@ -0,0 +1,182 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::bank_status::BankStatus;
+use crate::service::commerces::validate_commerce_to_store_service::ValidateCommerceToStoreService;
+use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_new_alias_for_existing_ruc_success() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| {
+                let mut bank = create_bank_for_test();
+                bank.bank_id = 7;
+                Ok(Some(bank))
+            })
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_commerce_does_not_exist_by_alias()
+            .with(eq(String::from("@aliasTest")))
+            .returning(|_| Ok(true))
+            .times(1);
+        // The RUC is already registered under another alias with the same legal business name
+        commerce_repository
+            .expect_commerce_exists_by_ruc_or_legal_business_name()
+            .with(eq(String::from("123456789-9-2099")), eq(String::from("legal business name test")))
+            .returning(|_, _| Ok(true))
+            .times(1);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let commerce = service.process(create_commerce_for_test()).await.unwrap();
+
+        assert_eq!(commerce.alias, "@aliasTest");
+        // The account is stored with the bank found by the validation
+        assert_eq!(commerce.account.bank_id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_already_exists() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(false))
+            .times(1);
+        commerce_repository.expect_commerce_exists_by_ruc_or_legal_business_name().times(0);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let error = service.process(create_commerce_for_test()).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(), CommerceError::alias_already_exists().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_ruc_legal_business_name_mismatch() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+        commerce_repository
+            .expect_commerce_exists_by_ruc_or_legal_business_name()
+            .returning(|_, _| Ok(false))
+            .times(1);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let error = service.process(create_commerce_for_test()).await.unwrap_err();
+
+        let commerce_error = error.downcast_ref::<CommerceError>().unwrap();
+        assert_eq!(commerce_error.get_code(),
+                   CommerceError::ruc_legal_business_does_not_match().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_unknown_bank() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| Ok(None))
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+        commerce_repository.expect_commerce_exists_by_ruc_or_legal_business_name().times(0);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let error = service.process(create_commerce_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-002");
+    }
+
+    #[tokio::test]
+    async fn test_process_disabled_bank() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("841")))
+            .returning(|_| {
+                let mut bank = create_bank_for_test();
+                bank.bank_status = BankStatus::Suspended;
+                Ok(Some(bank))
+            })
+            .times(1);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository
+            .expect_commerce_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+        commerce_repository.expect_commerce_exists_by_ruc_or_legal_business_name().times(0);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let error = service.process(create_commerce_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-088");
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_bank_code_format() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository.expect_commerce_does_not_exist_by_alias().times(0);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        let mut commerce = create_commerce_for_test();
+        commerce.account.bank_code = String::from("BOA001");
+        let error = service.process(commerce).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-087");
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_format_checked_on_stored_form() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut commerce_repository = MockCommerceRepo::new();
+        commerce_repository.expect_commerce_does_not_exist_by_alias().times(0);
+
+        let service = ValidateCommerceToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(commerce_repository));
+
+        // 26 characters once the "@" is added, one more than the alias type allows
+        let mut commerce = create_commerce_for_test();
+        commerce.alias = "a".repeat(26);
+        let error = service.process(commerce).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(),
+                   CommerceError::not_valid_alias_format().get_code());
+    }
+}
@@ -0,0 +1,140 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_update::CommerceUpdate;
+use crate::service::commerces::validate_commerce_to_update_service::ValidateCommerceToUpdateService;
+use crate::use_case::commerces::validate_commerce_to_update::ValidateCommerceToUpdate;
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, MockCommerceRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored_commerce() -> Commerce {
+        let mut commerce = create_commerce_for_test();
+        commerce.alias = String::from("@aliasTest");
+        commerce
+    }
+
+    #[tokio::test]
+    async fn test_process_rename_success() {
+        let mut repository = MockCommerceRepo::new();
+        repository.expect_find_active_commerce_by_alias().times(0);
+        repository
+            .expect_commerce_exists_by_ruc_or_legal_business_name_excluding()
+            .with(eq(50), eq(String::from("123456789-9-2099")), eq(String::from("new name")))
+            .returning(|_, _, _| Ok(true))
+            .times(1);
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let commerce = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(None, None, Some(String::from(" new name ")),
+                                         Some(String::from("123456789-9-2099"))))
+            .await
+            .unwrap();
+
+        assert_eq!(commerce.alias, "@aliasTest");
+        assert_eq!(commerce.legal_business_name, "new name");
+        assert_eq!(commerce.ruc, "123456789-9-2099");
+    }
+
+    #[tokio::test]
+    async fn test_process_ruc_is_immutable() {
+        let mut repository = MockCommerceRepo::new();
+        repository.expect_find_active_commerce_by_alias().times(0);
+        repository.expect_commerce_exists_by_ruc_or_legal_business_name_excluding().times(0);
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let error = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(None, None, None, Some(String::from("999999"))))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-097");
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_alias_format() {
+        let repository = MockCommerceRepo::new();
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let error = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(Some(String::from("@@")), None, None, None))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-003");
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_already_exists() {
+        let mut other_commerce = stored_commerce();
+        other_commerce.commerce_id = 51;
+        other_commerce.alias = String::from("@otherAlias");
+
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_find_active_commerce_by_alias()
+            .with(eq(String::from("@otherAlias")))
+            .returning(move |_| Ok(Some(other_commerce.clone())))
+            .times(1);
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let error = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(Some(String::from("otherAlias")), None, None, None))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-008");
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_of_inactive_commerce_can_be_taken() {
+        let mut repository = MockCommerceRepo::new();
+        // Only an inactive commerce holds the alias, so no active commerce is found
+        repository
+            .expect_find_active_commerce_by_alias()
+            .with(eq(String::from("@otherAlias")))
+            .returning(|_| Ok(None))
+            .times(1);
+        repository.expect_find_commerce_by_alias().times(0);
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let commerce = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(Some(String::from("otherAlias")), None, None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(commerce.alias, "@otherAlias");
+    }
+
+    #[tokio::test]
+    async fn test_process_legal_business_name_does_not_match_ruc() {
+        let mut repository = MockCommerceRepo::new();
+        repository
+            .expect_commerce_exists_by_ruc_or_legal_business_name_excluding()
+            .returning(|_, _, _| Ok(false))
+            .times(1);
+
+        let service = ValidateCommerceToUpdateService::new(Arc::new(repository));
+
+        let error = service
+            .process(&stored_commerce(),
+                     CommerceUpdate::new(None, None, Some(String::from("another name")), None))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(), "ERR-093");
+    }
+}
@@ -0,0 +1,45 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::info;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_update::CommerceUpdate;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::commerce_lookup::find_existing_commerce;
+use crate::use_case::commerces::update_commerce_use_case::UpdateCommerceUseCase;
+use crate::use_case::commerces::validate_commerce_to_update::ValidateCommerceToUpdate;
+
+pub struct UpdateCommerceService<VU: ValidateCommerceToUpdate, CR: CommerceRepositoryPort> {
+    validate_commerce_to_update_use_case: Arc<VU>,
+    commerce_repository: Arc<CR>
+}
+
+impl<VU: ValidateCommerceToUpdate, CR: CommerceRepositoryPort> UpdateCommerceService<VU, CR> {
+    pub fn new(validate_commerce_to_update_use_case: Arc<VU>, commerce_repository: Arc<CR>) -> Self {
+        Self {
+            validate_commerce_to_update_use_case,
+            commerce_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<VU, CR> UpdateCommerceUseCase for UpdateCommerceService<VU, CR>
+where
+    VU: ValidateCommerceToUpdate + Send + Sync + 'static,
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_id: i64, commerce_update: CommerceUpdate)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        let current_commerce = find_existing_commerce(self.commerce_repository.as_ref(),
+                                                      commerce_id).await?;
+        info!("Validating update {:?} for commerce: {:?}", commerce_update, current_commerce);
+        let valid_commerce = self.validate_commerce_to_update_use_case
+            .process(&current_commerce, commerce_update).await?;
+        info!("Updating commerce: {:?}", valid_commerce);
+        match self.commerce_repository.update_commerce(&valid_commerce).await {
+            Ok(commerce_updated) => Ok(commerce_updated),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
\ No newline at end of file
@@ -6,20 +6,17 @@ use regex::Regex;
 use tracing::{error, info};
 use domain::exception::bank_error::BankError;
 use domain::exception::commerce_error::CommerceError;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
 use domain::model::commerce::Commerce;
+use domain::model::party_kind::PartyKind;
 use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
 use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::banks::bank_code_format::bank_code_format;
+use crate::service::commerces::alias_type_catalog::alias_type_catalog;
 use crate::use_case::commerces::validate_commerce_to_store::ValidateCommerceToStore;
 
-
-const MIN_LENGTH: u8 = 3;
-const MAX_LENGTH: u8 = 25;
 lazy_static! {
-    static ref ALIAS_REGEX: Regex = {
-        let pattern = format!("^[A-Za-z0-9]{{{},{}}}", MIN_LENGTH, MAX_LENGTH);
-        Regex::new(&pattern).unwrap()
-    };
-
     static ref RUC_REGEX: Regex = {
         Regex::new("^[0-9-]{1,25}$").unwrap()
     };
@@ -42,9 +39,11 @@ impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> ValidateCommerceToStore
         }
     }
 
-    async fn validate_commerce_logic(&self, commerce: &Commerce) -> Result<(), Box<dyn StdError + Send + Sync>> {
+    // Returns the id of the settling bank found while validating
+    async fn validate_commerce_logic(&self, commerce: &Commerce) -> Result<i64, Box<dyn StdError + Send + Sync>> {
+        // A legal entity can own several aliases, so only the alias has to be unique
         match self.commerce_repository
-            .commerce_does_not_exist_by_ruc_and_alias(&commerce.ruc, &commerce.alias)
+            .commerce_does_not_exist_by_alias(&commerce.alias)
             .await
         {
             Ok(true) => (),
@@ -58,20 +57,8 @@ impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> ValidateCommerceToStore
             }
         }
 
-        match self.bank_repository
-            .validate_if_bank_exists_exists_by_bank_code(&commerce.account.bank_code)
-            .await
-        {
-            Ok(false) => {
-                error!("Creditor bank code '{}' does not exist", commerce.account.bank_code);
-                return Err(BankError::creditor_bank_not_found().into())
-            },
-            Ok(true) => (),
-            Err(e) => {
-                error!("There was an error in the database. Error is: {:?}", e);
-                return Err(e.into())
-            }
-        }
+        let bank = validate_creditor_bank_is_active(self.bank_repository.as_ref(),
+                                                    &commerce.account.bank_code).await?;
 
         match self.commerce_repository
             .commerce_exists_by_ruc_or_legal_business_name(&commerce.ruc, &commerce.legal_business_name)
@@ -89,7 +76,7 @@ impl<BR: BankRepositoryPort, CR: CommerceRepositoryPort> ValidateCommerceToStore
             }
         }
 
-        Ok(())
+        Ok(bank.bank_id)
     }
 }
 
@@ -101,27 +88,49 @@ where
     CR: CommerceRepositoryPort + Send + Sync + 'static
 {
     async fn process(&self, commerce: Commerce) -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
-        info!("Validating commerce field formats");
-        validate_commerce_field_formats(&commerce)?;
-        info!("Commerce field formats are valid for commerce {:?}", commerce);
         info!("Formatting alias value an erasing blank spaces in legal business name");
         let mut commerce_validated = commerce;
-        commerce_validated.alias = format!("@{}", commerce_validated.alias);
+        // Alias types describe the stored alias, so the format is checked after adding the "@"
+        commerce_validated.alias = Commerce::canonical_alias(&commerce_validated.alias);
+        info!("Validating commerce field formats");
+        validate_commerce_field_formats(&commerce_validated)?;
+        info!("Commerce field formats are valid for commerce {:?}", commerce_validated);
         commerce_validated.legal_business_name = commerce_validated.legal_business_name.trim()
             .to_string();
         info!("Validating commerce logic for commerce {:?}", commerce_validated);
-        self.validate_commerce_logic(&commerce_validated).await?;
+        commerce_validated.account.bank_id = self.validate_commerce_logic(&commerce_validated).await?;
         info!("Commerce logic is valid for commerce {:?}", commerce_validated);
         Ok(commerce_validated)
     }
 }
 
-fn validate_commerce_field_formats(commerce: &Commerce) -> Result<(), CommerceError> {
-    if commerce.alias_type != 2 {
+// Returns the bank so its id can be used to store the account without another lookup
+pub(crate) async fn validate_creditor_bank_is_active<BR: BankRepositoryPort>(bank_repository: &BR,
+                                                                            bank_code: &str)
+    -> Result<Bank, Box<dyn StdError + Send + Sync>> {
+    match bank_repository.find_bank_by_code(bank_code).await {
+        Ok(None) => {
+            error!("Creditor bank code '{}' does not exist", bank_code);
+            Err(BankError::creditor_bank_not_found().into())
+        },
+        Ok(Some(bank)) if bank.bank_status != BankStatus::Enabled => {
+            error!("Creditor bank code '{}' is {}", bank_code, bank.bank_status.status_name());
+            Err(BankError::creditor_bank_not_active().into())
+        },
+        Ok(Some(bank)) => Ok(bank),
+        Err(e) => {
+            error!("There was an error in the database. Error is: {:?}", e);
+            Err(e.into())
+        }
+    }
+}
+
+pub(crate) fn validate_commerce_field_formats(commerce: &Commerce) -> Result<(), CommerceError> {
+    if !alias_type_catalog().is_allowed(commerce.alias_type, PartyKind::Commerce) {
         error!("Invalid alias type: {}", commerce.alias_type);
         return Err(CommerceError::not_valid_alias_type());
     }
-    if is_invalid_alias(&commerce.alias) {
+    if !alias_type_catalog().is_valid_alias(commerce.alias_type, &commerce.alias) {
         error!("Invalid alias format: {}", commerce.alias);
         return Err(CommerceError::not_valid_alias_format());
     }
@@ -133,26 +142,35 @@ fn validate_commerce_field_formats(commerce: &Commerce) -> Result<(), CommerceEr
         error!("Invalid RUC: {}", commerce.ruc);
         return Err(CommerceError::not_valid_ruc());
     }
-    if is_invalid_account_number(&commerce.account.account_number) {
-        error!("Invalid account number: {}", commerce.account.account_number);
+    validate_account_number_format(&commerce.account.account_number)?;
+    validate_bank_code_format(&commerce.account.bank_code)
+}
+
+pub(crate) fn validate_account_number_format(account_number: &str) -> Result<(), CommerceError> {
+    if is_invalid_account_number(account_number) {
+        error!("Invalid account number: {}", account_number);
         return Err(CommerceError::not_valid_account_format())
     }
     Ok(())
 }
 
-fn is_invalid_alias(alias: &String) -> bool {
-    !ALIAS_REGEX.is_match(alias)
+pub(crate) fn validate_bank_code_format(bank_code: &str) -> Result<(), CommerceError> {
+    if !bank_code_format().is_valid(bank_code) {
+        error!("Invalid bank code: {}", bank_code);
+        return Err(CommerceError::not_valid_format_bank())
+    }
+    Ok(())
 }
 
 fn is_invalid_legal_business(legal_business_name: &str) -> bool {
     legal_business_name.len() > 255 //I am only going to use ASCII characters
 }
 
-fn is_invalid_ruc(ruc: &String) -> bool {
+pub(crate) fn is_invalid_ruc(ruc: &str) -> bool {
     !RUC_REGEX.is_match(ruc)
 }
 
-fn is_invalid_account_number(account_number: &String) -> bool {
+fn is_invalid_account_number(account_number: &str) -> bool {
     !ACCOUNT_NUMBER_REGEX.is_match(account_number)
 }
 
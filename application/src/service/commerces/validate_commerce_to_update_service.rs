@@ -0,0 +1,105 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::commerce_error::CommerceError;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_update::CommerceUpdate;
+use crate::port::db::commerces::commerce_repository_port::CommerceRepositoryPort;
+use crate::service::commerces::validate_commerce_to_store_service::validate_commerce_field_formats;
+use crate::use_case::commerces::validate_commerce_to_update::ValidateCommerceToUpdate;
+
+pub struct ValidateCommerceToUpdateService<CR: CommerceRepositoryPort> {
+    commerce_repository: Arc<CR>
+}
+
+impl<CR: CommerceRepositoryPort> ValidateCommerceToUpdateService<CR> {
+    pub fn new(commerce_repository: Arc<CR>) -> Self {
+        Self {
+            commerce_repository
+        }
+    }
+
+    async fn validate_commerce_logic(&self, current_commerce: &Commerce,
+                                     commerce_to_update: &Commerce)
+        -> Result<(), Box<dyn StdError + Send + Sync>> {
+        // Like on registration, only an active commerce holds its alias, an inactive one can
+        // lose it to another commerce
+        if commerce_to_update.alias != current_commerce.alias {
+            match self.commerce_repository
+                .find_active_commerce_by_alias(&commerce_to_update.alias)
+                .await
+            {
+                Ok(Some(commerce_found))
+                if commerce_found.commerce_id != current_commerce.commerce_id => {
+                    error!("Alias '{}' already exists", commerce_to_update.alias);
+                    return Err(CommerceError::alias_already_exists().into())
+                },
+                Ok(_) => (),
+                Err(e) => {
+                    error!("There was an error in the database. Error is: {:?}", e);
+                    return Err(e.into())
+                }
+            }
+        }
+
+        if commerce_to_update.legal_business_name != current_commerce.legal_business_name {
+            match self.commerce_repository
+                .commerce_exists_by_ruc_or_legal_business_name_excluding(
+                    &current_commerce.commerce_id, &commerce_to_update.ruc,
+                    &commerce_to_update.legal_business_name)
+                .await
+            {
+                Ok(false) => {
+                    error!("Ruc: {:?} and legal business name: {:?} does not match",
+                        commerce_to_update.ruc, commerce_to_update.legal_business_name);
+                    return Err(CommerceError::ruc_legal_business_does_not_match().into())
+                },
+                Ok(true) => (),
+                Err(e) => {
+                    error!("There was an error in the database. Error is: {:?}", e);
+                    return Err(e.into())
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<CR> ValidateCommerceToUpdate for ValidateCommerceToUpdateService<CR>
+where
+    CR: CommerceRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, current_commerce: &Commerce, commerce_update: CommerceUpdate)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>> {
+        if let Some(ruc) = &commerce_update.ruc {
+            if ruc.trim() != current_commerce.ruc {
+                error!("Ruc '{}' of commerce {} cannot be changed to '{}'", current_commerce.ruc,
+                    current_commerce.commerce_id, ruc);
+                return Err(CommerceError::ruc_cannot_be_modified().into());
+            }
+        }
+
+        let mut commerce_to_update = current_commerce.clone();
+        // Alias types describe the stored alias, so the format is checked after adding the "@"
+        commerce_to_update.alias = commerce_update.alias
+            .map_or_else(|| current_commerce.alias.clone(),
+                         |alias| Commerce::canonical_alias(&alias));
+        commerce_to_update.alias_type = commerce_update.alias_type
+            .unwrap_or(current_commerce.alias_type);
+        commerce_to_update.legal_business_name = commerce_update.legal_business_name
+            .unwrap_or_else(|| current_commerce.legal_business_name.clone());
+
+        info!("Validating commerce field formats");
+        validate_commerce_field_formats(&commerce_to_update)?;
+        info!("Commerce field formats are valid for commerce {:?}", commerce_to_update);
+        commerce_to_update.legal_business_name = commerce_to_update.legal_business_name.trim()
+            .to_string();
+        info!("Validating commerce logic for commerce {:?}", commerce_to_update);
+        self.validate_commerce_logic(current_commerce, &commerce_to_update).await?;
+        info!("Commerce logic is valid for commerce {:?}", commerce_to_update);
+        Ok(commerce_to_update)
+    }
+}
\ No newline at end of file
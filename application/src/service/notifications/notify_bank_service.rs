@@ -0,0 +1,93 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info, warn};
+use domain::model::bank::Bank;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+use domain::model::notification::Notification;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::notifications::notification_port::NotificationPort;
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+
+// The first line of each template is the subject, the rest is the body
+const COMMERCE_CREATED_TEMPLATE: &str = include_str!("templates/commerce_created.txt");
+const COMMERCE_DEACTIVATED_TEMPLATE: &str = include_str!("templates/commerce_deactivated.txt");
+const COMMERCE_ACCOUNT_CHANGED_TEMPLATE: &str = include_str!("templates/commerce_account_changed.txt");
+
+pub struct NotifyBankService<BR: BankRepositoryPort, NP: NotificationPort> {
+    bank_repository: Arc<BR>,
+    notification_port: Arc<NP>
+}
+
+impl<BR: BankRepositoryPort, NP: NotificationPort> NotifyBankService<BR, NP> {
+    pub fn new(bank_repository: Arc<BR>, notification_port: Arc<NP>) -> Self {
+        Self {
+            bank_repository,
+            notification_port
+        }
+    }
+
+    async fn find_settling_bank(&self, commerce: &Commerce) -> Option<Bank> {
+        match self.bank_repository.find_bank_by_code(&commerce.account.bank_code).await {
+            Ok(Some(bank)) => Some(bank),
+            Ok(None) => {
+                warn!("Bank '{}' of commerce {} does not exist", commerce.account.bank_code,
+                    commerce.commerce_id);
+                None
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<BR, NP> NotifyBankUseCase for NotifyBankService<BR, NP>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    NP: NotificationPort + Send + Sync + 'static
+{
+    async fn process(&self, commerce_event: CommerceEvent, commerce: &Commerce) {
+        let Some(bank) = self.find_settling_bank(commerce).await else {
+            return;
+        };
+        if bank.notification_mail.trim().is_empty() {
+            warn!("Bank {} has no notification mail, {} is not sent", bank.bank_code,
+                commerce_event.event_name());
+            return;
+        }
+
+        let notification = render_notification(commerce_event, &bank, commerce);
+        info!("Notifying {} of commerce {} to bank {}", commerce_event.event_name(),
+            commerce.commerce_id, bank.bank_code);
+        if let Err(e) = self.notification_port.send(&notification).await {
+            error!("Notification {} to bank {} could not be sent. Error is: {:?}",
+                commerce_event.event_name(), bank.bank_code, e);
+        }
+    }
+}
+
+pub(crate) fn render_notification(commerce_event: CommerceEvent, bank: &Bank, commerce: &Commerce)
+    -> Notification {
+    let template = match commerce_event {
+        CommerceEvent::Created => COMMERCE_CREATED_TEMPLATE,
+        CommerceEvent::Deactivated => COMMERCE_DEACTIVATED_TEMPLATE,
+        CommerceEvent::AccountChanged => COMMERCE_ACCOUNT_CHANGED_TEMPLATE,
+    };
+    let values = [
+        ("bank_name", bank.bank_name.clone()),
+        ("commerce_id", commerce.commerce_id.to_string()),
+        ("alias", commerce.alias.clone()),
+        ("legal_business_name", commerce.legal_business_name.clone()),
+        ("ruc", commerce.ruc.clone()),
+        ("account_number", commerce.account.account_number.clone()),
+    ];
+    let rendered = values.iter().fold(String::from(template), |text, (key, value)| {
+        text.replace(&format!("{{{{{}}}}}", key), value)
+    });
+    let (subject, body) = rendered.split_once('\n').unwrap_or((rendered.as_str(), ""));
+    Notification::new(bank.notification_mail.clone(), subject.trim().to_string(),
+                      body.trim_start().to_string())
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use std::error::Error;
+use mockall::mock;
+use async_trait::async_trait;
+use domain::model::notification::Notification;
+use crate::port::notifications::notification_port::NotificationPort;
+
+mock! {
+    pub NotificationPort {}
+
+    #[async_trait]
+    impl NotificationPort for NotificationPort {
+        async fn send(&self, notification: &Notification) -> Result<(), Box<dyn Error + Send + Sync>>;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use domain::model::commerce_event::CommerceEvent;
+use crate::service::notifications::notify_bank_service::{render_notification, NotifyBankService};
+use crate::use_case::notifications::notify_bank_use_case::NotifyBankUseCase;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::commerces::tests::mocks::{create_commerce_for_test, database_error_for_test};
+use crate::service::notifications::tests::mocks::MockNotificationPort;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_sends_to_notification_mail() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .withf(|bank_code| bank_code == "841")
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut notification_port = MockNotificationPort::new();
+        notification_port
+            .expect_send()
+            .withf(|notification| {
+                notification.recipient == "notifications@boa.com"
+                    && notification.subject == "New commerce aliasTest registered"
+                    && notification.body.contains("registered with id 50")
+            })
+            .returning(|_| Ok(()))
+            .times(1);
+
+        let service = NotifyBankService::new(Arc::new(bank_repository),
+                                             Arc::new(notification_port));
+
+        service.process(CommerceEvent::Created, &create_commerce_for_test()).await;
+    }
+
+    #[tokio::test]
+    async fn test_process_bank_not_found() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        let mut notification_port = MockNotificationPort::new();
+        notification_port.expect_send().times(0);
+
+        let service = NotifyBankService::new(Arc::new(bank_repository),
+                                             Arc::new(notification_port));
+
+        service.process(CommerceEvent::Deactivated, &create_commerce_for_test()).await;
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error_is_not_propagated() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+        let mut notification_port = MockNotificationPort::new();
+        notification_port.expect_send().times(0);
+
+        let service = NotifyBankService::new(Arc::new(bank_repository),
+                                             Arc::new(notification_port));
+
+        service.process(CommerceEvent::Deactivated, &create_commerce_for_test()).await;
+    }
+
+    #[tokio::test]
+    async fn test_process_send_error_is_not_propagated() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut notification_port = MockNotificationPort::new();
+        notification_port
+            .expect_send()
+            .returning(|_| Err(Box::from("SMTP server unavailable")))
+            .times(1);
+
+        let service = NotifyBankService::new(Arc::new(bank_repository),
+                                             Arc::new(notification_port));
+
+        service.process(CommerceEvent::AccountChanged, &create_commerce_for_test()).await;
+    }
+
+    #[test]
+    fn test_render_account_changed_notification() {
+        let notification = render_notification(CommerceEvent::AccountChanged,
+                                                &create_bank_for_test(),
+                                                &create_commerce_for_test());
+
+        assert_eq!(notification.subject, "Settlement account of commerce aliasTest changed");
+        assert!(notification.body.starts_with("Dear Bank of America,"));
+        assert!(notification.body.contains("84102e21-01b5-4f5d-8771-36f915c4d29e"));
+        assert!(!notification.body.contains("{{"));
+    }
+}
\ No newline at end of file
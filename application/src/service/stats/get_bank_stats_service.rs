@@ -0,0 +1,47 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::bank_stats_filter::BankStatsFilter;
+use crate::port::db::stats::bank_stats_repository_port::BankStatsRepositoryPort;
+use crate::use_case::stats::get_bank_stats_use_case::GetBankStatsUseCase;
+
+pub struct GetBankStatsService<SR: BankStatsRepositoryPort> {
+    bank_stats_repository: Arc<SR>
+}
+
+impl<SR: BankStatsRepositoryPort> GetBankStatsService<SR> {
+    pub fn new(bank_stats_repository: Arc<SR>) -> Self {
+        Self {
+            bank_stats_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<SR> GetBankStatsUseCase for GetBankStatsService<SR>
+where
+    SR: BankStatsRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, filter: BankStatsFilter)
+        -> Result<Vec<BankCommerceStats>, Box<dyn StdError + Send + Sync>> {
+        if let (Some(created_from), Some(created_to)) = (filter.created_from, filter.created_to) {
+            if created_from > created_to {
+                error!("Statistics date range starts on {} after it ends on {}", created_from,
+                    created_to);
+                return Err(BankError::not_valid_stats_date_range().into());
+            }
+        }
+
+        info!("Getting bank statistics with filter {:?}", filter);
+        match self.bank_stats_repository.find_bank_commerce_stats(&filter).await {
+            Ok(stats) => Ok(stats),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use chrono::NaiveDate;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_stats_filter::BankStatsFilter;
+use crate::service::stats::get_bank_stats_service::GetBankStatsService;
+use crate::use_case::stats::get_bank_stats_use_case::GetBankStatsUseCase;
+use crate::service::stats::tests::mocks::{create_bank_commerce_stats_for_test, MockBankStatsRepo};
+use crate::service::commerces::tests::mocks::database_error_for_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let filter = BankStatsFilter::new(NaiveDate::from_ymd_opt(2025, 9, 1),
+                                          NaiveDate::from_ymd_opt(2025, 9, 30));
+
+        let mut repository = MockBankStatsRepo::new();
+        repository
+            .expect_find_bank_commerce_stats()
+            .with(eq(filter.clone()))
+            .returning(|_| Ok(vec![create_bank_commerce_stats_for_test()]))
+            .times(1);
+
+        let service = GetBankStatsService::new(Arc::new(repository));
+
+        let stats = service.process(filter).await.unwrap();
+
+        assert_eq!(stats, vec![create_bank_commerce_stats_for_test()]);
+    }
+
+    #[tokio::test]
+    async fn test_process_without_dates() {
+        let mut repository = MockBankStatsRepo::new();
+        repository
+            .expect_find_bank_commerce_stats()
+            .with(eq(BankStatsFilter::default()))
+            .returning(|_| Ok(vec![]))
+            .times(1);
+
+        let service = GetBankStatsService::new(Arc::new(repository));
+
+        let stats = service.process(BankStatsFilter::default()).await.unwrap();
+
+        assert!(stats.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_date_range_reversed() {
+        let mut repository = MockBankStatsRepo::new();
+        repository.expect_find_bank_commerce_stats().times(0);
+
+        let service = GetBankStatsService::new(Arc::new(repository));
+
+        let error = service
+            .process(BankStatsFilter::new(NaiveDate::from_ymd_opt(2025, 10, 1),
+                                          NaiveDate::from_ymd_opt(2025, 9, 1)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(), "ERR-115");
+    }
+
+    #[tokio::test]
+    async fn test_process_database_error() {
+        let mut repository = MockBankStatsRepo::new();
+        repository
+            .expect_find_bank_commerce_stats()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let service = GetBankStatsService::new(Arc::new(repository));
+
+        let result = service.process(BankStatsFilter::default()).await;
+
+        assert!(result.is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use mockall::mock;
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::bank_stats_filter::BankStatsFilter;
+use crate::port::db::stats::bank_stats_repository_port::BankStatsRepositoryPort;
+
+mock! {
+    pub BankStatsRepo {}
+
+    #[async_trait]
+    impl BankStatsRepositoryPort for BankStatsRepo {
+        async fn find_bank_commerce_stats(&self, filter: &BankStatsFilter)
+        -> Result<Vec<BankCommerceStats>, DatabaseError>;
+    }
+}
+
+pub fn create_bank_commerce_stats_for_test() -> BankCommerceStats {
+    BankCommerceStats::new(
+        String::from("001"),
+        String::from("Bank of America"),
+        String::from("ACTIVE"),
+        String::from("COMMERCE"),
+        Some(String::from("2025-09")),
+        12
+    )
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::user_error::UserError;
+use domain::model::debtor_user_info::DebtorUserInfo;
+use domain::model::status_debtor_user_info::StatusDebtorUserInfo;
+use domain::model::user::User;
+use crate::port::db::countries::country_repository_port::CountryRepositoryPort;
+use crate::port::db::users::user_repository_port::UserRepositoryPort;
+use crate::service::banks::bank_code_format::validate_debtor_bank_code_format;
+use crate::service::users::validate_user_to_store_service::find_country;
+use crate::use_case::users::check_debtor_status_use_case::CheckDebtorStatusUseCase;
+
+pub struct CheckDebtorStatusService<UR: UserRepositoryPort, CO: CountryRepositoryPort> {
+    user_repository: Arc<UR>,
+    country_repository: Arc<CO>
+}
+
+impl<UR: UserRepositoryPort, CO: CountryRepositoryPort> CheckDebtorStatusService<UR, CO> {
+    pub fn new(user_repository: Arc<UR>, country_repository: Arc<CO>) -> Self {
+        Self {
+            user_repository,
+            country_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<UR, CO> CheckDebtorStatusUseCase for CheckDebtorStatusService<UR, CO>
+where
+    UR: UserRepositoryPort + Send + Sync + 'static,
+    CO: CountryRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, status_debtor_user_info: StatusDebtorUserInfo)
+        -> Result<DebtorUserInfo, Box<dyn StdError + Send + Sync>> {
+        let bank_code = status_debtor_user_info.bank_code.trim().to_string();
+        validate_debtor_field_formats(&status_debtor_user_info.alias_value, &bank_code)?;
+        let country_code = status_debtor_user_info.country_code;
+        let Some(country) = find_country(self.country_repository.as_ref(), country_code).await? else {
+            error!("Debtor country code {} does not exist", country_code);
+            return Err(UserError::debtor_invalid_country_code().into());
+        };
+        let Some(alias) = User::canonical_phone_alias(&status_debtor_user_info.alias_value, &country)
+        else {
+            error!("Invalid debtor alias format: {}", status_debtor_user_info.alias_value);
+            return Err(UserError::debtor_invalid_alias().into());
+        };
+
+        info!("Checking status of debtor alias: {}", alias);
+        let user = match self.user_repository.find_user_by_alias(&alias).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                error!("Debtor alias '{}' does not exist", alias);
+                return Err(UserError::debtor_not_found().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into())
+            }
+        };
+        // The debtor is only known to the bank holding its account
+        if user.account.bank_code != bank_code {
+            error!("Debtor alias '{}' belongs to bank {}, not to bank {}", alias,
+                user.account.bank_code, bank_code);
+            return Err(UserError::debtor_not_found().into());
+        }
+        if user.country != country_code {
+            error!("Debtor alias '{}' is registered in country {}, not in {}", alias, user.country,
+                country_code);
+            return Err(UserError::debtor_invalid_country_code().into());
+        }
+        if !user.user_status.is_active() {
+            error!("Debtor alias '{}' is {}", alias, user.user_status.status_name);
+            return Err(UserError::debtor_inactive().into());
+        }
+        info!("Debtor alias '{}' is active", alias);
+        Ok(DebtorUserInfo::new(user.alias, user.account.bank_code, user.country, user.user_status))
+    }
+}
+
+fn validate_debtor_field_formats(alias: &str, bank_code: &str) -> Result<(), UserError> {
+    if alias.trim().is_empty() {
+        return Err(UserError::debtor_null());
+    }
+    validate_debtor_bank_code_format(bank_code)
+}
\ No newline at end of file
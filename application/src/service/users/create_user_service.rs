@@ -0,0 +1,45 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::model::user::User;
+use domain::model::user_status::UserStatus;
+use crate::port::db::users::user_repository_port::UserRepositoryPort;
+use crate::use_case::users::create_user_use_case::CreateUserUseCase;
+use crate::use_case::users::validate_user_to_store::ValidateUserToStore;
+
+pub struct CreateUserService<VU: ValidateUserToStore, UR: UserRepositoryPort> {
+    validate_user_to_store_use_case: Arc<VU>,
+    user_repository: Arc<UR>
+}
+
+impl<VU: ValidateUserToStore, UR: UserRepositoryPort> CreateUserService<VU, UR> {
+    pub fn new(validate_user_to_store_use_case: Arc<VU>, user_repository: Arc<UR>) -> Self {
+        Self {
+            validate_user_to_store_use_case,
+            user_repository
+        }
+    }
+}
+
+#[async_trait]
+impl<VU, UR> CreateUserUseCase for CreateUserService<VU, UR>
+where
+    VU: ValidateUserToStore + Send + Sync + 'static,
+    UR: UserRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, user: User) -> Result<User, Box<dyn StdError + Send + Sync>> {
+        info!("Validating user: {:?}", user);
+        let mut valid_user = self.validate_user_to_store_use_case.process(user).await?;
+        // Users always start active, the status sent by the bank is ignored
+        valid_user.user_status = UserStatus::active();
+        info!("Creating user: {:?}", valid_user);
+        match self.user_repository.create_user(&valid_user).await {
+            Ok(user_created) => Ok(user_created),
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                Err(e.into())
+            }
+        }
+    }
+}
\ No newline at end of file
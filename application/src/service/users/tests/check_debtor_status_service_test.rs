@@ -0,0 +1,123 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::user_error::UserError;
+use domain::model::status_debtor_user_info::StatusDebtorUserInfo;
+use domain::model::user_status::UserStatus;
+use crate::service::users::check_debtor_status_service::CheckDebtorStatusService;
+use crate::service::users::tests::mocks::{country_repository_for_test, create_user_for_test,
+                                          MockUserRepo};
+use crate::use_case::users::check_debtor_status_use_case::CheckDebtorStatusUseCase;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_debtor_user_info_for_test() -> StatusDebtorUserInfo {
+        StatusDebtorUserInfo::new(String::from(" 51999888777 "), String::from("001"), 604)
+    }
+
+    fn user_repository_returning_user(user_status: UserStatus) -> MockUserRepo {
+        let mut stored_user = create_user_for_test();
+        stored_user.user_status = user_status;
+        let mut user_repository = MockUserRepo::new();
+        user_repository
+            .expect_find_user_by_alias()
+            .with(eq(String::from("+51999888777")))
+            .returning(move |_| Ok(Some(stored_user.clone())))
+            .times(1);
+        user_repository
+    }
+
+    async fn process_expecting_error(service_input: StatusDebtorUserInfo,
+                                     user_repository: MockUserRepo) -> String {
+        let service = CheckDebtorStatusService::new(Arc::new(user_repository),
+                                                    Arc::new(country_repository_for_test()));
+        let error = service.process(service_input).await.unwrap_err();
+        String::from(error.downcast_ref::<UserError>().unwrap().get_code())
+    }
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let service = CheckDebtorStatusService::new(
+            Arc::new(user_repository_returning_user(UserStatus::active())),
+            Arc::new(country_repository_for_test()));
+
+        let debtor = service.process(status_debtor_user_info_for_test()).await.unwrap();
+
+        assert_eq!(debtor.alias_value, "+51999888777");
+        assert_eq!(debtor.bank_code, "001");
+        assert_eq!(debtor.user_status, UserStatus::active());
+    }
+
+    #[tokio::test]
+    async fn test_process_empty_alias() {
+        let mut status_debtor_user_info = status_debtor_user_info_for_test();
+        status_debtor_user_info.alias_value = String::from("  ");
+
+        let code = process_expecting_error(status_debtor_user_info, MockUserRepo::new()).await;
+
+        assert_eq!(code, UserError::debtor_null().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_alias() {
+        let mut status_debtor_user_info = status_debtor_user_info_for_test();
+        status_debtor_user_info.alias_value = String::from("@aliasTest");
+
+        let code = process_expecting_error(status_debtor_user_info, MockUserRepo::new()).await;
+
+        assert_eq!(code, UserError::debtor_invalid_alias().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_country_code() {
+        let mut status_debtor_user_info = status_debtor_user_info_for_test();
+        status_debtor_user_info.country_code = 999;
+
+        let code = process_expecting_error(status_debtor_user_info, MockUserRepo::new()).await;
+
+        assert_eq!(code, "ERR-079");
+    }
+
+    #[tokio::test]
+    async fn test_process_empty_bank_code() {
+        let mut status_debtor_user_info = status_debtor_user_info_for_test();
+        status_debtor_user_info.bank_code = String::new();
+
+        let code = process_expecting_error(status_debtor_user_info, MockUserRepo::new()).await;
+
+        assert_eq!(code, UserError::bank_code_null_or_empty_for_debtor().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_debtor_not_found() {
+        let mut user_repository = MockUserRepo::new();
+        user_repository
+            .expect_find_user_by_alias()
+            .returning(|_| Ok(None))
+            .times(1);
+
+        let code = process_expecting_error(status_debtor_user_info_for_test(), user_repository).await;
+
+        assert_eq!(code, UserError::debtor_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_debtor_of_another_bank() {
+        let mut status_debtor_user_info = status_debtor_user_info_for_test();
+        status_debtor_user_info.bank_code = String::from("002");
+
+        let code = process_expecting_error(status_debtor_user_info,
+                                           user_repository_returning_user(UserStatus::active())).await;
+
+        assert_eq!(code, UserError::debtor_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_debtor_inactive() {
+        let code = process_expecting_error(status_debtor_user_info_for_test(),
+                                           user_repository_returning_user(UserStatus::inactive())).await;
+
+        assert_eq!(code, UserError::debtor_inactive().get_code());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::model::user_status::UserStatus;
+use crate::service::users::create_user_service::CreateUserService;
+use crate::service::users::tests::mocks::{create_user_for_test, MockUserRepo,
+                                          MockValidateUserToStore};
+use crate::use_case::users::create_user_use_case::CreateUserUseCase;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut validator = MockValidateUserToStore::new();
+        validator
+            .expect_process()
+            .with(eq(create_user_for_test()))
+            .returning(Ok)
+            .times(1);
+        let mut expected_user = create_user_for_test();
+        expected_user.user_status = UserStatus::active();
+        let mut repository = MockUserRepo::new();
+        repository
+            .expect_create_user()
+            .with(eq(expected_user))
+            .returning(|user| {
+                let mut user_created = user.clone();
+                user_created.user_id = 15;
+                Ok(user_created)
+            })
+            .times(1);
+
+        let service = CreateUserService::new(Arc::new(validator), Arc::new(repository));
+
+        let user = service.process(create_user_for_test()).await.unwrap();
+
+        assert_eq!(user.user_id, 15);
+        assert!(user.user_status.is_active());
+    }
+
+    #[tokio::test]
+    async fn test_process_validation_error() {
+        let mut validator = MockValidateUserToStore::new();
+        validator
+            .expect_process()
+            .returning(|_| Err(Box::new(std::io::Error::other("Validation error"))))
+            .times(1);
+        let mut repository = MockUserRepo::new();
+        repository.expect_create_user().times(0);
+
+        let service = CreateUserService::new(Arc::new(validator), Arc::new(repository));
+
+        assert!(service.process(create_user_for_test()).await.is_err());
+    }
+}
\ No newline at end of file
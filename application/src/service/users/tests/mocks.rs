@@ -0,0 +1,76 @@
+use std::error::Error;
+use mockall::mock;
+use async_trait::async_trait;
+use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
+use domain::model::country::Country;
+use domain::model::user::User;
+use domain::model::user_status::UserStatus;
+use crate::port::db::countries::country_repository_port::CountryRepositoryPort;
+use crate::port::db::users::user_repository_port::UserRepositoryPort;
+use crate::use_case::users::validate_user_to_store::ValidateUserToStore;
+
+// Mocks shared by the user service tests
+mock! {
+    pub ValidateUserToStore {}
+
+    #[async_trait]
+    impl ValidateUserToStore for ValidateUserToStore {
+        async fn process(&self, user: User) -> Result<User, Box<dyn Error + Send + Sync>>;
+    }
+}
+
+mock! {
+    pub UserRepo {}
+
+    #[async_trait]
+    impl UserRepositoryPort for UserRepo {
+        async fn create_user(&self, user: &User) -> Result<User, DatabaseError>;
+
+        async fn user_does_not_exist_by_alias(&self, alias: &str)
+        -> Result<bool, DatabaseError>;
+
+        async fn find_user_by_alias(&self, alias: &str)
+        -> Result<Option<User>, DatabaseError>;
+    }
+}
+
+mock! {
+    pub CountryRepo {}
+
+    #[async_trait]
+    impl CountryRepositoryPort for CountryRepo {
+        async fn find_country_by_code(&self, country_code: &i32)
+        -> Result<Option<Country>, DatabaseError>;
+    }
+}
+
+pub fn create_country_for_test() -> Country {
+    Country::new(1, String::from("Peru"), 604, String::from("51"))
+}
+
+// Country repository that knows only the country of the test user
+pub fn country_repository_for_test() -> MockCountryRepo {
+    let mut country_repository = MockCountryRepo::new();
+    country_repository
+        .expect_find_country_by_code()
+        .returning(|country_code| Ok(Some(create_country_for_test())
+            .filter(|country| country.country_code == *country_code)));
+    country_repository
+}
+
+pub fn create_user_for_test() -> User {
+    User::new(
+        0,
+        1,
+        String::from("+51999888777"),
+        604,
+        Account::new(
+            0,
+            String::from("84102e21-01b5-4f5d-8771-36f915c4d29e"),
+            String::from("001"),
+            0
+        ),
+        UserStatus::inactive()
+    )
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use mockall::predicate::*;
+use std::sync::Arc;
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::user_error::UserError;
+use crate::service::banks::tests::mocks::{create_bank_for_test, MockBankRepo};
+use crate::service::users::tests::mocks::{country_repository_for_test, create_user_for_test,
+                                          MockCountryRepo, MockUserRepo};
+use crate::service::users::validate_user_to_store_service::ValidateUserToStoreService;
+use crate::use_case::users::validate_user_to_store::ValidateUserToStore;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_process_success() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("001")))
+            .returning(|_| Ok(Some(create_bank_for_test())))
+            .times(1);
+        let mut user_repository = MockUserRepo::new();
+        user_repository
+            .expect_user_does_not_exist_by_alias()
+            .with(eq(String::from("+51999888777")))
+            .returning(|_| Ok(true))
+            .times(1);
+
+        let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+        let mut user = create_user_for_test();
+        user.alias = String::from(" +51999888777 ");
+
+        let user = service.process(user).await.unwrap();
+
+        assert_eq!(user.alias, "+51999888777");
+    }
+
+    #[tokio::test]
+    async fn test_process_not_phone_alias_type() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut user_repository = MockUserRepo::new();
+        user_repository.expect_user_does_not_exist_by_alias().times(0);
+
+        let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+        let mut user = create_user_for_test();
+        user.alias_type = 2;
+
+        let error = service.process(user).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(),
+                   UserError::creditor_invalid_alias_type().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_phone_alias_is_stored_in_e164_form() {
+        for alias in ["+51 999-888-777", "51999888777", "999888777", "(51) 999.888.777"] {
+            let mut bank_repository = MockBankRepo::new();
+            bank_repository
+                .expect_find_bank_by_code()
+                .returning(|_| Ok(Some(create_bank_for_test())))
+                .times(1);
+            let mut user_repository = MockUserRepo::new();
+            user_repository
+                .expect_user_does_not_exist_by_alias()
+                .with(eq(String::from("+51999888777")))
+                .returning(|_| Ok(true))
+                .times(1);
+
+            let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                          Arc::new(user_repository),
+                                                          Arc::new(country_repository_for_test()));
+            let mut user = create_user_for_test();
+            user.alias = String::from(alias);
+
+            let user = service.process(user).await.unwrap();
+
+            assert_eq!(user.alias, "+51999888777", "alias {} was not canonicalised", alias);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_phone_alias_of_another_country() {
+        let mut user_repository = MockUserRepo::new();
+        user_repository.expect_user_does_not_exist_by_alias().times(0);
+
+        let service = ValidateUserToStoreService::new(Arc::new(MockBankRepo::new()),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+        let mut user = create_user_for_test();
+        user.alias = String::from("+54 911 2345 6789");
+
+        let error = service.process(user).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(),
+                   UserError::creditor_invalid_alias().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_phone_alias() {
+        let mut user_repository = MockUserRepo::new();
+        user_repository.expect_user_does_not_exist_by_alias().times(0);
+
+        let service = ValidateUserToStoreService::new(Arc::new(MockBankRepo::new()),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+        let mut user = create_user_for_test();
+        user.alias = String::from("aliasTest");
+
+        let error = service.process(user).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(),
+                   UserError::creditor_invalid_alias().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_invalid_account_number() {
+        let service = ValidateUserToStoreService::new(Arc::new(MockBankRepo::new()),
+                                                      Arc::new(MockUserRepo::new()),
+                                                      Arc::new(MockCountryRepo::new()));
+        let mut user = create_user_for_test();
+        user.account.account_number = String::from("not-an-account");
+
+        let error = service.process(user).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<CommerceError>().unwrap().get_code(),
+                   CommerceError::not_valid_account_format().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_alias_already_exists() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut user_repository = MockUserRepo::new();
+        user_repository
+            .expect_user_does_not_exist_by_alias()
+            .returning(|_| Ok(false))
+            .times(1);
+
+        let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+
+        let error = service.process(create_user_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(),
+                   UserError::alias_already_exists().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_unknown_bank() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository
+            .expect_find_bank_by_code()
+            .returning(|_| Ok(None))
+            .times(1);
+        let mut user_repository = MockUserRepo::new();
+        user_repository
+            .expect_user_does_not_exist_by_alias()
+            .returning(|_| Ok(true))
+            .times(1);
+
+        let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+
+        let error = service.process(create_user_for_test()).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<BankError>().unwrap().get_code(),
+                   BankError::creditor_bank_not_found().get_code());
+    }
+
+    #[tokio::test]
+    async fn test_process_unknown_country_code() {
+        let mut bank_repository = MockBankRepo::new();
+        bank_repository.expect_find_bank_by_code().times(0);
+        let mut user_repository = MockUserRepo::new();
+        user_repository.expect_user_does_not_exist_by_alias().times(0);
+
+        let service = ValidateUserToStoreService::new(Arc::new(bank_repository),
+                                                      Arc::new(user_repository),
+                                                      Arc::new(country_repository_for_test()));
+        let mut user = create_user_for_test();
+        user.country = 999;
+
+        let error = service.process(user).await.unwrap_err();
+
+        assert_eq!(error.downcast_ref::<UserError>().unwrap().get_code(), "ERR-084");
+    }
+}
\ No newline at end of file
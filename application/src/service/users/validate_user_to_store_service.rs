@@ -0,0 +1,119 @@
+use std::error::Error as StdError;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::{error, info};
+use domain::exception::user_error::UserError;
+use domain::model::country::Country;
+use domain::model::party_kind::PartyKind;
+use domain::model::user::User;
+use crate::port::db::banks::bank_repository_port::BankRepositoryPort;
+use crate::port::db::countries::country_repository_port::CountryRepositoryPort;
+use crate::port::db::users::user_repository_port::UserRepositoryPort;
+use crate::service::commerces::alias_type_catalog::{alias_type_catalog, PHONE_ALIAS_TYPE};
+use crate::service::commerces::validate_commerce_to_store_service::{validate_account_number_format,
+                                                                    validate_bank_code_format,
+                                                                    validate_creditor_bank_is_active};
+use crate::use_case::users::validate_user_to_store::ValidateUserToStore;
+
+pub struct ValidateUserToStoreService<BR: BankRepositoryPort, UR: UserRepositoryPort,
+    CO: CountryRepositoryPort> {
+    bank_repository: Arc<BR>,
+    user_repository: Arc<UR>,
+    country_repository: Arc<CO>
+}
+
+impl<BR: BankRepositoryPort, UR: UserRepositoryPort, CO: CountryRepositoryPort>
+ValidateUserToStoreService<BR, UR, CO> {
+    pub fn new(bank_repository: Arc<BR>, user_repository: Arc<UR>,
+               country_repository: Arc<CO>) -> Self {
+        Self {
+            bank_repository,
+            user_repository,
+            country_repository
+        }
+    }
+
+    async fn canonical_user_alias(&self, user: &User)
+        -> Result<String, Box<dyn StdError + Send + Sync>> {
+        let Some(country) = find_country(self.country_repository.as_ref(), user.country).await? else {
+            error!("User country code {} does not exist", user.country);
+            return Err(UserError::creditor_invalid_country_code().into())
+        };
+        // Only PHONE aliases have several spellings, other alias types are stored as sent
+        let alias = if user.alias_type == PHONE_ALIAS_TYPE {
+            User::canonical_phone_alias(&user.alias, &country)
+        } else {
+            Some(user.alias.clone())
+        };
+        alias.filter(|alias| alias_type_catalog().is_valid_alias(user.alias_type, alias))
+            .ok_or_else(|| {
+                error!("Invalid alias format: {}", user.alias);
+                UserError::creditor_invalid_alias().into()
+            })
+    }
+
+    // Returns the id of the settling bank found while validating
+    async fn validate_user_logic(&self, user: &User) -> Result<i64, Box<dyn StdError + Send + Sync>> {
+        // Aliases are stored in E.164 form, so every spelling of a number is checked at once
+        match self.user_repository.user_does_not_exist_by_alias(&user.alias).await {
+            Ok(true) => (),
+            Ok(false) => {
+                error!("User alias '{}' already exists", user.alias);
+                return Err(UserError::alias_already_exists().into())
+            },
+            Err(e) => {
+                error!("There was an error in the database. Error is: {:?}", e);
+                return Err(e.into())
+            }
+        }
+
+        validate_creditor_bank_is_active(self.bank_repository.as_ref(), &user.account.bank_code)
+            .await
+            .map(|bank| bank.bank_id)
+    }
+}
+
+#[async_trait]
+impl<BR, UR, CO> ValidateUserToStore for ValidateUserToStoreService<BR, UR, CO>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static,
+    UR: UserRepositoryPort + Send + Sync + 'static,
+    CO: CountryRepositoryPort + Send + Sync + 'static
+{
+    async fn process(&self, user: User) -> Result<User, Box<dyn StdError + Send + Sync>> {
+        let mut user_validated = user;
+        user_validated.alias = user_validated.alias.trim().to_string();
+        user_validated.account.account_number = user_validated.account.account_number.trim()
+            .to_string();
+        user_validated.account.bank_code = user_validated.account.bank_code.trim().to_string();
+        info!("Validating user field formats for user {:?}", user_validated);
+        validate_user_field_formats(&user_validated)?;
+        user_validated.alias = self.canonical_user_alias(&user_validated).await?;
+        info!("Validating user logic for user {:?}", user_validated);
+        user_validated.account.bank_id = self.validate_user_logic(&user_validated).await?;
+        info!("User logic is valid for user {:?}", user_validated);
+        Ok(user_validated)
+    }
+}
+
+fn validate_user_field_formats(user: &User) -> Result<(), Box<dyn StdError + Send + Sync>> {
+    if !alias_type_catalog().is_allowed(user.alias_type, PartyKind::User) {
+        error!("Invalid alias type for user: {}", user.alias_type);
+        return Err(UserError::creditor_invalid_alias_type().into());
+    }
+    validate_account_number_format(&user.account.account_number)?;
+    validate_bank_code_format(&user.account.bank_code)?;
+    Ok(())
+}
+
+pub(crate) async fn find_country<CO: CountryRepositoryPort>(country_repository: &CO,
+                                                            country_code: i32)
+    -> Result<Option<Country>, Box<dyn StdError + Send + Sync>> {
+    match country_repository.find_country_by_code(&country_code).await {
+        Ok(country) => Ok(country),
+        Err(e) => {
+            error!("There was an error in the database. Error is: {:?}", e);
+            Err(e.into())
+        }
+    }
+}
\ No newline at end of file
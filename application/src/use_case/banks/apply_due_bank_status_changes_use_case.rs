@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait ApplyDueBankStatusChangesUseCase {
+    // Returns how many scheduled changes were applied
+    async fn process(&self, at: DateTime<Utc>) -> Result<usize, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
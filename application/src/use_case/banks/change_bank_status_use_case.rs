@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
+
+#[async_trait]
+pub trait ChangeBankStatusUseCase {
+    async fn process(&self, bank_code: String, new_status: BankStatus, changed_by: String)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
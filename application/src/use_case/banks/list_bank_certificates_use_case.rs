@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::bank_certificate::BankCertificate;
+
+#[async_trait]
+pub trait ListBankCertificatesUseCase {
+    async fn process(&self, bank_code: String)
+        -> Result<Vec<BankCertificate>, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
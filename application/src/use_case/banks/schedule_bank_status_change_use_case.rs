@@ -0,0 +1,12 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+
+#[async_trait]
+pub trait ScheduleBankStatusChangeUseCase {
+    async fn process(&self, bank_code: String, new_status: BankStatus,
+                     effective_at: DateTime<Utc>, scheduled_by: String)
+        -> Result<BankStatusSchedule, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::bank::Bank;
+
+#[async_trait]
+pub trait UpdateBankUseCase {
+    async fn process(&self, bank_code: String, bank: Bank)
+        -> Result<Bank, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
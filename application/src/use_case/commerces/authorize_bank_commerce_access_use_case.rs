@@ -0,0 +1,13 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+
+#[async_trait]
+pub trait AuthorizeBankCommerceAccessUseCase {
+    // Returns the commerce when its primary settlement account belongs to the calling bank
+    async fn process(&self, calling_bank_code: &str, commerce_id: i64)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+    fn authorize_account(&self, calling_bank_code: &str, account: &Account)
+        -> Result<(), Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::account::Account;
+use domain::model::commerce::Commerce;
+
+#[async_trait]
+pub trait ChangeCommerceAccountUseCase {
+    async fn process(&self, commerce_id: i64, account: Account)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_status::CommerceStatus;
+
+#[async_trait]
+pub trait ChangeCommerceStatusUseCase {
+    async fn process(&self, commerce_id: i64, new_status: CommerceStatus, reason: String,
+                     changed_by: String)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce::Commerce;
+
+#[async_trait]
+pub trait CreateCommerceBranchUseCase {
+    async fn process(&self, parent_commerce_id: i64, branch: Commerce)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
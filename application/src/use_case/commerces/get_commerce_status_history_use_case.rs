@@ -0,0 +1,9 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce_status_history::CommerceStatusHistory;
+
+#[async_trait]
+pub trait GetCommerceStatusHistoryUseCase {
+    async fn process(&self, commerce_id: i64)
+        -> Result<Vec<CommerceStatusHistory>, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce::Commerce;
+
+#[async_trait]
+pub trait GetCommerceUseCase {
+    async fn process(&self, commerce_id: i64) -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
+
+#[async_trait]
+pub trait ListCommercesUseCase {
+    async fn process(&self, filter: CommerceFilter, cursor: Option<i64>, limit: Option<i64>)
+        -> Result<CommercePage, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::creditor_user_info::CreditorUserInfo;
+use domain::model::status_creditor_user_info::StatusCreditorUserInfo;
+
+#[async_trait]
+pub trait ResolveCreditorAliasUseCase {
+    async fn process(&self, status_creditor_user_info: StatusCreditorUserInfo)
+        -> Result<CreditorUserInfo, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_update::CommerceUpdate;
+
+#[async_trait]
+pub trait UpdateCommerceUseCase {
+    async fn process(&self, commerce_id: i64, commerce_update: CommerceUpdate)
+        -> Result<Commerce, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
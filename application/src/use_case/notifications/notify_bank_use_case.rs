@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use domain::model::commerce::Commerce;
+use domain::model::commerce_event::CommerceEvent;
+
+#[async_trait]
+pub trait NotifyBankUseCase {
+    // Never fails, a notification problem must not undo the change that triggered it
+    async fn process(&self, commerce_event: CommerceEvent, commerce: &Commerce);
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::bank_stats_filter::BankStatsFilter;
+
+#[async_trait]
+pub trait GetBankStatsUseCase {
+    async fn process(&self, filter: BankStatsFilter)
+        -> Result<Vec<BankCommerceStats>, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::debtor_user_info::DebtorUserInfo;
+use domain::model::status_debtor_user_info::StatusDebtorUserInfo;
+
+#[async_trait]
+pub trait CheckDebtorStatusUseCase {
+    async fn process(&self, status_debtor_user_info: StatusDebtorUserInfo)
+        -> Result<DebtorUserInfo, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use std::error::{Error as StdError};
+use async_trait::async_trait;
+use domain::model::user::User;
+
+#[async_trait]
+pub trait ValidateUserToStore {
+    async fn process(&self, user: User) -> Result<User, Box<dyn StdError + Send + Sync>>;
+}
\ No newline at end of file
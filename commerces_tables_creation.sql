@@ -84,4 +84,240 @@ ALTER TABLE dbo.accounts ADD bank_code varchar(100);
 -- Update some fields
 UPDATE dbo.accounts SET accounts.bank_code = '001' WHERE account_id = 1;
 UPDATE dbo.accounts SET accounts.bank_code = '002' WHERE account_id = 2;
-UPDATE dbo.accounts SET accounts.bank_code = '002' WHERE account_id = 3;
\ No newline at end of file
+UPDATE dbo.accounts SET accounts.bank_code = '002' WHERE account_id = 3;
+
+-- Reason of the last commerce status change
+ALTER TABLE dbo.commerces ADD status_reason varchar(400);
+
+
+-- Audit trail of commerce status changes
+CREATE TABLE commerce_status_history (
+                                         history_id bigint IDENTITY(1,1) PRIMARY KEY,
+                                         id_commerce bigint NOT NULL,
+                                         previous_commerce_status_id bigint NOT NULL,
+                                         new_commerce_status_id bigint NOT NULL,
+                                         reason varchar(400) NOT NULL,
+                                         changed_by varchar(100) NOT NULL,
+                                         changed_at datetime NOT NULL,
+                                         FOREIGN KEY (id_commerce) REFERENCES commerces(id_commerce),
+                                         FOREIGN KEY (previous_commerce_status_id) REFERENCES commerce_status(commerce_status_id),
+                                         FOREIGN KEY (new_commerce_status_id) REFERENCES commerce_status(commerce_status_id)
+);
+
+-- Accounts replaced by a settlement account rotation are kept and marked as superseded
+ALTER TABLE dbo.accounts ADD superseded_at datetime;
+
+-- A legal entity is identified by its RUC and can own several aliases (one per brand)
+CREATE TABLE legal_entities (
+                                legal_entity_id bigint IDENTITY(1,1) PRIMARY KEY,
+                                ruc varchar(50) NOT NULL UNIQUE,
+                                legal_business_name varchar(400) NOT NULL
+);
+
+ALTER TABLE dbo.commerces ADD legal_entity_id bigint;
+ALTER TABLE dbo.commerces ADD FOREIGN KEY (legal_entity_id) REFERENCES legal_entities(legal_entity_id);
+
+-- Move the RUC and legal business name of existing commerces to their legal entity
+INSERT INTO legal_entities (ruc, legal_business_name)
+SELECT ruc, MIN(legal_business_name) FROM dbo.commerces GROUP BY ruc;
+UPDATE dbo.commerces SET legal_entity_id = (SELECT le.legal_entity_id FROM legal_entities le
+                                            WHERE le.ruc = commerces.ruc);
+
+ALTER TABLE dbo.commerces DROP COLUMN ruc;
+ALTER TABLE dbo.commerces DROP COLUMN legal_business_name;
+
+-- Settlement accounts of each commerce, the primary one is still referenced by commerces.account_id
+CREATE TABLE commerce_accounts (
+                                   id_commerce bigint NOT NULL,
+                                   account_id bigint NOT NULL,
+                                   PRIMARY KEY (id_commerce, account_id),
+                                   FOREIGN KEY (id_commerce) REFERENCES commerces(id_commerce),
+                                   FOREIGN KEY (account_id) REFERENCES accounts(account_id)
+);
+
+INSERT INTO commerce_accounts (id_commerce, account_id)
+SELECT id_commerce, account_id FROM dbo.commerces;
+
+
+-- Branches report under the RUC of their parent commerce
+ALTER TABLE dbo.commerces ADD parent_commerce_id bigint;
+ALTER TABLE dbo.commerces ADD FOREIGN KEY (parent_commerce_id) REFERENCES commerces(id_commerce);
+
+-- Commerces are suspended while the bank of any of their accounts is disabled
+INSERT INTO commerce_status (commerce_status_id, status_name) VALUES
+                                                                  (3, 'SUSPENDED');
+
+-- Bank mTLS certificates, the previous ones stay valid until retires_at during a rotation
+ALTER TABLE banks ADD ocp_cert_mtls text;
+
+CREATE TABLE bank_certificates (
+                                   certificate_id bigint IDENTITY(1,1) PRIMARY KEY,
+                                   bank_id bigint NOT NULL,
+                                   certificate_pem text NOT NULL,
+                                   subject varchar(400),
+                                   fingerprint varchar(95) NOT NULL,
+                                   not_before datetime NOT NULL,
+                                   not_after datetime NOT NULL,
+                                   uploaded_at datetime NOT NULL,
+                                   retires_at datetime,
+                                   FOREIGN KEY (bank_id) REFERENCES banks(bank_id)
+);
+
+-- Bank onboarding lifecycle, DISABLED becomes SUSPENDED
+UPDATE bank_status SET status_name = 'SUSPENDED' WHERE status_id = 2;
+INSERT INTO bank_status (status_id, status_name) VALUES
+                                                     (3, 'PENDING'),
+                                                     (4, 'CERTIFIED'),
+                                                     (5, 'OFFBOARDED');
+
+-- Bank status changes scheduled ahead of time, applied by a background task
+CREATE TABLE bank_status_schedules (
+                                       schedule_id bigint IDENTITY(1,1) PRIMARY KEY,
+                                       bank_id bigint NOT NULL,
+                                       status_id bigint NOT NULL,
+                                       effective_at datetime NOT NULL,
+                                       scheduled_by varchar(100) NOT NULL,
+                                       scheduled_at datetime NOT NULL,
+                                       processed_at datetime,
+                                       applied bit,
+                                       FOREIGN KEY (bank_id) REFERENCES banks(bank_id),
+                                       FOREIGN KEY (status_id) REFERENCES bank_status(status_id)
+);
+
+-- Registration date of each commerce, empty for the ones created before it was recorded
+ALTER TABLE dbo.commerces ADD created_at datetime;
+
+-- Natural persons registered under a PHONE alias
+CREATE TABLE user_status (
+                             user_status_id bigint PRIMARY KEY,
+                             status_name varchar(50)
+);
+
+INSERT INTO user_status (user_status_id, status_name) VALUES
+                                                          (1, 'ACTIVE'),
+                                                          (2, 'INACTIVE');
+
+CREATE TABLE users (
+                       user_id bigint IDENTITY(1,1) PRIMARY KEY,
+                       alias varchar(400) UNIQUE,
+                       alias_type_id bigint NOT NULL,
+                       country_code int NOT NULL,
+                       account_id bigint NOT NULL,
+                       user_status_id bigint NOT NULL,
+                       created_at datetime,
+                       FOREIGN KEY (alias_type_id) REFERENCES alias_type(alias_type_id),
+                       FOREIGN KEY (account_id) REFERENCES accounts(account_id),
+                       FOREIGN KEY (user_status_id) REFERENCES user_status(user_status_id)
+);
+
+-- Country catalog, country_code is the ISO 3166-1 numeric code
+CREATE TABLE countries (
+                           country_id bigint IDENTITY(1,1) PRIMARY KEY,
+                           country_name varchar(100) NOT NULL,
+                           country_code int NOT NULL UNIQUE,
+                           dialling_code varchar(5) NOT NULL
+);
+
+INSERT INTO countries (country_name, country_code, dialling_code) VALUES
+                                                                      ('Argentina', 32, '54'),
+                                                                      ('Australia', 36, '61'),
+                                                                      ('Belgium', 56, '32'),
+                                                                      ('Bolivia', 68, '591'),
+                                                                      ('Brazil', 76, '55'),
+                                                                      ('Canada', 124, '1'),
+                                                                      ('Chile', 152, '56'),
+                                                                      ('China', 156, '86'),
+                                                                      ('Colombia', 170, '57'),
+                                                                      ('Costa Rica', 188, '506'),
+                                                                      ('Cuba', 192, '53'),
+                                                                      ('Dominican Republic', 214, '1'),
+                                                                      ('Ecuador', 218, '593'),
+                                                                      ('El Salvador', 222, '503'),
+                                                                      ('France', 250, '33'),
+                                                                      ('Germany', 276, '49'),
+                                                                      ('Guatemala', 320, '502'),
+                                                                      ('Haiti', 332, '509'),
+                                                                      ('Honduras', 340, '504'),
+                                                                      ('India', 356, '91'),
+                                                                      ('Israel', 376, '972'),
+                                                                      ('Italy', 380, '39'),
+                                                                      ('Jamaica', 388, '1'),
+                                                                      ('Japan', 392, '81'),
+                                                                      ('Korea, Republic of', 410, '82'),
+                                                                      ('Mexico', 484, '52'),
+                                                                      ('Netherlands', 528, '31'),
+                                                                      ('Nicaragua', 558, '505'),
+                                                                      ('Panama', 591, '507'),
+                                                                      ('Paraguay', 600, '595'),
+                                                                      ('Peru', 604, '51'),
+                                                                      ('Portugal', 620, '351'),
+                                                                      ('Puerto Rico', 630, '1'),
+                                                                      ('South Africa', 710, '27'),
+                                                                      ('Spain', 724, '34'),
+                                                                      ('Switzerland', 756, '41'),
+                                                                      ('United Kingdom', 826, '44'),
+                                                                      ('United States of America', 840, '1'),
+                                                                      ('Uruguay', 858, '598'),
+                                                                      ('Venezuela', 862, '58');
+
+ALTER TABLE users ADD FOREIGN KEY (country_code) REFERENCES countries(country_code);
+
+-- PHONE aliases are stored in E.164 form. Set the format existing ones were stored in before
+-- running: 'NATIONAL' when they lack the dialling code, 'INTERNATIONAL' when they carry it
+-- without the '+'. Any other value migrates nothing
+SET @legacy_phone_alias_format = 'NATIONAL';
+
+CREATE TABLE user_alias_migration AS
+SELECT u.user_id,
+       CASE @legacy_phone_alias_format
+           WHEN 'NATIONAL' THEN CONCAT('+', c.dialling_code, u.alias)
+           WHEN 'INTERNATIONAL' THEN CONCAT('+', u.alias)
+       END AS new_alias
+FROM users u
+    INNER JOIN countries c ON c.country_code = u.country_code
+WHERE u.alias_type_id = 1
+  AND u.alias NOT LIKE '+%'
+  AND @legacy_phone_alias_format IN ('NATIONAL', 'INTERNATIONAL');
+
+-- Aliases that would collide or are not valid E.164 are kept as they are and listed for review
+CREATE TABLE user_alias_migration_conflicts AS
+SELECT m.user_id, m.new_alias,
+       CASE
+           WHEN m.new_alias NOT REGEXP '^\\+[1-9][0-9]{7,14}$' THEN 'NOT_E164'
+           WHEN EXISTS (SELECT 1 FROM users u WHERE u.alias = m.new_alias) THEN 'ALIAS_TAKEN'
+           ELSE 'DUPLICATED'
+       END AS reason
+FROM user_alias_migration m
+WHERE m.new_alias NOT REGEXP '^\\+[1-9][0-9]{7,14}$'
+   OR EXISTS (SELECT 1 FROM users u WHERE u.alias = m.new_alias)
+   OR m.new_alias IN (SELECT d.new_alias FROM (SELECT new_alias FROM user_alias_migration
+                                               GROUP BY new_alias HAVING COUNT(*) > 1) d);
+
+DELETE FROM user_alias_migration
+WHERE user_id IN (SELECT user_id FROM user_alias_migration_conflicts);
+
+UPDATE users u
+    INNER JOIN user_alias_migration m ON m.user_id = u.user_id
+SET u.alias = m.new_alias;
+
+DROP TABLE user_alias_migration;
+
+-- Validation rules of each alias type, a new alias type only needs a new row
+ALTER TABLE alias_type ADD pattern varchar(200);
+ALTER TABLE alias_type ADD min_length int;
+ALTER TABLE alias_type ADD max_length int;
+ALTER TABLE alias_type ADD allowed_for_commerce boolean;
+ALTER TABLE alias_type ADD allowed_for_user boolean;
+
+UPDATE alias_type SET pattern = '^\\+[1-9][0-9]{7,14}$', min_length = 9, max_length = 16,
+                      allowed_for_commerce = false, allowed_for_user = true
+WHERE alias_type_id = 1;
+UPDATE alias_type SET pattern = '^@[A-Za-z0-9]+$', min_length = 4, max_length = 26,
+                      allowed_for_commerce = true, allowed_for_user = false
+WHERE alias_type_id = 2;
+
+ALTER TABLE alias_type MODIFY pattern varchar(200) NOT NULL;
+ALTER TABLE alias_type MODIFY min_length int NOT NULL;
+ALTER TABLE alias_type MODIFY max_length int NOT NULL;
+ALTER TABLE alias_type MODIFY allowed_for_commerce boolean NOT NULL;
+ALTER TABLE alias_type MODIFY allowed_for_user boolean NOT NULL;
\ No newline at end of file
@@ -8,6 +8,25 @@ pub enum BankError {
     CreditorBankNotActive(ErrorAttributes),
     CreditorBankNotFound(ErrorAttributes),
     DebtorBankNotActive(ErrorAttributes),
+    NotFound(ErrorAttributes),
+    BankCodeAlreadyExists(ErrorAttributes),
+    BankCodeCannotBeModified(ErrorAttributes),
+    NotValidBankCode(ErrorAttributes),
+    NotValidBankName(ErrorAttributes),
+    NotValidBankMail(ErrorAttributes),
+    NotValidBankPhone(ErrorAttributes),
+    NotValidBankRuc(ErrorAttributes),
+    StatusTransitionNotAllowed(ErrorAttributes),
+    NotValidCertificate(ErrorAttributes),
+    CertificateExpired(ErrorAttributes),
+    CertificateAlreadyRegistered(ErrorAttributes),
+    CommerceBelongsToAnotherBank(ErrorAttributes),
+    NotValidEffectiveDate(ErrorAttributes),
+    NotValidBankStatus(ErrorAttributes),
+    NotValidStatsDateRange(ErrorAttributes),
+    NotValidCutoverHours(ErrorAttributes),
+    AccessToAnotherBank(ErrorAttributes),
+    NotValidContactName(ErrorAttributes),
 }
 
 impl BankError {
@@ -35,11 +54,182 @@ impl BankError {
         ))
     }
 
+    pub fn not_found() -> Self {
+        error!("Bank is not found");
+        BankError::NotFound(ErrorAttributes::new(
+            String::from("ERR-002"),
+            String::from("Banco no encontrado"),
+        ))
+    }
+
+    pub fn bank_code_already_exists() -> Self {
+        error!("Bank code already exists");
+        BankError::BankCodeAlreadyExists(ErrorAttributes::new(
+            String::from("ERR-102"),
+            String::from("El codigo de banco ya existe"),
+        ))
+    }
+
+    pub fn bank_code_cannot_be_modified() -> Self {
+        error!("Bank code cannot be modified");
+        BankError::BankCodeCannotBeModified(ErrorAttributes::new(
+            String::from("ERR-103"),
+            String::from("El codigo de banco no puede ser modificado"),
+        ))
+    }
+
+    pub fn not_valid_bank_code() -> Self {
+        error!("Bank code has an invalid format");
+        BankError::NotValidBankCode(ErrorAttributes::new(
+            String::from("ERR-087"),
+            String::from("Formato de codigo de banco invalido"),
+        ))
+    }
+
+    pub fn not_valid_bank_name() -> Self {
+        error!("Bank name or contact name is invalid");
+        BankError::NotValidBankName(ErrorAttributes::new(
+            String::from("ERR-104"),
+            String::from("Nombre de banco o de contacto invalido"),
+        ))
+    }
+
+    pub fn not_valid_bank_mail() -> Self {
+        error!("Bank mail is invalid");
+        BankError::NotValidBankMail(ErrorAttributes::new(
+            String::from("ERR-105"),
+            String::from("Formato de correo de banco invalido"),
+        ))
+    }
+
+    pub fn not_valid_bank_phone() -> Self {
+        error!("Bank phone is invalid");
+        BankError::NotValidBankPhone(ErrorAttributes::new(
+            String::from("ERR-106"),
+            String::from("Formato de telefono de banco invalido"),
+        ))
+    }
+
+    pub fn not_valid_bank_ruc() -> Self {
+        error!("Bank RUC is invalid");
+        BankError::NotValidBankRuc(ErrorAttributes::new(
+            String::from("ERR-107"),
+            String::from("RUC de banco invalido"),
+        ))
+    }
+
+    pub fn status_transition_not_allowed() -> Self {
+        error!("Bank status transition is not allowed");
+        BankError::StatusTransitionNotAllowed(ErrorAttributes::new(
+            String::from("ERR-108"),
+            String::from("Cambio de estado de banco no permitido"),
+        ))
+    }
+
+    pub fn not_valid_certificate() -> Self {
+        error!("Bank certificate is malformed");
+        BankError::NotValidCertificate(ErrorAttributes::new(
+            String::from("ERR-109"),
+            String::from("Certificado mTLS de banco invalido"),
+        ))
+    }
+
+    pub fn certificate_expired() -> Self {
+        error!("Bank certificate is expired");
+        BankError::CertificateExpired(ErrorAttributes::new(
+            String::from("ERR-110"),
+            String::from("Certificado mTLS de banco expirado"),
+        ))
+    }
+
+    pub fn certificate_already_registered() -> Self {
+        error!("Bank certificate is already registered");
+        BankError::CertificateAlreadyRegistered(ErrorAttributes::new(
+            String::from("ERR-111"),
+            String::from("Certificado mTLS de banco ya registrado"),
+        ))
+    }
+
+    pub fn commerce_belongs_to_another_bank() -> Self {
+        error!("Commerce settlement account belongs to another bank");
+        BankError::CommerceBelongsToAnotherBank(ErrorAttributes::new(
+            String::from("ERR-112"),
+            String::from("El comercio pertenece a otro banco participante"),
+        ))
+    }
+
+    pub fn not_valid_effective_date() -> Self {
+        error!("Bank status change effective date is missing or not in the future");
+        BankError::NotValidEffectiveDate(ErrorAttributes::new(
+            String::from("ERR-113"),
+            String::from("La fecha efectiva del cambio de estado debe ser una fecha futura"),
+        ))
+    }
+
+    pub fn not_valid_bank_status() -> Self {
+        error!("Bank status is invalid");
+        BankError::NotValidBankStatus(ErrorAttributes::new(
+            String::from("ERR-114"),
+            String::from("Estado de banco invalido"),
+        ))
+    }
+
+    pub fn not_valid_stats_date_range() -> Self {
+        error!("Bank statistics date range is invalid");
+        BankError::NotValidStatsDateRange(ErrorAttributes::new(
+            String::from("ERR-115"),
+            String::from("Rango de fechas de estadisticas invalido"),
+        ))
+    }
+
+    pub fn not_valid_cutover_hours() -> Self {
+        error!("Certificate cutover hours are out of range");
+        BankError::NotValidCutoverHours(ErrorAttributes::new(
+            String::from("ERR-118"),
+            String::from("Las horas de transicion del certificado exceden el maximo permitido"),
+        ))
+    }
+
+    pub fn access_to_another_bank() -> Self {
+        error!("Participant bank tried to access the data of another bank");
+        BankError::AccessToAnotherBank(ErrorAttributes::new(
+            String::from("ERR-119"),
+            String::from("El banco participante solo puede operar sobre sus propios datos"),
+        ))
+    }
+
+    pub fn not_valid_contact_name() -> Self {
+        error!("Bank contact name is invalid");
+        BankError::NotValidContactName(ErrorAttributes::new(
+            String::from("ERR-121"),
+            String::from("Nombre de contacto del banco invalido"),
+        ))
+    }
+
     pub fn get_code(&self) -> &str {
         match self {
             BankError::CreditorBankNotActive(attrs) => attrs.get_code(),
             BankError::CreditorBankNotFound(attrs) => attrs.get_code(),
             BankError::DebtorBankNotActive(attrs) => attrs.get_code(),
+            BankError::NotFound(attrs) => attrs.get_code(),
+            BankError::BankCodeAlreadyExists(attrs) => attrs.get_code(),
+            BankError::BankCodeCannotBeModified(attrs) => attrs.get_code(),
+            BankError::NotValidBankCode(attrs) => attrs.get_code(),
+            BankError::NotValidBankName(attrs) => attrs.get_code(),
+            BankError::NotValidBankMail(attrs) => attrs.get_code(),
+            BankError::NotValidBankPhone(attrs) => attrs.get_code(),
+            BankError::NotValidBankRuc(attrs) => attrs.get_code(),
+            BankError::StatusTransitionNotAllowed(attrs) => attrs.get_code(),
+            BankError::NotValidCertificate(attrs) => attrs.get_code(),
+            BankError::CertificateExpired(attrs) => attrs.get_code(),
+            BankError::CertificateAlreadyRegistered(attrs) => attrs.get_code(),
+            BankError::CommerceBelongsToAnotherBank(attrs) => attrs.get_code(),
+            BankError::NotValidEffectiveDate(attrs) => attrs.get_code(),
+            BankError::NotValidBankStatus(attrs) => attrs.get_code(),
+            BankError::NotValidStatsDateRange(attrs) => attrs.get_code(),
+            BankError::NotValidCutoverHours(attrs) => attrs.get_code(),
+            BankError::AccessToAnotherBank(attrs) => attrs.get_code(),
+            BankError::NotValidContactName(attrs) => attrs.get_code(),
         }
     }
 
@@ -48,6 +238,25 @@ impl BankError {
             BankError::CreditorBankNotActive(attrs) => attrs.get_message(),
             BankError::CreditorBankNotFound(attrs) => attrs.get_message(),
             BankError::DebtorBankNotActive(attrs) => attrs.get_message(),
+            BankError::NotFound(attrs) => attrs.get_message(),
+            BankError::BankCodeAlreadyExists(attrs) => attrs.get_message(),
+            BankError::BankCodeCannotBeModified(attrs) => attrs.get_message(),
+            BankError::NotValidBankCode(attrs) => attrs.get_message(),
+            BankError::NotValidBankName(attrs) => attrs.get_message(),
+            BankError::NotValidBankMail(attrs) => attrs.get_message(),
+            BankError::NotValidBankPhone(attrs) => attrs.get_message(),
+            BankError::NotValidBankRuc(attrs) => attrs.get_message(),
+            BankError::StatusTransitionNotAllowed(attrs) => attrs.get_message(),
+            BankError::NotValidCertificate(attrs) => attrs.get_message(),
+            BankError::CertificateExpired(attrs) => attrs.get_message(),
+            BankError::CertificateAlreadyRegistered(attrs) => attrs.get_message(),
+            BankError::CommerceBelongsToAnotherBank(attrs) => attrs.get_message(),
+            BankError::NotValidEffectiveDate(attrs) => attrs.get_message(),
+            BankError::NotValidBankStatus(attrs) => attrs.get_message(),
+            BankError::NotValidStatsDateRange(attrs) => attrs.get_message(),
+            BankError::NotValidCutoverHours(attrs) => attrs.get_message(),
+            BankError::AccessToAnotherBank(attrs) => attrs.get_message(),
+            BankError::NotValidContactName(attrs) => attrs.get_message(),
         }
     }
 }
@@ -17,6 +17,15 @@ pub enum CommerceError {
     BankCodeIsEmptyOrNull(ErrorAttributes),
     NotValidFormatBank(ErrorAttributes),
     CommerceInactive(ErrorAttributes),
+    NotFound(ErrorAttributes),
+    RucCannotBeModified(ErrorAttributes),
+    StatusTransitionNotAllowed(ErrorAttributes),
+    StatusReasonIsEmptyOrNull(ErrorAttributes),
+    AccountNotFound(ErrorAttributes),
+    PrimaryAccountCannotBeRemoved(ErrorAttributes),
+    BranchCannotHaveBranches(ErrorAttributes),
+    AccountCannotBeUpdated(ErrorAttributes),
+    AccountsLockedWhileSuspended(ErrorAttributes),
 }
 
 impl CommerceError {
@@ -117,6 +126,78 @@ impl CommerceError {
         ))
     }
 
+    pub fn not_found() -> Self {
+        error!("Commerce not found");
+        CommerceError::NotFound(ErrorAttributes::new(
+            String::from("ERR-096"),
+            String::from("Comercio no encontrado"),
+        ))
+    }
+
+    pub fn ruc_cannot_be_modified() -> Self {
+        error!("Ruc cannot be modified");
+        CommerceError::RucCannotBeModified(ErrorAttributes::new(
+            String::from("ERR-097"),
+            String::from("El RUC del comercio no puede ser modificado"),
+        ))
+    }
+
+    pub fn status_transition_not_allowed() -> Self {
+        error!("Commerce status transition is not allowed");
+        CommerceError::StatusTransitionNotAllowed(ErrorAttributes::new(
+            String::from("ERR-098"),
+            String::from("Cambio de estado de comercio no permitido"),
+        ))
+    }
+
+    pub fn status_reason_is_empty_or_null() -> Self {
+        error!("Commerce status reason is empty or null");
+        CommerceError::StatusReasonIsEmptyOrNull(ErrorAttributes::new(
+            String::from("ERR-001"),
+            String::from("Vacio o nulo para campos obligatorios"),
+        ))
+    }
+
+    pub fn account_not_found() -> Self {
+        error!("Commerce account not found");
+        CommerceError::AccountNotFound(ErrorAttributes::new(
+            String::from("ERR-099"),
+            String::from("Cuenta no encontrada para el comercio"),
+        ))
+    }
+
+    pub fn primary_account_cannot_be_removed() -> Self {
+        error!("Primary account cannot be removed");
+        CommerceError::PrimaryAccountCannotBeRemoved(ErrorAttributes::new(
+            String::from("ERR-100"),
+            String::from("La cuenta principal del comercio no puede ser eliminada"),
+        ))
+    }
+
+    pub fn branch_cannot_have_branches() -> Self {
+        error!("A branch cannot have branches");
+        CommerceError::BranchCannotHaveBranches(ErrorAttributes::new(
+            String::from("ERR-101"),
+            String::from("Una sucursal no puede tener sucursales"),
+        ))
+    }
+
+    pub fn account_cannot_be_updated() -> Self {
+        error!("The settlement account cannot be changed through a commerce update");
+        CommerceError::AccountCannotBeUpdated(ErrorAttributes::new(
+            String::from("ERR-117"),
+            String::from("La cuenta del comercio no se modifica en esta operacion, use PUT /api/commerces/<id>/account"),
+        ))
+    }
+
+    pub fn accounts_locked_while_suspended() -> Self {
+        error!("The settlement accounts of a suspended commerce cannot be rotated or removed");
+        CommerceError::AccountsLockedWhileSuspended(ErrorAttributes::new(
+            String::from("ERR-120"),
+            String::from("Las cuentas de un comercio suspendido no se pueden cambiar ni eliminar"),
+        ))
+    }
+
     pub fn get_code(&self) -> &str {
         match self {
             CommerceError::NotValidAliasType(attrs) => attrs.get_code(),
@@ -131,6 +212,15 @@ impl CommerceError {
             CommerceError::BankCodeIsEmptyOrNull(attrs) => attrs.get_code(),
             CommerceError::NotValidFormatBank(attrs) => attrs.get_code(),
             CommerceError::CommerceInactive(attrs) => attrs.get_code(),
+            CommerceError::NotFound(attrs) => attrs.get_code(),
+            CommerceError::RucCannotBeModified(attrs) => attrs.get_code(),
+            CommerceError::StatusTransitionNotAllowed(attrs) => attrs.get_code(),
+            CommerceError::StatusReasonIsEmptyOrNull(attrs) => attrs.get_code(),
+            CommerceError::AccountNotFound(attrs) => attrs.get_code(),
+            CommerceError::PrimaryAccountCannotBeRemoved(attrs) => attrs.get_code(),
+            CommerceError::BranchCannotHaveBranches(attrs) => attrs.get_code(),
+            CommerceError::AccountCannotBeUpdated(attrs) => attrs.get_code(),
+            CommerceError::AccountsLockedWhileSuspended(attrs) => attrs.get_code(),
         }
     }
 
@@ -148,6 +238,15 @@ impl CommerceError {
             CommerceError::BankCodeIsEmptyOrNull(attrs) => attrs.get_message(),
             CommerceError::NotValidFormatBank(attrs) => attrs.get_message(),
             CommerceError::CommerceInactive(attrs) => attrs.get_message(),
+            CommerceError::NotFound(attrs) => attrs.get_message(),
+            CommerceError::RucCannotBeModified(attrs) => attrs.get_message(),
+            CommerceError::StatusTransitionNotAllowed(attrs) => attrs.get_message(),
+            CommerceError::StatusReasonIsEmptyOrNull(attrs) => attrs.get_message(),
+            CommerceError::AccountNotFound(attrs) => attrs.get_message(),
+            CommerceError::PrimaryAccountCannotBeRemoved(attrs) => attrs.get_message(),
+            CommerceError::BranchCannotHaveBranches(attrs) => attrs.get_message(),
+            CommerceError::AccountCannotBeUpdated(attrs) => attrs.get_message(),
+            CommerceError::AccountsLockedWhileSuspended(attrs) => attrs.get_message(),
         }
     }
 }
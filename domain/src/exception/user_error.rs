@@ -18,6 +18,7 @@ pub enum UserError {
     CreditorInvalidAliasType(ErrorAttributes),
     CreditorInvalidAlias(ErrorAttributes),
     CreditorInvalidCountryCode(ErrorAttributes),
+    AliasAlreadyExists(ErrorAttributes),
 }
 
 impl UserError {
@@ -125,6 +126,14 @@ impl UserError {
         ))
     }
 
+    pub fn alias_already_exists() -> Self {
+        error!("User alias already exists");
+        UserError::AliasAlreadyExists(ErrorAttributes::new(
+            String::from("ERR-116"),
+            String::from("El alias de usuario ya se encuentra registrado"),
+        ))
+    }
+
     pub fn get_code(&self) -> &str {
         match self {
             UserError::DebtorNull(attrs) => attrs.get_code(),
@@ -140,6 +149,7 @@ impl UserError {
             UserError::CreditorInvalidAliasType(attrs) => attrs.get_code(),
             UserError::CreditorInvalidAlias(attrs) => attrs.get_code(),
             UserError::CreditorInvalidCountryCode(attrs) => attrs.get_code(),
+            UserError::AliasAlreadyExists(attrs) => attrs.get_code(),
         }
     }
 
@@ -158,6 +168,7 @@ impl UserError {
             UserError::CreditorInvalidAliasType(attrs) => attrs.get_message(),
             UserError::CreditorInvalidAlias(attrs) => attrs.get_message(),
             UserError::CreditorInvalidCountryCode(attrs) => attrs.get_message(),
+            UserError::AliasAlreadyExists(attrs) => attrs.get_message(),
         }
     }
 }
@@ -3,12 +3,26 @@ pub mod model {
     pub mod alias_type;
     pub mod account;
     pub mod bank;
+    pub mod bank_certificate;
+    pub mod bank_commerce_stats;
+    pub mod bank_stats_filter;
     pub mod bank_status;
+    pub mod bank_status_schedule;
+    pub mod certificate_details;
     pub mod commerce;
+    pub mod commerce_event;
+    pub mod commerce_filter;
+    pub mod commerce_page;
     pub mod commerce_status;
+    pub mod commerce_status_history;
+    pub mod commerce_update;
     pub mod country;
+    pub mod creditor_user_info;
+    pub mod debtor_user_info;
     pub mod generic_response;
     pub mod message;
+    pub mod notification;
+    pub mod party_kind;
     pub mod status_creditor_user_info;
     pub mod status_debtor_user_info;
     pub mod user;
@@ -1,16 +1,37 @@
 use serde::{Serialize, Deserialize};
+use crate::model::party_kind::PartyKind;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AliasType {
-    pub id: i64,  
+    pub id: i64,
     pub description: String,
+    // Regular expression the alias must match in its stored form, with the "@" of commerce
+    // aliases and the E.164 form of phone aliases. Lengths are checked on the same form
+    pub pattern: String,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub party_kinds: Vec<PartyKind>,
 }
 
 impl AliasType {
-    pub fn new(id: i64, description: String) -> Self {
+    pub fn new(id: i64, description: String, pattern: String, min_length: usize,
+               max_length: usize, party_kinds: Vec<PartyKind>) -> Self {
         Self {
             id,
             description,
+            pattern,
+            min_length,
+            max_length,
+            party_kinds,
         }
     }
+
+    pub fn allows(&self, party_kind: PartyKind) -> bool {
+        self.party_kinds.contains(&party_kind)
+    }
+
+    pub fn has_valid_length(&self, alias: &str) -> bool {
+        let length = alias.chars().count();
+        length >= self.min_length && length <= self.max_length
+    }
 }
\ No newline at end of file
@@ -1,8 +1,11 @@
 use serde::{Serialize, Deserialize};
 use crate::model::bank_status::BankStatus;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Bank {
+    // Internal id of the stored bank, lets accounts be linked without looking the bank up again
+    #[serde(skip)]
+    pub bank_id: i64,
     pub bank_name: String,
     pub bank_code: String,
     pub contact_name: String,
@@ -14,6 +17,7 @@ pub struct Bank {
     pub ocp_cert_mtls: String,
 }
 impl Bank {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bank_name: String,
         bank_code: String,
@@ -26,6 +30,7 @@ impl Bank {
         ocp_cert_mtls: String,
     ) -> Self {
         Self {
+            bank_id: 0,
             bank_name,
             bank_code,
             contact_name,
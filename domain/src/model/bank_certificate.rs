@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BankCertificate {
+    pub certificate_id: i64,
+    pub bank_code: String,
+    pub subject: String,
+    pub fingerprint: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub uploaded_at: DateTime<Utc>,
+    // Set when a newer certificate replaces this one, both are valid until then
+    pub retires_at: Option<DateTime<Utc>>,
+}
+
+impl BankCertificate {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        certificate_id: i64,
+        bank_code: String,
+        subject: String,
+        fingerprint: String,
+        not_before: DateTime<Utc>,
+        not_after: DateTime<Utc>,
+        uploaded_at: DateTime<Utc>,
+        retires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            certificate_id,
+            bank_code,
+            subject,
+            fingerprint,
+            not_before,
+            not_after,
+            uploaded_at,
+            retires_at,
+        }
+    }
+}
\ No newline at end of file
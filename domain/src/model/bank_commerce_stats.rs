@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+
+// Number of commerces of a bank sharing the same status, alias type and registration month
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BankCommerceStats {
+    pub bank_code: String,
+    pub bank_name: String,
+    pub commerce_status: String,
+    pub alias_type: String,
+    // YYYY-MM, empty for commerces registered before creation dates were recorded
+    pub registration_month: Option<String>,
+    pub commerces: i64,
+}
+
+impl BankCommerceStats {
+    pub fn new(
+        bank_code: String,
+        bank_name: String,
+        commerce_status: String,
+        alias_type: String,
+        registration_month: Option<String>,
+        commerces: i64,
+    ) -> Self {
+        Self {
+            bank_code,
+            bank_name,
+            commerce_status,
+            alias_type,
+            registration_month,
+            commerces,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+
+// Both dates are inclusive, commerces without a creation date only match when no date is given
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BankStatsFilter {
+    pub created_from: Option<NaiveDate>,
+    pub created_to: Option<NaiveDate>,
+}
+
+impl BankStatsFilter {
+    pub fn new(created_from: Option<NaiveDate>, created_to: Option<NaiveDate>) -> Self {
+        Self {
+            created_from,
+            created_to,
+        }
+    }
+}
\ No newline at end of file
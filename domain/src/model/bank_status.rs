@@ -1,13 +1,72 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BankStatus {
-    pub status_name: String
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BankStatus {
+    Pending,
+    Certified,
+    Enabled,
+    Suspended,
+    Offboarded,
 }
+
 impl BankStatus {
-    pub fn new(status_name: String) -> Self {
-        Self {
-            status_name,
+    // Ids match the rows seeded in the bank_status table, 2 was DISABLED before the onboarding states
+    pub fn id(&self) -> i64 {
+        match self {
+            BankStatus::Enabled => 1,
+            BankStatus::Suspended => 2,
+            BankStatus::Pending => 3,
+            BankStatus::Certified => 4,
+            BankStatus::Offboarded => 5,
+        }
+    }
+
+    pub fn status_name(&self) -> &'static str {
+        match self {
+            BankStatus::Pending => "PENDING",
+            BankStatus::Certified => "CERTIFIED",
+            BankStatus::Enabled => "ENABLED",
+            BankStatus::Suspended => "SUSPENDED",
+            BankStatus::Offboarded => "OFFBOARDED",
+        }
+    }
+
+    pub fn from_id(status_id: i64) -> Option<Self> {
+        match status_id {
+            1 => Some(BankStatus::Enabled),
+            2 => Some(BankStatus::Suspended),
+            3 => Some(BankStatus::Pending),
+            4 => Some(BankStatus::Certified),
+            5 => Some(BankStatus::Offboarded),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(status_name: &str) -> Option<Self> {
+        match status_name {
+            "PENDING" => Some(BankStatus::Pending),
+            "CERTIFIED" => Some(BankStatus::Certified),
+            "ENABLED" => Some(BankStatus::Enabled),
+            "SUSPENDED" => Some(BankStatus::Suspended),
+            "OFFBOARDED" => Some(BankStatus::Offboarded),
+            _ => None,
         }
     }
+
+    // Onboarding goes PENDING -> CERTIFIED -> ENABLED, an enabled bank can be suspended and
+    // restored, and any bank can be offboarded for good
+    pub fn can_transition_to(&self, next_status: &BankStatus) -> bool {
+        matches!(
+            (self, next_status),
+            (BankStatus::Pending, BankStatus::Certified)
+                | (BankStatus::Certified, BankStatus::Enabled)
+                | (BankStatus::Enabled, BankStatus::Suspended)
+                | (BankStatus::Suspended, BankStatus::Enabled)
+                | (BankStatus::Pending, BankStatus::Offboarded)
+                | (BankStatus::Certified, BankStatus::Offboarded)
+                | (BankStatus::Enabled, BankStatus::Offboarded)
+                | (BankStatus::Suspended, BankStatus::Offboarded)
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::model::bank_status::BankStatus;
+
+// Status change requested ahead of time, applied once effective_at is reached
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BankStatusSchedule {
+    pub schedule_id: i64,
+    pub bank_code: String,
+    pub new_status: BankStatus,
+    pub effective_at: DateTime<Utc>,
+    pub scheduled_by: String,
+}
+
+impl BankStatusSchedule {
+    pub fn new(
+        schedule_id: i64,
+        bank_code: String,
+        new_status: BankStatus,
+        effective_at: DateTime<Utc>,
+        scheduled_by: String,
+    ) -> Self {
+        Self {
+            schedule_id,
+            bank_code,
+            new_status,
+            effective_at,
+            scheduled_by,
+        }
+    }
+
+    pub fn is_due_at(&self, at: &DateTime<Utc>) -> bool {
+        self.effective_at <= *at
+    }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+// Fields read from an X.509 certificate, the fingerprint is the SHA-256 of its DER encoding
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CertificateDetails {
+    pub subject: String,
+    pub fingerprint: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl CertificateDetails {
+    pub fn new(
+        subject: String,
+        fingerprint: String,
+        not_before: DateTime<Utc>,
+        not_after: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            subject,
+            fingerprint,
+            not_before,
+            not_after,
+        }
+    }
+
+    pub fn is_expired_at(&self, at: &DateTime<Utc>) -> bool {
+        self.not_after <= *at
+    }
+}
\ No newline at end of file
@@ -8,9 +8,14 @@ pub struct Commerce {
     pub alias: String,
     pub alias_type: i64,
     pub legal_business_name: String,
+    // Primary settlement account, the one used for alias resolution
     pub account: Account,
+    // Every settlement account of the commerce, the primary one included
+    pub accounts: Vec<Account>,
     pub ruc: String,
     pub commerce_status: CommerceStatus,
+    // Set when the commerce is a branch reporting under a parent commerce
+    pub parent_commerce_id: Option<i64>,
 }
 
 impl Commerce {
@@ -28,9 +33,23 @@ impl Commerce {
             alias,
             alias_type,
             legal_business_name,
+            accounts: vec![account.clone()],
             account,
             ruc,
             commerce_status,
+            parent_commerce_id: None,
         }
     }
+
+    pub fn is_branch(&self) -> bool {
+        self.parent_commerce_id.is_some()
+    }
+
+    pub fn find_account(&self, account_id: i64) -> Option<&Account> {
+        self.accounts.iter().find(|account| account.account_id == account_id)
+    }
+
+    pub fn canonical_alias(alias: &str) -> String {
+        format!("@{}", alias.trim().trim_start_matches('@'))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+// Commerce changes the settling bank is notified about
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CommerceEvent {
+    Created,
+    Deactivated,
+    AccountChanged,
+}
+
+impl CommerceEvent {
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            CommerceEvent::Created => "COMMERCE_CREATED",
+            CommerceEvent::Deactivated => "COMMERCE_DEACTIVATED",
+            CommerceEvent::AccountChanged => "COMMERCE_ACCOUNT_CHANGED",
+        }
+    }
+}
\ No newline at end of file
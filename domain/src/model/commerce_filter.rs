@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CommerceFilter {
+    pub bank_code: Option<String>,
+    pub status_name: Option<String>,
+    pub alias_type: Option<i64>,
+    pub ruc_prefix: Option<String>,
+    pub legal_business_name: Option<String>,
+}
+
+impl CommerceFilter {
+    pub fn new(
+        bank_code: Option<String>,
+        status_name: Option<String>,
+        alias_type: Option<i64>,
+        ruc_prefix: Option<String>,
+        legal_business_name: Option<String>,
+    ) -> Self {
+        Self {
+            bank_code,
+            status_name,
+            alias_type,
+            ruc_prefix,
+            legal_business_name,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use serde::{Serialize, Deserialize};
+use crate::model::commerce::Commerce;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommercePage {
+    pub commerces: Vec<Commerce>,
+    pub next_cursor: Option<i64>,
+    pub total_count: i64,
+}
+
+impl CommercePage {
+    pub fn new(commerces: Vec<Commerce>, next_cursor: Option<i64>, total_count: i64) -> Self {
+        Self {
+            commerces,
+            next_cursor,
+            total_count,
+        }
+    }
+}
\ No newline at end of file
@@ -1,14 +1,57 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct CommerceStatus {
-    pub status_name: String,
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CommerceStatus {
+    Active,
+    Inactive,
+    // Set only while the bank of the primary account is disabled
+    Suspended,
 }
 
 impl CommerceStatus {
-    pub fn new(status_name: String) -> Self {
-        Self {
-            status_name,
+    // Ids match the rows seeded in the commerce_status table
+    pub fn id(&self) -> i64 {
+        match self {
+            CommerceStatus::Active => 1,
+            CommerceStatus::Inactive => 2,
+            CommerceStatus::Suspended => 3,
         }
     }
+
+    pub fn status_name(&self) -> &'static str {
+        match self {
+            CommerceStatus::Active => "ACTIVE",
+            CommerceStatus::Inactive => "INACTIVE",
+            CommerceStatus::Suspended => "SUSPENDED",
+        }
+    }
+
+    pub fn from_id(commerce_status_id: i64) -> Option<Self> {
+        match commerce_status_id {
+            1 => Some(CommerceStatus::Active),
+            2 => Some(CommerceStatus::Inactive),
+            3 => Some(CommerceStatus::Suspended),
+            _ => None,
+        }
+    }
+
+    pub fn from_status_name(status_name: &str) -> Option<Self> {
+        match status_name.trim().to_uppercase().as_str() {
+            "ACTIVE" => Some(CommerceStatus::Active),
+            "INACTIVE" => Some(CommerceStatus::Inactive),
+            "SUSPENDED" => Some(CommerceStatus::Suspended),
+            _ => None,
+        }
+    }
+
+    // Suspension is driven by the bank status, so it is never requested for a single commerce
+    pub fn can_transition_to(&self, next_status: &CommerceStatus) -> bool {
+        matches!(
+            (self, next_status),
+            (CommerceStatus::Active, CommerceStatus::Inactive)
+                | (CommerceStatus::Inactive, CommerceStatus::Active)
+                | (CommerceStatus::Suspended, CommerceStatus::Inactive)
+        )
+    }
 }
\ No newline at end of file
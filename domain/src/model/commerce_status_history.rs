@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::model::commerce_status::CommerceStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommerceStatusHistory {
+    pub history_id: i64,
+    pub commerce_id: i64,
+    pub previous_status: CommerceStatus,
+    pub new_status: CommerceStatus,
+    pub reason: String,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl CommerceStatusHistory {
+    pub fn new(
+        history_id: i64,
+        commerce_id: i64,
+        previous_status: CommerceStatus,
+        new_status: CommerceStatus,
+        reason: String,
+        changed_by: String,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            history_id,
+            commerce_id,
+            previous_status,
+            new_status,
+            reason,
+            changed_by,
+            changed_at,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CommerceUpdate {
+    pub alias: Option<String>,
+    pub alias_type: Option<i64>,
+    pub legal_business_name: Option<String>,
+    pub ruc: Option<String>,
+}
+
+impl CommerceUpdate {
+    pub fn new(
+        alias: Option<String>,
+        alias_type: Option<i64>,
+        legal_business_name: Option<String>,
+        ruc: Option<String>,
+    ) -> Self {
+        Self {
+            alias,
+            alias_type,
+            legal_business_name,
+            ruc,
+        }
+    }
+}
\ No newline at end of file
@@ -1,18 +1,23 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Country {
     pub country_id: i64,
     pub country_name: String,
+    // ISO 3166-1 numeric code
     pub country_code: i32,
+    // International dialling code without the leading '+'
+    pub dialling_code: String,
 }
 
 impl Country {
-    pub fn new(country_id: i64, country_name: String, country_code: i32) -> Self {
+    pub fn new(country_id: i64, country_name: String, country_code: i32,
+               dialling_code: String) -> Self {
         Self {
             country_id,
             country_name,
             country_code,
+            dialling_code,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+use serde::{Serialize, Deserialize};
+use crate::model::commerce_status::CommerceStatus;
+
+const VISIBLE_CHARS_PER_WORD: usize = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreditorUserInfo {
+    pub alias_value: String,
+    pub legal_business_name: String,
+    pub bank_code: String,
+    pub commerce_status: CommerceStatus,
+}
+
+impl CreditorUserInfo {
+    pub fn new(
+        alias_value: String,
+        legal_business_name: String,
+        bank_code: String,
+        commerce_status: CommerceStatus,
+    ) -> Self {
+        Self {
+            alias_value,
+            legal_business_name: mask_legal_business_name(&legal_business_name),
+            bank_code,
+            commerce_status,
+        }
+    }
+}
+
+// Keeps the first characters of every word so the payer can recognise the merchant
+// without exposing its full legal business name, e.g. "Bodega Lucia SAC" -> "Bo**** Lu*** SA*"
+fn mask_legal_business_name(legal_business_name: &str) -> String {
+    legal_business_name
+        .split_whitespace()
+        .map(|word| word.chars()
+            .enumerate()
+            .map(|(index, character)| if index < VISIBLE_CHARS_PER_WORD { character } else { '*' })
+            .collect::<String>())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
@@ -0,0 +1,22 @@
+use serde::{Serialize, Deserialize};
+use crate::model::user_status::UserStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DebtorUserInfo {
+    pub alias_value: String,
+    pub bank_code: String,
+    pub country_code: i32,
+    pub user_status: UserStatus,
+}
+
+impl DebtorUserInfo {
+    pub fn new(alias_value: String, bank_code: String, country_code: i32,
+               user_status: UserStatus) -> Self {
+        Self {
+            alias_value,
+            bank_code,
+            country_code,
+            user_status,
+        }
+    }
+}
\ No newline at end of file
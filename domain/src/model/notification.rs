@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Notification {
+    pub recipient: String,
+    pub subject: String,
+    pub body: String,
+}
+
+impl Notification {
+    pub fn new(recipient: String, subject: String, body: String) -> Self {
+        Self {
+            recipient,
+            subject,
+            body,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use serde::{Serialize, Deserialize};
+
+// Kind of party an alias can be registered for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PartyKind {
+    Commerce,
+    User,
+}
\ No newline at end of file
@@ -1,9 +1,15 @@
 use serde::{Serialize, Deserialize};
 use crate::model::account::Account;
+use crate::model::country::Country;
 use crate::model::user_status::UserStatus;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+const PHONE_SEPARATORS: [char; 5] = [' ', '-', '.', '(', ')'];
+const MIN_NATIONAL_NUMBER_LENGTH: usize = 7;
+const MAX_E164_DIGITS: usize = 15;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct User {
+    pub user_id: i64,
     pub alias_type: i64,
     pub alias: String,
     pub country: i32,
@@ -13,6 +19,7 @@ pub struct User {
 
 impl User {
     pub fn new(
+        user_id: i64,
         alias_type: i64,
         alias: String,
         country: i32,
@@ -20,6 +27,7 @@ impl User {
         user_status: UserStatus,
     ) -> Self {
         Self {
+            user_id,
             alias_type,
             alias,
             country,
@@ -27,4 +35,34 @@ impl User {
             user_status,
         }
     }
+
+    // E.164 form of a PHONE alias, e.g. "+51 999-888-777", "51999888777" and "999888777" are all
+    // "+51999888777" for Peru. Digits without '+' are taken as international only when they start
+    // with the dialling code and a full national number follows it
+    pub fn canonical_phone_alias(alias: &str, country: &Country) -> Option<String> {
+        let compact_alias: String = alias.trim().chars()
+            .filter(|character| !PHONE_SEPARATORS.contains(character))
+            .collect();
+        let (is_international, digits) = match compact_alias.strip_prefix('+') {
+            Some(digits) => (true, digits),
+            None => (false, compact_alias.as_str())
+        };
+        if digits.is_empty() || !digits.chars().all(|character| character.is_ascii_digit()) {
+            return None;
+        }
+
+        let national_number = match digits.strip_prefix(country.dialling_code.as_str()) {
+            Some(national_number)
+                if is_international || national_number.len() >= MIN_NATIONAL_NUMBER_LENGTH =>
+                national_number,
+            // An international number of another country
+            _ if is_international => return None,
+            _ => digits
+        };
+        if national_number.len() < MIN_NATIONAL_NUMBER_LENGTH
+            || country.dialling_code.len() + national_number.len() > MAX_E164_DIGITS {
+            return None;
+        }
+        Some(format!("+{}{}", country.dialling_code, national_number))
+    }
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+const ACTIVE_STATUS_ID: i64 = 1;
+const INACTIVE_STATUS_ID: i64 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UserStatus {
     pub status_id: i64,
     pub status_name: String,
@@ -13,4 +16,16 @@ impl UserStatus {
             status_name,
         }
     }
+
+    pub fn active() -> Self {
+        Self::new(ACTIVE_STATUS_ID, String::from("ACTIVE"))
+    }
+
+    pub fn inactive() -> Self {
+        Self::new(INACTIVE_STATUS_ID, String::from("INACTIVE"))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status_id == ACTIVE_STATUS_ID
+    }
 }
\ No newline at end of file
@@ -0,0 +1,163 @@
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{error, info};
+use application::port::db::banks::bank_repository_port::BankRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::certificate_details::CertificateDetails;
+
+// Keeps the bank catalog in memory, writes go to the wrapped repository and drop the catalog so
+// the next read loads it again
+pub struct CachedBankRepositoryAdapter<BR: BankRepositoryPort> {
+    bank_repository: Arc<BR>,
+    banks: RwLock<Option<Vec<Bank>>>,
+    // Bumped on every invalidation so a load that raced with a write is not stored
+    generation: AtomicU64
+}
+
+impl<BR> CachedBankRepositoryAdapter<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    pub fn new(bank_repository: Arc<BR>) -> Self {
+        Self {
+            bank_repository,
+            banks: RwLock::new(None),
+            generation: AtomicU64::new(0)
+        }
+    }
+
+    pub async fn refresh(&self) -> Result<(), DatabaseError> {
+        self.load().await.map(|_| ())
+    }
+
+    pub fn invalidate(&self) {
+        info!("Bank catalog invalidated");
+        let mut banks = self.banks.write().unwrap();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        *banks = None;
+    }
+
+    // Reloads the catalog periodically so changes made by other instances are picked up
+    pub fn spawn_refresh(self: Arc<Self>, refresh_interval: Duration) {
+        rocket::tokio::spawn(async move {
+            let mut interval = rocket::tokio::time::interval(refresh_interval);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if let Err(err) = self.refresh().await {
+                    error!("Unable to refresh the bank catalog. Error is: {:?}", err);
+                }
+            }
+        });
+    }
+
+    // The loaded banks are returned to the caller even when an invalidation makes them too old
+    // to be cached
+    async fn load(&self) -> Result<Vec<Bank>, DatabaseError> {
+        let generation = self.generation.load(Ordering::SeqCst);
+        let banks = self.bank_repository.find_banks().await?;
+        let mut cached_banks = self.banks.write().unwrap();
+        if self.generation.load(Ordering::SeqCst) == generation {
+            info!("Bank catalog loaded with {} banks", banks.len());
+            *cached_banks = Some(banks.clone());
+        } else {
+            info!("Bank catalog changed while loading, the loaded banks are not cached");
+        }
+        Ok(banks)
+    }
+
+    async fn find_in_catalog<T, F>(&self, lookup: F) -> Result<T, DatabaseError>
+    where
+        F: Fn(&[Bank]) -> T
+    {
+        if let Some(banks) = self.banks.read().unwrap().as_ref() {
+            return Ok(lookup(banks));
+        }
+        let banks = self.load().await?;
+        Ok(lookup(&banks))
+    }
+}
+
+#[async_trait]
+impl<BR> BankRepositoryPort for CachedBankRepositoryAdapter<BR>
+where
+    BR: BankRepositoryPort + Send + Sync + 'static
+{
+    async fn find_bank_by_code(&self, bank_code: &str) -> Result<Option<Bank>, DatabaseError> {
+        let bank = self
+            .find_in_catalog(|banks| banks.iter().find(|bank| bank.bank_code == bank_code).cloned())
+            .await?;
+        if bank.is_some() {
+            return Ok(bank);
+        }
+        // The bank may have been registered by another instance since the last refresh
+        let bank = self.bank_repository.find_bank_by_code(bank_code).await?;
+        if bank.is_some() {
+            self.invalidate();
+        }
+        Ok(bank)
+    }
+
+    async fn find_banks(&self) -> Result<Vec<Bank>, DatabaseError> {
+        self.find_in_catalog(|banks| banks.to_vec()).await
+    }
+
+    async fn create_bank(&self, bank: &Bank) -> Result<Bank, DatabaseError> {
+        let result = self.bank_repository.create_bank(bank).await;
+        self.invalidate();
+        result
+    }
+
+    async fn update_bank(&self, bank: &Bank) -> Result<Option<Bank>, DatabaseError> {
+        let result = self.bank_repository.update_bank(bank).await;
+        self.invalidate();
+        result
+    }
+
+    async fn change_bank_status(&self, bank_code: &str, current_status: &BankStatus,
+                                new_status: &BankStatus, changed_by: &str)
+        -> Result<Option<Bank>, DatabaseError> {
+        let result = self.bank_repository
+            .change_bank_status(bank_code, current_status, new_status, changed_by)
+            .await;
+        self.invalidate();
+        result
+    }
+
+    async fn add_bank_certificate(&self, bank_code: &str, certificate_pem: &str,
+                                  details: &CertificateDetails, retire_previous_at: &DateTime<Utc>)
+        -> Result<Option<BankCertificate>, DatabaseError> {
+        let result = self.bank_repository
+            .add_bank_certificate(bank_code, certificate_pem, details, retire_previous_at)
+            .await;
+        self.invalidate();
+        result
+    }
+
+    async fn find_valid_bank_certificates(&self, bank_code: &str, at: &DateTime<Utc>)
+        -> Result<Vec<BankCertificate>, DatabaseError> {
+        self.bank_repository.find_valid_bank_certificates(bank_code, at).await
+    }
+
+    async fn schedule_bank_status_change(&self, schedule: &BankStatusSchedule)
+        -> Result<Option<BankStatusSchedule>, DatabaseError> {
+        self.bank_repository.schedule_bank_status_change(schedule).await
+    }
+
+    async fn find_due_bank_status_schedules(&self, at: &DateTime<Utc>)
+        -> Result<Vec<BankStatusSchedule>, DatabaseError> {
+        self.bank_repository.find_due_bank_status_schedules(at).await
+    }
+
+    async fn complete_bank_status_schedule(&self, schedule_id: &i64, applied: &bool)
+        -> Result<(), DatabaseError> {
+        self.bank_repository.complete_bank_status_schedule(schedule_id, applied).await
+    }
+}
\ No newline at end of file
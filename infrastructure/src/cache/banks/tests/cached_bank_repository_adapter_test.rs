@@ -0,0 +1,218 @@
+use mockall::predicate::*;
+use std::sync::{Arc, OnceLock, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use chrono::Utc;
+use application::port::db::banks::bank_repository_port::BankRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank_status::BankStatus;
+use crate::cache::banks::cached_bank_repository_adapter::CachedBankRepositoryAdapter;
+use crate::cache::banks::tests::mocks::{create_bank_for_test, create_certificate_details_for_test,
+                                        database_error_for_test, MockBankRepo};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository_with_catalog_loads(times: usize) -> MockBankRepo {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(|| Ok(vec![create_bank_for_test("001", BankStatus::Enabled)]))
+            .times(times);
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_find_bank_by_code_is_served_from_the_catalog() {
+        let mut repository = repository_with_catalog_loads(1);
+        repository.expect_find_bank_by_code().times(0);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        let first_lookup = adapter.find_bank_by_code("001").await.unwrap();
+        let second_lookup = adapter.find_bank_by_code("001").await.unwrap();
+
+        assert_eq!(first_lookup, Some(create_bank_for_test("001", BankStatus::Enabled)));
+        assert_eq!(second_lookup, first_lookup);
+    }
+
+    #[tokio::test]
+    async fn test_find_bank_by_code_missing_from_the_catalog_reads_the_repository() {
+        let mut repository = repository_with_catalog_loads(1);
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| Ok(None))
+            .times(2);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        assert_eq!(adapter.find_bank_by_code("002").await.unwrap(), None);
+        assert_eq!(adapter.find_bank_by_code("002").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_find_bank_by_code_registered_elsewhere_reloads_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_find_bank_by_code()
+            .with(eq(String::from("002")))
+            .returning(|_| Ok(Some(create_bank_for_test("002", BankStatus::Pending))))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        let bank = adapter.find_bank_by_code("002").await.unwrap();
+        adapter.find_banks().await.unwrap();
+
+        assert_eq!(bank, Some(create_bank_for_test("002", BankStatus::Pending)));
+    }
+
+    #[tokio::test]
+    async fn test_create_bank_invalidates_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_create_bank()
+            .returning(|bank| Ok(bank.clone()))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.find_banks().await.unwrap();
+        adapter.create_bank(&create_bank_for_test("002", BankStatus::Pending)).await.unwrap();
+        adapter.find_banks().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_bank_invalidates_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_update_bank()
+            .returning(|bank| Ok(Some(bank.clone())))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.find_banks().await.unwrap();
+        adapter.update_bank(&create_bank_for_test("001", BankStatus::Enabled)).await.unwrap();
+        adapter.find_banks().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_change_bank_status_invalidates_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_change_bank_status()
+            .returning(|_, _, _, _| Ok(Some(create_bank_for_test("001", BankStatus::Suspended))))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.find_banks().await.unwrap();
+        adapter
+            .change_bank_status("001", &BankStatus::Enabled, &BankStatus::Suspended, "operator")
+            .await
+            .unwrap();
+        adapter.find_banks().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_add_bank_certificate_invalidates_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_add_bank_certificate()
+            .returning(|_, _, _, _| Ok(None))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.find_banks().await.unwrap();
+        adapter
+            .add_bank_certificate("001", "pem", &create_certificate_details_for_test(), &Utc::now())
+            .await
+            .unwrap();
+        adapter.find_banks().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_still_invalidates_the_catalog() {
+        let mut repository = repository_with_catalog_loads(2);
+        repository
+            .expect_update_bank()
+            .returning(|_| Err(database_error_for_test()))
+            .times(1);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.find_banks().await.unwrap();
+        assert!(adapter.update_bank(&create_bank_for_test("001", BankStatus::Enabled)).await.is_err());
+        adapter.find_banks().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_catalog_load_failure_is_returned() {
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(|| Err(database_error_for_test()))
+            .times(1);
+        repository.expect_find_bank_by_code().times(0);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        let error = adapter.find_bank_by_code("001").await.unwrap_err();
+
+        assert!(matches!(error, DatabaseError::Unexpected(_)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_failure_keeps_the_loaded_catalog() {
+        let loads = AtomicUsize::new(0);
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(move || match loads.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(vec![create_bank_for_test("001", BankStatus::Enabled)]),
+                _ => Err(database_error_for_test())
+            })
+            .times(2);
+        repository.expect_find_bank_by_code().times(0);
+
+        let adapter = CachedBankRepositoryAdapter::new(Arc::new(repository));
+
+        adapter.refresh().await.unwrap();
+        assert!(adapter.refresh().await.is_err());
+        let bank = adapter.find_bank_by_code("001").await.unwrap();
+
+        assert_eq!(bank, Some(create_bank_for_test("001", BankStatus::Enabled)));
+    }
+
+    #[tokio::test]
+    async fn test_load_racing_with_an_invalidation_is_not_cached() {
+        type Adapter = CachedBankRepositoryAdapter<MockBankRepo>;
+        let adapter_slot: Arc<OnceLock<Weak<Adapter>>> = Arc::new(OnceLock::new());
+        let racing_adapter = adapter_slot.clone();
+        let loads = AtomicUsize::new(0);
+        let mut repository = MockBankRepo::new();
+        repository
+            .expect_find_banks()
+            .returning(move || match loads.fetch_add(1, Ordering::SeqCst) {
+                0 => {
+                    // The bank is disabled while its old status is being read
+                    racing_adapter.get().and_then(Weak::upgrade).unwrap().invalidate();
+                    Ok(vec![create_bank_for_test("001", BankStatus::Enabled)])
+                },
+                _ => Ok(vec![create_bank_for_test("001", BankStatus::Suspended)])
+            })
+            .times(2);
+
+        let adapter = Arc::new(CachedBankRepositoryAdapter::new(Arc::new(repository)));
+        adapter_slot.set(Arc::downgrade(&adapter)).unwrap();
+
+        let racing_lookup = adapter.find_bank_by_code("001").await.unwrap().unwrap();
+        let next_lookup = adapter.find_bank_by_code("001").await.unwrap().unwrap();
+
+        assert_eq!(racing_lookup.bank_status, BankStatus::Enabled);
+        assert_eq!(next_lookup.bank_status, BankStatus::Suspended);
+    }
+}
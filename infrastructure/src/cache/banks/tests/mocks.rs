@@ -0,0 +1,73 @@
+use mockall::mock;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use application::port::db::banks::bank_repository_port::BankRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::certificate_details::CertificateDetails;
+
+// Mocks shared by the bank cache tests
+mock! {
+    pub BankRepo {}
+
+    #[async_trait]
+    impl BankRepositoryPort for BankRepo {
+        async fn find_bank_by_code(&self, bank_code: &str) -> Result<Option<Bank>, DatabaseError>;
+
+        async fn find_banks(&self) -> Result<Vec<Bank>, DatabaseError>;
+
+        async fn create_bank(&self, bank: &Bank) -> Result<Bank, DatabaseError>;
+
+        async fn update_bank(&self, bank: &Bank) -> Result<Option<Bank>, DatabaseError>;
+
+        async fn change_bank_status(&self, bank_code: &str, current_status: &BankStatus,
+                                    new_status: &BankStatus, changed_by: &str)
+        -> Result<Option<Bank>, DatabaseError>;
+
+        async fn add_bank_certificate(&self, bank_code: &str, certificate_pem: &str,
+                                      details: &CertificateDetails, retire_previous_at: &DateTime<Utc>)
+        -> Result<Option<BankCertificate>, DatabaseError>;
+
+        async fn find_valid_bank_certificates(&self, bank_code: &str, at: &DateTime<Utc>)
+        -> Result<Vec<BankCertificate>, DatabaseError>;
+
+        async fn schedule_bank_status_change(&self, schedule: &BankStatusSchedule)
+        -> Result<Option<BankStatusSchedule>, DatabaseError>;
+
+        async fn find_due_bank_status_schedules(&self, at: &DateTime<Utc>)
+        -> Result<Vec<BankStatusSchedule>, DatabaseError>;
+
+        async fn complete_bank_status_schedule(&self, schedule_id: &i64, applied: &bool)
+        -> Result<(), DatabaseError>;
+    }
+}
+
+pub fn create_bank_for_test(bank_code: &str, bank_status: BankStatus) -> Bank {
+    Bank::new(
+        String::from("Bank of America"),
+        String::from(bank_code),
+        String::from("John Doe"),
+        String::from("john.doe@boa.com"),
+        String::from("notifications@boa.com"),
+        String::from("1234567890"),
+        String::from("12345678901"),
+        bank_status,
+        String::new()
+    )
+}
+
+pub fn create_certificate_details_for_test() -> CertificateDetails {
+    CertificateDetails::new(
+        String::from("CN=api.boa.com, O=Bank of America"),
+        String::from("AB:CD:EF"),
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2099, 1, 1, 0, 0, 0).unwrap()
+    )
+}
+
+pub fn database_error_for_test() -> DatabaseError {
+    DatabaseError::Unexpected(Box::new(std::io::Error::other("Database error in test")))
+}
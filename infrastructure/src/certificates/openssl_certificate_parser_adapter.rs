@@ -0,0 +1,64 @@
+use application::port::certificates::certificate_parser_port::CertificateParserPort;
+use chrono::{DateTime, Utc};
+use domain::model::certificate_details::CertificateDetails;
+use openssl::asn1::{Asn1Time, Asn1TimeRef};
+use openssl::hash::MessageDigest;
+use openssl::x509::X509;
+use tracing::error;
+
+pub struct OpensslCertificateParserAdapter;
+
+impl OpensslCertificateParserAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for OpensslCertificateParserAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CertificateParserPort for OpensslCertificateParserAdapter {
+    fn parse_certificate(&self, certificate_pem: &str) -> Option<CertificateDetails> {
+        let certificate = match X509::from_pem(certificate_pem.as_bytes()) {
+            Ok(certificate) => certificate,
+            Err(err) => {
+                error!("Certificate is not a valid PEM. Error is: {:?}", err);
+                return None;
+            }
+        };
+
+        let subject = certificate.subject_name().entries()
+            .filter_map(|entry| {
+                let value = entry.data().to_string().ok()?;
+                Some(format!("{}={}", entry.object().nid().short_name().ok()?, value))
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let fingerprint = match certificate.digest(MessageDigest::sha256()) {
+            Ok(digest) => digest.iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<Vec<String>>()
+                .join(":"),
+            Err(err) => {
+                error!("Unable to compute the certificate fingerprint. Error is: {:?}", err);
+                return None;
+            }
+        };
+
+        Some(CertificateDetails::new(
+            subject,
+            fingerprint,
+            map_asn1_time_to_utc(certificate.not_before())?,
+            map_asn1_time_to_utc(certificate.not_after())?,
+        ))
+    }
+}
+
+fn map_asn1_time_to_utc(time: &Asn1TimeRef) -> Option<DateTime<Utc>> {
+    let epoch = Asn1Time::from_unix(0).ok()?;
+    let diff = epoch.diff(time).ok()?;
+    DateTime::from_timestamp(diff.days as i64 * 86_400 + diff.secs as i64, 0)
+}
\ No newline at end of file
@@ -1,8 +1,19 @@
 use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::Error;
 use tracing::error;
 use application::port::db::banks::bank_repository_port::BankRepositoryPort;
 use domain::exception::database_error::DatabaseError;
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::certificate_details::CertificateDetails;
+use domain::model::commerce_status::CommerceStatus;
+use crate::db::mysql::banks::entity::bank_certificate_entity::BankCertificateEntity;
+use crate::db::mysql::banks::entity::bank_entity::BankEntity;
+use crate::db::mysql::banks::entity::bank_status_schedule_entity::BankStatusScheduleEntity;
 use crate::db::mysql::banks::repository::bank_repository::{BankRepository, SqlxBankRepository};
 
 pub struct BankRepositoryAdapter {
@@ -13,20 +24,223 @@ impl BankRepositoryAdapter {
     pub fn new(bank_repository: Arc<SqlxBankRepository>) -> Self {
         Self { bank_repository }
     }
+
+    fn log_error_info(err: &Error) {
+        error!("Unexpected error from database. Error is: {:?}", err);
+    }
 }
 
 #[async_trait]
 impl BankRepositoryPort for BankRepositoryAdapter {
 
-    async fn validate_if_bank_exists_exists_by_bank_code(&self, bank_code: &String)
-        -> Result<bool, DatabaseError> {
+    async fn find_bank_by_code(&self, bank_code: &str) -> Result<Option<Bank>, DatabaseError> {
         match self.bank_repository.find_bank_by_bank_code(bank_code).await {
-            Ok(Some(bank_entity)) => Ok(bank_entity.bank_code == *bank_code),
-            Ok(None) => Ok(false),
+            Ok(bank_entity) => Ok(bank_entity.as_ref().map(map_bank_entity_to_model)),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_banks(&self) -> Result<Vec<Bank>, DatabaseError> {
+        match self.bank_repository.find_banks().await {
+            Ok(bank_entities) => Ok(bank_entities.iter().map(map_bank_entity_to_model).collect()),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn create_bank(&self, bank: &Bank) -> Result<Bank, DatabaseError> {
+        match self.bank_repository.insert_bank(&map_bank_to_entity(bank)).await {
+            Ok(Some(bank_entity)) => Ok(map_bank_entity_to_model(&bank_entity)),
+            Ok(None) => {
+                error!("Bank was not returned after being inserted");
+                Err(DatabaseError::Unexpected(Box::from("Unable to return bank created")))
+            },
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn update_bank(&self, bank: &Bank) -> Result<Option<Bank>, DatabaseError> {
+        match self.bank_repository.update_bank(&map_bank_to_entity(bank)).await {
+            Ok(bank_entity) => Ok(bank_entity.as_ref().map(map_bank_entity_to_model)),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn change_bank_status(&self, bank_code: &str, current_status: &BankStatus,
+                                new_status: &BankStatus, changed_by: &str)
+        -> Result<Option<Bank>, DatabaseError> {
+        // Active commerces are suspended while the bank is suspended and restored when it is
+        // enabled again, offboarding deactivates them for good
+        let commerce_status_change = match (current_status, new_status) {
+            (_, BankStatus::Enabled) => Some((CommerceStatus::Suspended, CommerceStatus::Active)),
+            (_, BankStatus::Suspended) => Some((CommerceStatus::Active, CommerceStatus::Suspended)),
+            (BankStatus::Suspended, BankStatus::Offboarded) =>
+                Some((CommerceStatus::Suspended, CommerceStatus::Inactive)),
+            (_, BankStatus::Offboarded) => Some((CommerceStatus::Active, CommerceStatus::Inactive)),
+            _ => None,
+        }.map(|(commerce_status_from, commerce_status_to)| {
+            (commerce_status_from.id(), commerce_status_to.id())
+        });
+        let reason = format!("Bank {} {}", bank_code, new_status.status_name().to_lowercase());
+        match self.bank_repository
+            .update_bank_status(bank_code, &current_status.id(), &new_status.id(),
+                                &commerce_status_change, &reason, changed_by)
+            .await {
+            Ok(bank_entity) => Ok(bank_entity.as_ref().map(map_bank_entity_to_model)),
             Err(err) => {
-                error!("There was an error validating if bank exists by bank_code. Error is: {:?}", err);
+                Self::log_error_info(&err);
                 Err(DatabaseError::Unexpected(err.into()))
             }
         }
     }
+
+    async fn add_bank_certificate(&self, bank_code: &str, certificate_pem: &str,
+                                  details: &CertificateDetails, retire_previous_at: &DateTime<Utc>)
+        -> Result<Option<BankCertificate>, DatabaseError> {
+        let certificate_entity = BankCertificateEntity {
+            certificate_id: 0,
+            bank_code: bank_code.to_string(),
+            subject: details.subject.clone(),
+            fingerprint: details.fingerprint.clone(),
+            not_before: details.not_before.naive_utc(),
+            not_after: details.not_after.naive_utc(),
+            uploaded_at: Utc::now().naive_utc(),
+            retires_at: None,
+        };
+        match self.bank_repository
+            .insert_bank_certificate(bank_code, certificate_pem, &certificate_entity,
+                                     &retire_previous_at.naive_utc())
+            .await {
+            Ok(entity) => Ok(entity.as_ref().map(map_bank_certificate_entity_to_model)),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_valid_bank_certificates(&self, bank_code: &str, at: &DateTime<Utc>)
+        -> Result<Vec<BankCertificate>, DatabaseError> {
+        match self.bank_repository.find_valid_bank_certificates(bank_code, &at.naive_utc()).await {
+            Ok(entities) => Ok(entities.iter().map(map_bank_certificate_entity_to_model).collect()),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn schedule_bank_status_change(&self, schedule: &BankStatusSchedule)
+        -> Result<Option<BankStatusSchedule>, DatabaseError> {
+        let schedule_entity = BankStatusScheduleEntity {
+            schedule_id: 0,
+            bank_code: schedule.bank_code.clone(),
+            status_id: schedule.new_status.id(),
+            effective_at: schedule.effective_at.naive_utc(),
+            scheduled_by: schedule.scheduled_by.clone(),
+        };
+        match self.bank_repository.insert_bank_status_schedule(&schedule_entity).await {
+            Ok(entity) => Ok(entity.as_ref().map(map_bank_status_schedule_entity_to_model)),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_due_bank_status_schedules(&self, at: &DateTime<Utc>)
+        -> Result<Vec<BankStatusSchedule>, DatabaseError> {
+        match self.bank_repository.find_due_bank_status_schedules(&at.naive_utc()).await {
+            Ok(entities) => Ok(entities.iter().map(map_bank_status_schedule_entity_to_model).collect()),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn complete_bank_status_schedule(&self, schedule_id: &i64, applied: &bool)
+        -> Result<(), DatabaseError> {
+        match self.bank_repository
+            .update_bank_status_schedule_processed(schedule_id, applied, &Utc::now().naive_utc())
+            .await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+}
+
+fn map_bank_to_entity(bank: &Bank) -> BankEntity {
+    BankEntity {
+        bank_id: 0,
+        bank_name: bank.bank_name.clone(),
+        bank_code: bank.bank_code.clone(),
+        contact_name: bank.contact_name.clone(),
+        contact_mail: bank.contact_mail.clone(),
+        notification_mail: bank.notification_mail.clone(),
+        contact_phone: bank.contact_phone.clone(),
+        bank_ruc: bank.bank_ruc.clone(),
+        status_id: bank.bank_status.id(),
+        ocp_cert_mtls: None,
+    }
+}
+
+fn map_bank_entity_to_model(bank_entity: &BankEntity) -> Bank {
+    let mut bank = Bank::new(
+        bank_entity.bank_name.clone(),
+        bank_entity.bank_code.clone(),
+        bank_entity.contact_name.clone(),
+        bank_entity.contact_mail.clone(),
+        bank_entity.notification_mail.clone(),
+        bank_entity.contact_phone.clone(),
+        bank_entity.bank_ruc.clone(),
+        map_bank_status_id_to_model(bank_entity.status_id),
+        bank_entity.ocp_cert_mtls.clone().unwrap_or_default()
+    );
+    bank.bank_id = bank_entity.bank_id;
+    bank
+}
+
+fn map_bank_certificate_entity_to_model(entity: &BankCertificateEntity) -> BankCertificate {
+    BankCertificate::new(
+        entity.certificate_id,
+        entity.bank_code.clone(),
+        entity.subject.clone(),
+        entity.fingerprint.clone(),
+        entity.not_before.and_utc(),
+        entity.not_after.and_utc(),
+        entity.uploaded_at.and_utc(),
+        entity.retires_at.map(|retires_at| retires_at.and_utc())
+    )
+}
+
+fn map_bank_status_schedule_entity_to_model(entity: &BankStatusScheduleEntity) -> BankStatusSchedule {
+    BankStatusSchedule::new(
+        entity.schedule_id,
+        entity.bank_code.clone(),
+        map_bank_status_id_to_model(entity.status_id),
+        entity.effective_at.and_utc(),
+        entity.scheduled_by.clone()
+    )
+}
+
+fn map_bank_status_id_to_model(status_id: i64) -> BankStatus {
+    BankStatus::from_id(status_id).unwrap_or_else(|| {
+        error!("Unknown bank status id {}, treating it as suspended", status_id);
+        BankStatus::Suspended
+    })
 }
\ No newline at end of file
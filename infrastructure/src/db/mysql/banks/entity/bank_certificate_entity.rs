@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct BankCertificateEntity {
+    #[sqlx(rename = "certificate_id")]
+    pub certificate_id: i64,
+    #[sqlx(rename = "bank_code")]
+    pub bank_code: String,
+    #[sqlx(rename = "subject")]
+    pub subject: String,
+    #[sqlx(rename = "fingerprint")]
+    pub fingerprint: String,
+    #[sqlx(rename = "not_before")]
+    pub not_before: NaiveDateTime,
+    #[sqlx(rename = "not_after")]
+    pub not_after: NaiveDateTime,
+    #[sqlx(rename = "uploaded_at")]
+    pub uploaded_at: NaiveDateTime,
+    #[sqlx(rename = "retires_at")]
+    pub retires_at: Option<NaiveDateTime>,
+}
\ No newline at end of file
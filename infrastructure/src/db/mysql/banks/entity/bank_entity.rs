@@ -21,4 +21,6 @@ pub struct BankEntity {
     pub bank_ruc: String,
     #[sqlx(rename = "status_id")]
     pub status_id: i64,
+    #[sqlx(rename = "ocp_cert_mtls")]
+    pub ocp_cert_mtls: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct BankStatusScheduleEntity {
+    #[sqlx(rename = "schedule_id")]
+    pub schedule_id: i64,
+    #[sqlx(rename = "bank_code")]
+    pub bank_code: String,
+    #[sqlx(rename = "status_id")]
+    pub status_id: i64,
+    #[sqlx(rename = "effective_at")]
+    pub effective_at: NaiveDateTime,
+    #[sqlx(rename = "scheduled_by")]
+    pub scheduled_by: String,
+}
\ No newline at end of file
@@ -1,34 +1,407 @@
 use std::sync::Arc;
-use sqlx::{Error, MySqlPool};
+use chrono::{NaiveDateTime, Utc};
+use sqlx::{Error, MySql, MySqlPool, Transaction};
 use async_trait::async_trait;
+use tracing::{info, warn};
+use domain::model::commerce_status::CommerceStatus;
+use crate::db::mysql::banks::entity::bank_certificate_entity::BankCertificateEntity;
 use crate::db::mysql::banks::entity::bank_entity::BankEntity;
+use crate::db::mysql::banks::entity::bank_status_schedule_entity::BankStatusScheduleEntity;
+use crate::db::mysql::commerces::entity::commerce_status_history_entity::CommerceStatusHistoryEntity;
+use crate::db::mysql::commerces::repository::commerce_status_history_repository::{CommerceStatusHistoryRepository, SqlxCommerceStatusHistoryRepository};
+
+// A suspended commerce is only restored once every bank of its accounts is enabled
+const COMMERCE_BANKS_ENABLED_FILTER: &str =
+    " AND NOT EXISTS (SELECT 1 FROM commerce_accounts ca
+        INNER JOIN accounts a ON a.account_id = ca.account_id
+        INNER JOIN banks b ON b.bank_id = a.bank_id
+        WHERE ca.id_commerce = c.id_commerce AND b.status_id <> 1)";
 
 #[async_trait]
 pub trait BankRepository {
-    async fn find_bank_by_bank_code(&self, bank_code: &String)
+    async fn find_bank_by_bank_code(&self, bank_code: &str)
         -> Result<Option<BankEntity>, Error>;
-
+    async fn find_banks(&self) -> Result<Vec<BankEntity>, Error>;
+    async fn insert_bank(&self, bank_entity: &BankEntity) -> Result<Option<BankEntity>, Error>;
+    async fn update_bank(&self, bank_entity: &BankEntity) -> Result<Option<BankEntity>, Error>;
+    // commerce_status_change holds the commerce status ids moved from and to along with the bank
+    async fn update_bank_status<'a>(&self, bank_code: &'a str, current_status_id: &'a i64,
+                                    new_status_id: &'a i64,
+                                    commerce_status_change: &'a Option<(i64, i64)>,
+                                    reason: &'a str, changed_by: &'a str)
+        -> Result<Option<BankEntity>, Error>;
+    async fn insert_bank_certificate<'a>(&self, bank_code: &'a str,
+                                         certificate_pem: &'a str,
+                                         certificate_entity: &'a BankCertificateEntity,
+                                         retire_previous_at: &'a NaiveDateTime)
+        -> Result<Option<BankCertificateEntity>, Error>;
+    async fn find_valid_bank_certificates(&self, bank_code: &str, at: &NaiveDateTime)
+        -> Result<Vec<BankCertificateEntity>, Error>;
+    async fn insert_bank_status_schedule(&self, schedule_entity: &BankStatusScheduleEntity)
+        -> Result<Option<BankStatusScheduleEntity>, Error>;
+    async fn find_due_bank_status_schedules(&self, at: &NaiveDateTime)
+        -> Result<Vec<BankStatusScheduleEntity>, Error>;
+    async fn update_bank_status_schedule_processed(&self, schedule_id: &i64, applied: &bool,
+                                                   processed_at: &NaiveDateTime)
+        -> Result<(), Error>;
 }
 
 pub struct SqlxBankRepository {
     pool: Arc<MySqlPool>,
+    sqlx_commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>
 }
 
 impl SqlxBankRepository {
-    pub fn new(pool: Arc<MySqlPool>) -> Self {
-        Self { pool }
+    pub fn new(pool: Arc<MySqlPool>,
+               sqlx_commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>)
+        -> Self {
+        Self { pool, sqlx_commerce_status_history_repository }
+    }
+
+    // Aliases are only unique among active commerces, another commerce may have taken the alias
+    // while this one was suspended
+    async fn alias_is_held_by_active_commerce(commerce_id: &i64, alias: &str,
+                                              tx: &mut Transaction<'_, MySql>)
+        -> Result<bool, Error> {
+        let active_commerces = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM commerces
+                WHERE alias = ? AND commerce_status_id = ? AND id_commerce <> ?"
+        )
+            .bind(alias)
+            .bind(CommerceStatus::Active.id())
+            .bind(commerce_id)
+            .fetch_one(&mut **tx)
+            .await?;
+        Ok(active_commerces > 0)
+    }
+
+    async fn commit_bank_status(mut tx: Transaction<'_, MySql>, bank_code: &str)
+        -> Result<Option<BankEntity>, Error> {
+        let bank_entity_stored = sqlx::query_as::<_, BankEntity>(
+            "SELECT * FROM banks WHERE bank_code = ?"
+        )
+            .bind(bank_code)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(bank_entity_stored)
     }
 }
 
 #[async_trait]
 impl BankRepository for SqlxBankRepository {
-    async fn find_bank_by_bank_code(&self, bank_code: &String) -> Result<Option<BankEntity>, Error> {
-        sqlx::query_as::<_, BankEntity>(
-            "SELECT * FROM banks WHERE bank_code = ?
-                AND status_id = 1"
-        )
+    async fn find_bank_by_bank_code(&self, bank_code: &str) -> Result<Option<BankEntity>, Error> {
+        sqlx::query_as::<_, BankEntity>("SELECT * FROM banks WHERE bank_code = ?")
             .bind(bank_code)
             .fetch_optional(&*self.pool)
             .await
     }
+
+    async fn find_banks(&self) -> Result<Vec<BankEntity>, Error> {
+        sqlx::query_as::<_, BankEntity>("SELECT * FROM banks ORDER BY bank_id ASC")
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn insert_bank(&self, bank_entity: &BankEntity) -> Result<Option<BankEntity>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        info!("Inserting bank_entity: {:?}", bank_entity);
+        let insert_result = sqlx::query(
+            "INSERT INTO banks (bank_name, bank_code, contact_name, contact_mail, notification_mail,
+              contact_phone, bank_ruc, status_id)
+     VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+            .bind(&bank_entity.bank_name)
+            .bind(&bank_entity.bank_code)
+            .bind(&bank_entity.contact_name)
+            .bind(&bank_entity.contact_mail)
+            .bind(&bank_entity.notification_mail)
+            .bind(&bank_entity.contact_phone)
+            .bind(&bank_entity.bank_ruc)
+            .bind(bank_entity.status_id)
+            .execute(&mut *tx)
+            .await?;
+        info!("Insert completed, rows affected: {}", insert_result.rows_affected());
+
+        let bank_entity_stored = sqlx::query_as::<_, BankEntity>(
+            "SELECT * FROM banks WHERE bank_id = LAST_INSERT_ID()"
+        )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(bank_entity_stored)
+    }
+
+    async fn update_bank(&self, bank_entity: &BankEntity) -> Result<Option<BankEntity>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        info!("Updating bank_entity: {:?}", bank_entity);
+        let update_result = sqlx::query(
+            "UPDATE banks SET bank_name = ?, contact_name = ?, contact_mail = ?,
+                notification_mail = ?, contact_phone = ?, bank_ruc = ?
+                WHERE bank_code = ?"
+        )
+            .bind(&bank_entity.bank_name)
+            .bind(&bank_entity.contact_name)
+            .bind(&bank_entity.contact_mail)
+            .bind(&bank_entity.notification_mail)
+            .bind(&bank_entity.contact_phone)
+            .bind(&bank_entity.bank_ruc)
+            .bind(&bank_entity.bank_code)
+            .execute(&mut *tx)
+            .await?;
+        info!("Update completed, rows affected: {}", update_result.rows_affected());
+
+        let bank_entity_stored = sqlx::query_as::<_, BankEntity>(
+            "SELECT * FROM banks WHERE bank_code = ?"
+        )
+            .bind(&bank_entity.bank_code)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(bank_entity_stored)
+    }
+
+    async fn update_bank_status<'a>(&self, bank_code: &'a str, current_status_id: &'a i64,
+                                    new_status_id: &'a i64,
+                                    commerce_status_change: &'a Option<(i64, i64)>,
+                                    reason: &'a str, changed_by: &'a str)
+        -> Result<Option<BankEntity>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // The current status is part of the filter so a concurrent change is not overwritten
+        let update_result = sqlx::query(
+            "UPDATE banks SET status_id = ? WHERE bank_code = ? AND status_id = ?"
+        )
+            .bind(new_status_id)
+            .bind(bank_code)
+            .bind(current_status_id)
+            .execute(&mut *tx)
+            .await?;
+        info!("Bank status update completed, rows affected: {}", update_result.rows_affected());
+
+        if update_result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        let Some((commerce_status_from_id, commerce_status_to_id)) = commerce_status_change else {
+            return Self::commit_bank_status(tx, bank_code).await;
+        };
+
+        // Only commerces in the expected status follow the bank, the ones deactivated for other
+        // reasons are left as they are. Every account linked to the commerce counts, not only the
+        // primary one
+        let restores_commerces = *commerce_status_to_id == CommerceStatus::Active.id();
+        let query = format!(
+            "SELECT c.id_commerce, c.alias FROM commerces c
+                WHERE c.commerce_status_id = ? AND EXISTS (SELECT 1 FROM commerce_accounts ca
+                    INNER JOIN accounts a ON a.account_id = ca.account_id
+                    INNER JOIN banks b ON b.bank_id = a.bank_id
+                    WHERE ca.id_commerce = c.id_commerce AND b.bank_code = ?){} FOR UPDATE",
+            if restores_commerces { COMMERCE_BANKS_ENABLED_FILTER } else { "" });
+        let commerces = sqlx::query_as::<_, (i64, String)>(&query)
+            .bind(commerce_status_from_id)
+            .bind(bank_code)
+            .fetch_all(&mut *tx)
+            .await?;
+        let mut changed_commerces = 0;
+        for (commerce_id, alias) in &commerces {
+            let alias_is_taken = restores_commerces
+                && Self::alias_is_held_by_active_commerce(commerce_id, alias, &mut tx).await?;
+            if alias_is_taken {
+                warn!("Commerce {} stays suspended, alias '{}' is used by another active commerce",
+                    commerce_id, alias);
+                continue;
+            }
+            sqlx::query(
+                "UPDATE commerces SET commerce_status_id = ?, status_reason = ?
+                    WHERE id_commerce = ?"
+            )
+                .bind(commerce_status_to_id)
+                .bind(reason)
+                .bind(commerce_id)
+                .execute(&mut *tx)
+                .await?;
+            self.sqlx_commerce_status_history_repository
+                .insert_commerce_status_history_tx(&CommerceStatusHistoryEntity {
+                    history_id: 0,
+                    id_commerce: *commerce_id,
+                    previous_commerce_status_id: *commerce_status_from_id,
+                    new_commerce_status_id: *commerce_status_to_id,
+                    reason: reason.to_string(),
+                    changed_by: changed_by.to_string(),
+                    changed_at: Utc::now().naive_utc(),
+                }, &mut tx)
+                .await?;
+            changed_commerces += 1;
+        }
+        info!("Status of {} commerces changed along with bank {}", changed_commerces, bank_code);
+
+        Self::commit_bank_status(tx, bank_code).await
+    }
+
+    async fn insert_bank_certificate<'a>(&self, bank_code: &'a str,
+                                         certificate_pem: &'a str,
+                                         certificate_entity: &'a BankCertificateEntity,
+                                         retire_previous_at: &'a NaiveDateTime)
+        -> Result<Option<BankCertificateEntity>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let bank_id = sqlx::query_scalar::<_, i64>(
+            "SELECT bank_id FROM banks WHERE bank_code = ? FOR UPDATE"
+        )
+            .bind(bank_code)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(bank_id) = bank_id else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        // Certificates already scheduled to retire keep their own date
+        let retire_result = sqlx::query(
+            "UPDATE bank_certificates SET retires_at = ?
+                WHERE bank_id = ? AND retires_at IS NULL"
+        )
+            .bind(retire_previous_at)
+            .bind(bank_id)
+            .execute(&mut *tx)
+            .await?;
+        info!("Certificates of bank {} set to retire at {}, rows affected: {}", bank_code,
+            retire_previous_at, retire_result.rows_affected());
+
+        info!("Inserting certificate {} for bank {}", certificate_entity.fingerprint, bank_code);
+        sqlx::query(
+            "INSERT INTO bank_certificates (bank_id, certificate_pem, subject, fingerprint,
+              not_before, not_after, uploaded_at, retires_at)
+     VALUES (?, ?, ?, ?, ?, ?, ?, NULL)"
+        )
+            .bind(bank_id)
+            .bind(certificate_pem)
+            .bind(&certificate_entity.subject)
+            .bind(&certificate_entity.fingerprint)
+            .bind(certificate_entity.not_before)
+            .bind(certificate_entity.not_after)
+            .bind(certificate_entity.uploaded_at)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE banks SET ocp_cert_mtls = ? WHERE bank_id = ?")
+            .bind(certificate_pem)
+            .bind(bank_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let certificate_entity_stored = sqlx::query_as::<_, BankCertificateEntity>(
+            "SELECT bc.certificate_id, b.bank_code, bc.subject, bc.fingerprint, bc.not_before,
+                bc.not_after, bc.uploaded_at, bc.retires_at
+                FROM bank_certificates bc
+                INNER JOIN banks b ON b.bank_id = bc.bank_id
+                WHERE bc.certificate_id = LAST_INSERT_ID()"
+        )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(certificate_entity_stored)
+    }
+
+    async fn find_valid_bank_certificates(&self, bank_code: &str, at: &NaiveDateTime)
+        -> Result<Vec<BankCertificateEntity>, Error> {
+        sqlx::query_as::<_, BankCertificateEntity>(
+            "SELECT bc.certificate_id, b.bank_code, bc.subject, bc.fingerprint, bc.not_before,
+                bc.not_after, bc.uploaded_at, bc.retires_at
+                FROM bank_certificates bc
+                INNER JOIN banks b ON b.bank_id = bc.bank_id
+                WHERE b.bank_code = ? AND bc.not_after > ?
+                AND (bc.retires_at IS NULL OR bc.retires_at > ?)
+                ORDER BY bc.uploaded_at DESC"
+        )
+            .bind(bank_code)
+            .bind(at)
+            .bind(at)
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn insert_bank_status_schedule(&self, schedule_entity: &BankStatusScheduleEntity)
+        -> Result<Option<BankStatusScheduleEntity>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let bank_id = sqlx::query_scalar::<_, i64>("SELECT bank_id FROM banks WHERE bank_code = ?")
+            .bind(&schedule_entity.bank_code)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(bank_id) = bank_id else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        info!("Inserting status schedule for bank {}", schedule_entity.bank_code);
+        sqlx::query(
+            "INSERT INTO bank_status_schedules (bank_id, status_id, effective_at, scheduled_by,
+              scheduled_at)
+     VALUES (?, ?, ?, ?, ?)"
+        )
+            .bind(bank_id)
+            .bind(schedule_entity.status_id)
+            .bind(schedule_entity.effective_at)
+            .bind(&schedule_entity.scheduled_by)
+            .bind(Utc::now().naive_utc())
+            .execute(&mut *tx)
+            .await?;
+
+        let schedule_entity_stored = sqlx::query_as::<_, BankStatusScheduleEntity>(
+            "SELECT s.schedule_id, b.bank_code, s.status_id, s.effective_at, s.scheduled_by
+                FROM bank_status_schedules s
+                INNER JOIN banks b ON b.bank_id = s.bank_id
+                WHERE s.schedule_id = LAST_INSERT_ID()"
+        )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(schedule_entity_stored)
+    }
+
+    async fn find_due_bank_status_schedules(&self, at: &NaiveDateTime)
+        -> Result<Vec<BankStatusScheduleEntity>, Error> {
+        sqlx::query_as::<_, BankStatusScheduleEntity>(
+            "SELECT s.schedule_id, b.bank_code, s.status_id, s.effective_at, s.scheduled_by
+                FROM bank_status_schedules s
+                INNER JOIN banks b ON b.bank_id = s.bank_id
+                WHERE s.processed_at IS NULL AND s.effective_at <= ?
+                ORDER BY s.effective_at, s.schedule_id"
+        )
+            .bind(at)
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn update_bank_status_schedule_processed(&self, schedule_id: &i64, applied: &bool,
+                                                   processed_at: &NaiveDateTime)
+        -> Result<(), Error> {
+        let update_result = sqlx::query(
+            "UPDATE bank_status_schedules SET processed_at = ?, applied = ?
+                WHERE schedule_id = ? AND processed_at IS NULL"
+        )
+            .bind(processed_at)
+            .bind(applied)
+            .bind(schedule_id)
+            .execute(&*self.pool)
+            .await?;
+        info!("Bank status schedule {} processed, rows affected: {}", schedule_id,
+            update_result.rows_affected());
+        Ok(())
+    }
 }
\ No newline at end of file
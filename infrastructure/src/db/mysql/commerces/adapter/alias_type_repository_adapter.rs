@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::error;
+use application::port::db::commerces::alias_type_repository_port::AliasTypeRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::alias_type::AliasType;
+use domain::model::party_kind::PartyKind;
+use crate::db::mysql::commerces::entity::alias_type_entity::AliasTypeEntity;
+use crate::db::mysql::commerces::repository::alias_type_repository::{AliasTypeRepository, SqlxAliasTypeRepository};
+
+pub struct AliasTypeRepositoryAdapter {
+    alias_type_repository: Arc<SqlxAliasTypeRepository>
+}
+
+impl AliasTypeRepositoryAdapter {
+    pub fn new(alias_type_repository: Arc<SqlxAliasTypeRepository>) -> Self {
+        Self { alias_type_repository }
+    }
+}
+
+#[async_trait]
+impl AliasTypeRepositoryPort for AliasTypeRepositoryAdapter {
+    async fn find_alias_types(&self) -> Result<Vec<AliasType>, DatabaseError> {
+        match self.alias_type_repository.find_alias_types().await {
+            Ok(alias_type_entities) => Ok(alias_type_entities.iter()
+                .map(map_alias_type_entity_to_model)
+                .collect()),
+            Err(err) => {
+                error!("Unexpected error from database. Error is: {:?}", err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+}
+
+fn map_alias_type_entity_to_model(alias_type_entity: &AliasTypeEntity) -> AliasType {
+    let mut party_kinds = Vec::new();
+    if alias_type_entity.allowed_for_commerce {
+        party_kinds.push(PartyKind::Commerce);
+    }
+    if alias_type_entity.allowed_for_user {
+        party_kinds.push(PartyKind::User);
+    }
+    AliasType::new(
+        alias_type_entity.alias_type_id,
+        alias_type_entity.description.clone(),
+        alias_type_entity.pattern.clone(),
+        alias_type_entity.min_length.max(0) as usize,
+        alias_type_entity.max_length.max(0) as usize,
+        party_kinds
+    )
+}
\ No newline at end of file
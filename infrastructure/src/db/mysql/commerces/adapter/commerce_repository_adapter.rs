@@ -6,33 +6,95 @@ use application::port::db::commerces::commerce_repository_port::CommerceReposito
 use domain::exception::database_error::DatabaseError;
 use domain::model::account::Account;
 use domain::model::commerce::Commerce;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_page::CommercePage;
 use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_status_history::CommerceStatusHistory;
 use crate::db::mysql::commerces::entity::commerce_entity::CommerceEntity;
+use crate::db::mysql::commerces::entity::wrappers::commerce_account_db_info_wrapper::CommerceAccountDbInfoWrapper;
+use crate::db::mysql::commerces::entity::commerce_status_history_entity::CommerceStatusHistoryEntity;
+use crate::db::mysql::commerces::entity::legal_entity_entity::LegalEntityEntity;
 use crate::db::mysql::commerces::entity::wrappers::commerce_db_info_wrapper::CommerceDbInfoWrapper;
+use crate::db::mysql::commerces::repository::account_repository::{AccountRepository, SqlxAccountRepository};
 use crate::db::mysql::commerces::repository::commerce_repository::{CommerceRepository, SqlxCommerceRepository};
+use crate::db::mysql::commerces::repository::commerce_status_history_repository::{CommerceStatusHistoryRepository, SqlxCommerceStatusHistoryRepository};
+use crate::db::mysql::commerces::repository::legal_entity_repository::{LegalEntityRepository, SqlxLegalEntityRepository};
 
 pub struct CommerceRepositoryAdapter {
-    commerce_repository: Arc<SqlxCommerceRepository>
+    commerce_repository: Arc<SqlxCommerceRepository>,
+    account_repository: Arc<SqlxAccountRepository>,
+    commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>,
+    legal_entity_repository: Arc<SqlxLegalEntityRepository>
 }
 
 impl CommerceRepositoryAdapter {
-    pub fn new(commerce_repository: Arc<SqlxCommerceRepository>) -> Self {
-        Self { commerce_repository }
+    pub fn new(commerce_repository: Arc<SqlxCommerceRepository>,
+               account_repository: Arc<SqlxAccountRepository>,
+               commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>,
+               legal_entity_repository: Arc<SqlxLegalEntityRepository>) -> Self {
+        Self {
+            commerce_repository,
+            account_repository,
+            commerce_status_history_repository,
+            legal_entity_repository
+        }
     }
 
     fn log_error_info(err: &Error) {
         error!("Unexpected error from database. Error is: {:?}", err);
     }
+
+    async fn map_commerce_db_info_wrapper_with_accounts(&self,
+                                                        commerce_db_info_wrapper: &CommerceDbInfoWrapper)
+        -> Result<Commerce, DatabaseError> {
+        let mut commerces = self.map_commerce_db_info_wrappers_with_accounts(
+            std::slice::from_ref(commerce_db_info_wrapper)).await?;
+        Ok(commerces.remove(0))
+    }
+
+    // Every settlement account is loaded with a single query for all the commerces
+    async fn map_commerce_db_info_wrappers_with_accounts(&self,
+                                                         commerce_db_info_wrappers: &[CommerceDbInfoWrapper])
+        -> Result<Vec<Commerce>, DatabaseError> {
+        let commerce_ids: Vec<i64> = commerce_db_info_wrappers
+            .iter()
+            .map(|commerce_db_info_wrapper| commerce_db_info_wrapper.id_commerce)
+            .collect();
+        let commerce_accounts = self.account_repository
+            .find_accounts_by_commerce_ids(&commerce_ids).await
+            .map_err(|err| {
+                Self::log_error_info(&err);
+                DatabaseError::Unexpected(err.into())
+            })?;
+
+        Ok(commerce_db_info_wrappers
+            .iter()
+            .map(|commerce_db_info_wrapper| {
+                let mut commerce = map_commerce_db_info_wrapper_entity_to_model(commerce_db_info_wrapper);
+                let accounts: Vec<Account> = commerce_accounts
+                    .iter()
+                    .filter(|commerce_account| commerce_account.id_commerce == commerce.commerce_id)
+                    .map(map_commerce_account_db_info_wrapper_to_model)
+                    .collect();
+                if !accounts.is_empty() {
+                    commerce.accounts = accounts;
+                }
+                commerce
+            })
+            .collect())
+    }
 }
 
 #[async_trait]
 impl CommerceRepositoryPort for CommerceRepositoryAdapter {
     async fn create_commerce(&self, commerce: &Commerce) -> Result<Commerce, DatabaseError> {
         let commerce_entity = map_commerce_to_entity(commerce);
+        let legal_entity_entity = map_commerce_to_legal_entity_entity(commerce);
 
-        match self.commerce_repository.create_commerce(&commerce_entity, &commerce.account.bank_code,
-        &commerce.account.account_number).await {
-            Ok(Some(commerce_db_info_wrapper)) => Ok(map_commerce_db_info_wrapper_entity_to_model(&commerce_db_info_wrapper)),
+        match self.commerce_repository.create_commerce(&commerce_entity, &legal_entity_entity,
+        &commerce.account.bank_id, &commerce.account.bank_code, &commerce.account.account_number).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await,
             Ok(None) => {
                 error!("Unable to return commerce created.");
                 Err(DatabaseError::Unexpected(Box::from("Unable to return commerce created")))
@@ -44,10 +106,9 @@ impl CommerceRepositoryPort for CommerceRepositoryAdapter {
         }
     }
 
-    async fn commerce_does_not_exist_by_ruc_and_alias(&self, ruc: &String, 
-                                                      alias: &String) 
+    async fn commerce_does_not_exist_by_alias(&self, alias: &str)
         -> Result<bool, DatabaseError> {
-        match self.commerce_repository.find_commerce_by_ruc_or_alias(ruc, alias).await {
+        match self.commerce_repository.find_active_commerce_by_alias(alias).await {
             Ok(Some(_)) => Ok(false),
             Ok(None) => Ok(true),
             Err(err) => {
@@ -57,13 +118,30 @@ impl CommerceRepositoryPort for CommerceRepositoryAdapter {
         }
     }
 
-    async fn commerce_exists_by_ruc_or_legal_business_name(&self, ruc: &String, 
-                                                           legal_business_name: &String) 
+    async fn commerce_exists_by_ruc_or_legal_business_name(&self, ruc: &str, 
+                                                           legal_business_name: &str) 
         -> Result<bool, DatabaseError> {
-        match self.commerce_repository
-            .find_commerce_by_ruc_or_legal_business_name(ruc, legal_business_name).await {
-            Ok(Some(commerce_entity)) => {Ok(*commerce_entity.legal_business_name == *legal_business_name
-                && *commerce_entity.ruc == *ruc)},
+        match self.legal_entity_repository
+            .find_legal_entity_by_ruc_or_legal_business_name(ruc, legal_business_name).await {
+            Ok(Some(legal_entity_entity)) => {Ok(*legal_entity_entity.legal_business_name == *legal_business_name
+                && *legal_entity_entity.ruc == *ruc)},
+            Ok(None) => Ok(true),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn commerce_exists_by_ruc_or_legal_business_name_excluding(&self, commerce_id: &i64,
+                                                                     ruc: &str,
+                                                                     legal_business_name: &str)
+        -> Result<bool, DatabaseError> {
+        match self.legal_entity_repository
+            .find_legal_entity_by_ruc_or_legal_business_name_excluding(commerce_id, ruc,
+                                                                       legal_business_name).await {
+            Ok(Some(legal_entity_entity)) => {Ok(*legal_entity_entity.legal_business_name == *legal_business_name
+                && *legal_entity_entity.ruc == *ruc)},
             Ok(None) => Ok(true),
             Err(err) => {
                 Self::log_error_info(&err);
@@ -71,6 +149,197 @@ impl CommerceRepositoryPort for CommerceRepositoryAdapter {
             }
         }
     }
+
+    async fn find_commerce_by_id(&self, commerce_id: &i64) -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository.find_commerce_by_id(commerce_id).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_commerce_by_alias(&self, alias: &str) -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository.find_commerce_by_alias(alias).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_active_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository.find_active_commerce_by_alias(alias).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_commerces(&self, filter: &CommerceFilter, cursor: &Option<i64>, limit: &i64)
+        -> Result<CommercePage, DatabaseError> {
+        // One extra row is fetched to know whether there is a next page
+        let commerce_db_info_wrappers = self.commerce_repository
+            .find_commerces(filter, cursor, &(limit + 1)).await
+            .map_err(|err| {
+                Self::log_error_info(&err);
+                DatabaseError::Unexpected(err.into())
+            })?;
+        let total_count = self.commerce_repository.count_commerces(filter).await
+            .map_err(|err| {
+                Self::log_error_info(&err);
+                DatabaseError::Unexpected(err.into())
+            })?;
+
+        let has_next_page = commerce_db_info_wrappers.len() as i64 > *limit;
+        let commerces = self.map_commerce_db_info_wrappers_with_accounts(
+            &commerce_db_info_wrappers[..commerce_db_info_wrappers.len().min(*limit as usize)]).await?;
+        let next_cursor = if has_next_page {
+            commerces.last().map(|commerce| commerce.commerce_id)
+        } else {
+            None
+        };
+        Ok(CommercePage::new(commerces, next_cursor, total_count))
+    }
+
+    async fn find_commerce_branches(&self, parent_commerce_id: &i64)
+        -> Result<Vec<Commerce>, DatabaseError> {
+        let commerce_db_info_wrappers = self.commerce_repository
+            .find_commerce_branches(parent_commerce_id).await
+            .map_err(|err| {
+                Self::log_error_info(&err);
+                DatabaseError::Unexpected(err.into())
+            })?;
+        self.map_commerce_db_info_wrappers_with_accounts(&commerce_db_info_wrappers).await
+    }
+
+    async fn change_commerce_status(&self, commerce_id: &i64, current_status: &CommerceStatus,
+                                    new_status: &CommerceStatus, reason: &str,
+                                    changed_by: &str, cascade_to_branches: &bool)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository
+            .update_commerce_status(commerce_id, &current_status.id(), &new_status.id(), reason,
+                                    changed_by, cascade_to_branches)
+            .await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn change_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository
+            .update_commerce_account(commerce_id, &account.bank_id, &account.bank_code,
+                                     &account.account_number)
+            .await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn add_commerce_account(&self, commerce_id: &i64, account: &Account)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository
+            .insert_commerce_account(commerce_id, &account.bank_id, &account.bank_code,
+                                     &account.account_number)
+            .await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn remove_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository.delete_commerce_account(commerce_id, account_id).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn promote_commerce_account(&self, commerce_id: &i64, account_id: &i64)
+        -> Result<Option<Commerce>, DatabaseError> {
+        match self.commerce_repository.update_commerce_primary_account(commerce_id, account_id).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_commerce_status_history(&self, commerce_id: &i64)
+        -> Result<Vec<CommerceStatusHistory>, DatabaseError> {
+        match self.commerce_status_history_repository
+            .find_commerce_status_history_by_commerce_id(commerce_id).await {
+            Ok(commerce_status_history_entities) => Ok(commerce_status_history_entities
+                .iter()
+                .map(map_commerce_status_history_entity_to_model)
+                .collect()),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn update_commerce(&self, commerce: &Commerce) -> Result<Commerce, DatabaseError> {
+        let commerce_entity = map_commerce_to_entity(commerce);
+        let legal_entity_entity = map_commerce_to_legal_entity_entity(commerce);
+
+        match self.commerce_repository.update_commerce(&commerce_entity, &legal_entity_entity).await {
+            Ok(Some(commerce_db_info_wrapper)) => self
+                .map_commerce_db_info_wrapper_with_accounts(&commerce_db_info_wrapper).await,
+            Ok(None) => {
+                error!("Unable to return commerce updated.");
+                Err(DatabaseError::Unexpected(Box::from("Unable to return commerce updated")))
+            },
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
 }
 
 fn map_commerce_to_entity(commerce: &Commerce) -> CommerceEntity {
@@ -78,10 +347,18 @@ fn map_commerce_to_entity(commerce: &Commerce) -> CommerceEntity {
         id_commerce: commerce.commerce_id,
         alias: commerce.alias.clone(),
         alias_type_id: commerce.alias_type,
-        legal_business_name: commerce.legal_business_name.clone(),
+        legal_entity_id: 0, // Resolved from the RUC when the commerce is stored
         account_id: commerce.account.account_id, // Assuming Account has account_id field
+        commerce_status_id: commerce.commerce_status.id(),
+        parent_commerce_id: commerce.parent_commerce_id,
+    }
+}
+
+fn map_commerce_to_legal_entity_entity(commerce: &Commerce) -> LegalEntityEntity {
+    LegalEntityEntity {
+        legal_entity_id: 0,
         ruc: commerce.ruc.clone(),
-        commerce_status_id: 1, // Assuming CommerceStatus has commerce_status_id field
+        legal_business_name: commerce.legal_business_name.clone(),
     }
 }
 
@@ -92,15 +369,49 @@ fn map_commerce_db_info_wrapper_entity_to_model(commerce_db_info_wrapper: &Comme
         alias: commerce_db_info_wrapper.alias.clone(),
         alias_type: commerce_db_info_wrapper.alias_type_id,
         legal_business_name: commerce_db_info_wrapper.legal_business_name.clone(),
-        account: Account {
-            account_id: commerce_db_info_wrapper.account_id,
-            account_number: commerce_db_info_wrapper.account_number.clone(),
-            bank_code: commerce_db_info_wrapper.bank_code.clone(),
-            bank_id: commerce_db_info_wrapper.bank_id
-        },
+        account: map_primary_account_to_model(commerce_db_info_wrapper),
+        accounts: vec![map_primary_account_to_model(commerce_db_info_wrapper)],
         ruc: commerce_db_info_wrapper.ruc.clone(),
-        commerce_status: CommerceStatus {
-            status_name: commerce_db_info_wrapper.commerce_status_name.clone()
-        }
+        commerce_status: map_commerce_status_id_to_model(commerce_db_info_wrapper.commerce_status_id),
+        parent_commerce_id: commerce_db_info_wrapper.parent_commerce_id
+    }
+}
+
+fn map_primary_account_to_model(commerce_db_info_wrapper: &CommerceDbInfoWrapper) -> Account {
+    Account {
+        account_id: commerce_db_info_wrapper.account_id,
+        account_number: commerce_db_info_wrapper.account_number.clone(),
+        bank_code: commerce_db_info_wrapper.bank_code.clone(),
+        bank_id: commerce_db_info_wrapper.bank_id
+    }
+}
+
+fn map_commerce_account_db_info_wrapper_to_model(commerce_account_db_info_wrapper: &CommerceAccountDbInfoWrapper)
+    -> Account {
+    Account {
+        account_id: commerce_account_db_info_wrapper.account_id,
+        account_number: commerce_account_db_info_wrapper.account_number.clone(),
+        bank_code: commerce_account_db_info_wrapper.bank_code.clone(),
+        bank_id: commerce_account_db_info_wrapper.bank_id
     }
+}
+
+fn map_commerce_status_history_entity_to_model(commerce_status_history_entity: &CommerceStatusHistoryEntity)
+    -> CommerceStatusHistory {
+    CommerceStatusHistory {
+        history_id: commerce_status_history_entity.history_id,
+        commerce_id: commerce_status_history_entity.id_commerce,
+        previous_status: map_commerce_status_id_to_model(commerce_status_history_entity.previous_commerce_status_id),
+        new_status: map_commerce_status_id_to_model(commerce_status_history_entity.new_commerce_status_id),
+        reason: commerce_status_history_entity.reason.clone(),
+        changed_by: commerce_status_history_entity.changed_by.clone(),
+        changed_at: commerce_status_history_entity.changed_at.and_utc()
+    }
+}
+
+fn map_commerce_status_id_to_model(commerce_status_id: i64) -> CommerceStatus {
+    CommerceStatus::from_id(commerce_status_id).unwrap_or_else(|| {
+        error!("Unknown commerce status id {}, treating it as inactive", commerce_status_id);
+        CommerceStatus::Inactive
+    })
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -11,4 +12,6 @@ pub struct AccountEntity {
     pub bank_code: String,
     #[sqlx(rename = "bank_id")]
     pub bank_id: i64,
+    #[sqlx(rename = "superseded_at")]
+    pub superseded_at: Option<NaiveDateTime>,
 }
\ No newline at end of file
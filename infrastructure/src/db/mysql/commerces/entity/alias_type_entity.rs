@@ -7,4 +7,14 @@ pub struct AliasTypeEntity {
     pub alias_type_id: i64,
     #[sqlx(rename = "description")]
     pub description: String,
+    #[sqlx(rename = "pattern")]
+    pub pattern: String,
+    #[sqlx(rename = "min_length")]
+    pub min_length: i32,
+    #[sqlx(rename = "max_length")]
+    pub max_length: i32,
+    #[sqlx(rename = "allowed_for_commerce")]
+    pub allowed_for_commerce: bool,
+    #[sqlx(rename = "allowed_for_user")]
+    pub allowed_for_user: bool,
 }
\ No newline at end of file
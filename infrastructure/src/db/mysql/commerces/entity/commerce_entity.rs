@@ -9,12 +9,12 @@ pub struct CommerceEntity {
     pub alias: String,
     #[sqlx(rename = "alias_type_id")]
     pub alias_type_id: i64,
-    #[sqlx(rename = "legal_business_name")]
-    pub legal_business_name: String,
     #[sqlx(rename = "account_id")]
     pub account_id: i64,
-    #[sqlx(rename = "ruc")]
-    pub ruc: String,
+    #[sqlx(rename = "legal_entity_id")]
+    pub legal_entity_id: i64,
     #[sqlx(rename = "commerce_status_id")]
     pub commerce_status_id: i64,
+    #[sqlx(rename = "parent_commerce_id")]
+    pub parent_commerce_id: Option<i64>,
 }
\ No newline at end of file
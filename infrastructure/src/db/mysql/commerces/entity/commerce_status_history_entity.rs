@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct CommerceStatusHistoryEntity {
+    #[sqlx(rename = "history_id")]
+    pub history_id: i64,
+    #[sqlx(rename = "id_commerce")]
+    pub id_commerce: i64,
+    #[sqlx(rename = "previous_commerce_status_id")]
+    pub previous_commerce_status_id: i64,
+    #[sqlx(rename = "new_commerce_status_id")]
+    pub new_commerce_status_id: i64,
+    #[sqlx(rename = "reason")]
+    pub reason: String,
+    #[sqlx(rename = "changed_by")]
+    pub changed_by: String,
+    #[sqlx(rename = "changed_at")]
+    pub changed_at: NaiveDateTime,
+}
\ No newline at end of file
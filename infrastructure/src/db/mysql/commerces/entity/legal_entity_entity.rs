@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct LegalEntityEntity {
+    #[sqlx(rename = "legal_entity_id")]
+    pub legal_entity_id: i64,
+    #[sqlx(rename = "ruc")]
+    pub ruc: String,
+    #[sqlx(rename = "legal_business_name")]
+    pub legal_business_name: String,
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct CommerceAccountDbInfoWrapper {
+    pub id_commerce: i64,
+    pub account_id: i64,
+    pub account_number: String,
+    pub bank_code: String,
+    pub bank_id: i64
+}
\ No newline at end of file
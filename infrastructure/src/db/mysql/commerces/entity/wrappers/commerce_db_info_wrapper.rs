@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct CommerceDbInfoWrapper {
     pub id_commerce: i64,
     pub alias: String,
@@ -12,10 +13,12 @@ pub struct CommerceDbInfoWrapper {
     pub bank_id: i64,
     pub ruc: String,
     pub commerce_status_id: i64,
-    pub commerce_status_name: String
+    pub commerce_status_name: String,
+    pub parent_commerce_id: Option<i64>
 }
 
 impl CommerceDbInfoWrapper {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(id_commerce: i64,
                alias: String, alias_type_id: i64,
                legal_business_name: String,
@@ -25,7 +28,8 @@ impl CommerceDbInfoWrapper {
                bank_id: i64,
                ruc: String,
                commerce_status_id: i64,
-               commerce_status_name: String) -> Self {
+               commerce_status_name: String,
+               parent_commerce_id: Option<i64>) -> Self {
         Self { id_commerce,
             alias,
             alias_type_id,
@@ -36,7 +40,8 @@ impl CommerceDbInfoWrapper {
             bank_id,
             ruc,
             commerce_status_id,
-            commerce_status_name
+            commerce_status_name,
+            parent_commerce_id
         }
     }
 }
@@ -1,14 +1,39 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sqlx::{Error, MySqlPool};
+use chrono::Utc;
+use sqlx::{Error, MySql, MySqlPool, QueryBuilder, Transaction};
 use crate::db::mysql::commerces::entity::account_entity::AccountEntity;
+use crate::db::mysql::commerces::entity::wrappers::commerce_account_db_info_wrapper::CommerceAccountDbInfoWrapper;
 
 #[async_trait]
 pub trait AccountRepository {
-    async fn insert_new_account<'a>(&self, account_number: &'a String,
-                                bank_code: &'a String,
+    async fn insert_new_account<'a>(&self, account_number: &'a str,
+                                bank_code: &'a str,
                                 bank_id: &'a i64)
                                 -> Result<Option<AccountEntity>, Error>;
+
+    async fn insert_new_account_tx<'a>(&self, account_number: &str,
+                                       bank_code: &str,
+                                       bank_id: &i64,
+                                       tx: &mut Transaction<'a, MySql>)
+                                       -> Result<Option<AccountEntity>, Error>;
+
+    // Superseded accounts are kept for traceability but no longer receive settlements
+    async fn supersede_account_tx<'a>(&self, account_id: &i64,
+                                      tx: &mut Transaction<'a, MySql>)
+                                      -> Result<(), Error>;
+
+    async fn find_accounts_by_commerce_ids(&self, commerce_ids: &[i64])
+                                           -> Result<Vec<CommerceAccountDbInfoWrapper>, Error>;
+
+    async fn link_account_to_commerce_tx<'a>(&self, commerce_id: &i64, account_id: &i64,
+                                             tx: &mut Transaction<'a, MySql>)
+                                             -> Result<(), Error>;
+
+    // Returns whether the account was linked to the commerce
+    async fn unlink_account_from_commerce_tx<'a>(&self, commerce_id: &i64, account_id: &i64,
+                                                 tx: &mut Transaction<'a, MySql>)
+                                                 -> Result<bool, Error>;
 }
 
 pub struct SqlxAccountRepository {
@@ -23,8 +48,8 @@ impl SqlxAccountRepository {
 
 #[async_trait]
 impl AccountRepository for SqlxAccountRepository {
-    async fn insert_new_account<'a>(&self, account_number: &'a String,
-                                bank_code: &'a String,
+    async fn insert_new_account<'a>(&self, account_number: &'a str,
+                                bank_code: &'a str,
                                 bank_id: &'a i64)
                                 -> Result<Option<AccountEntity>, Error> {
         let mut tx = self.pool.begin().await?;
@@ -49,4 +74,86 @@ impl AccountRepository for SqlxAccountRepository {
 
         Ok(result)
     }
+
+    async fn insert_new_account_tx<'a>(&self, account_number: &str,
+                                       bank_code: &str,
+                                       bank_id: &i64,
+                                       tx: &mut Transaction<'a, MySql>)
+                                       -> Result<Option<AccountEntity>, Error> {
+        sqlx::query(
+            "INSERT INTO accounts (account_number, bank_code, bank_id)
+                VALUES (?, ?, ?)"
+        )
+            .bind(account_number)
+            .bind(bank_code)
+            .bind(bank_id)
+            .execute(&mut **tx)
+            .await?;
+
+        sqlx::query_as::<_, AccountEntity>(
+            "SELECT * FROM accounts WHERE account_id = LAST_INSERT_ID()"
+        )
+            .fetch_optional(&mut **tx)
+            .await
+    }
+
+    async fn supersede_account_tx<'a>(&self, account_id: &i64,
+                                      tx: &mut Transaction<'a, MySql>)
+                                      -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE accounts SET superseded_at = ? WHERE account_id = ? AND superseded_at IS NULL"
+        )
+            .bind(Utc::now().naive_utc())
+            .bind(account_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_accounts_by_commerce_ids(&self, commerce_ids: &[i64])
+                                           -> Result<Vec<CommerceAccountDbInfoWrapper>, Error> {
+        if commerce_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut query_builder = QueryBuilder::<MySql>::new(
+            "SELECT ca.id_commerce, a.account_id, a.account_number, a.bank_code, a.bank_id
+                FROM commerce_accounts ca
+                    INNER JOIN accounts a ON a.account_id = ca.account_id
+                WHERE a.superseded_at IS NULL AND ca.id_commerce IN ("
+        );
+        let mut separated = query_builder.separated(", ");
+        for commerce_id in commerce_ids {
+            separated.push_bind(*commerce_id);
+        }
+        query_builder.push(") ORDER BY ca.id_commerce ASC, a.account_id ASC");
+        query_builder.build_query_as::<CommerceAccountDbInfoWrapper>()
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn link_account_to_commerce_tx<'a>(&self, commerce_id: &i64, account_id: &i64,
+                                             tx: &mut Transaction<'a, MySql>)
+                                             -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO commerce_accounts (id_commerce, account_id) VALUES (?, ?)"
+        )
+            .bind(commerce_id)
+            .bind(account_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn unlink_account_from_commerce_tx<'a>(&self, commerce_id: &i64, account_id: &i64,
+                                                 tx: &mut Transaction<'a, MySql>)
+                                                 -> Result<bool, Error> {
+        let delete_result = sqlx::query(
+            "DELETE FROM commerce_accounts WHERE id_commerce = ? AND account_id = ?"
+        )
+            .bind(commerce_id)
+            .bind(account_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(delete_result.rows_affected() > 0)
+    }
 }
\ No newline at end of file
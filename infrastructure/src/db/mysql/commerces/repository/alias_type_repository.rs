@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{Error, MySqlPool};
+use crate::db::mysql::commerces::entity::alias_type_entity::AliasTypeEntity;
+
+#[async_trait]
+pub trait AliasTypeRepository {
+    async fn find_alias_types(&self) -> Result<Vec<AliasTypeEntity>, Error>;
+}
+
+pub struct SqlxAliasTypeRepository {
+    pool: Arc<MySqlPool>
+}
+
+impl SqlxAliasTypeRepository {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AliasTypeRepository for SqlxAliasTypeRepository {
+    async fn find_alias_types(&self) -> Result<Vec<AliasTypeEntity>, Error> {
+        sqlx::query_as::<_, AliasTypeEntity>(
+            "SELECT alias_type_id, description, pattern, min_length, max_length,
+                allowed_for_commerce, allowed_for_user
+            FROM alias_type"
+        )
+            .fetch_all(&*self.pool)
+            .await
+    }
+}
\ No newline at end of file
@@ -1,23 +1,64 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sqlx::{Error, MySqlPool};
+use chrono::Utc;
+use sqlx::{Error, MySql, MySqlPool, QueryBuilder, Transaction};
 use tracing::info;
-use crate::db::mysql::banks::repository::bank_repository::{BankRepository, SqlxBankRepository};
+use domain::model::commerce::Commerce;
+use domain::model::commerce_filter::CommerceFilter;
+use domain::model::commerce_status::CommerceStatus;
 use crate::db::mysql::commerces::entity::account_entity::AccountEntity;
 use crate::db::mysql::commerces::entity::commerce_entity::CommerceEntity;
+use crate::db::mysql::commerces::entity::commerce_status_history_entity::CommerceStatusHistoryEntity;
+use crate::db::mysql::commerces::entity::legal_entity_entity::LegalEntityEntity;
 use crate::db::mysql::commerces::entity::wrappers::commerce_db_info_wrapper::CommerceDbInfoWrapper;
 use crate::db::mysql::commerces::repository::account_repository::{AccountRepository, SqlxAccountRepository};
 use crate::db::mysql::commerces::repository::commerce_status_repository::{CommerceStatusRepository, SqlxCommerceStatusRepository};
+use crate::db::mysql::commerces::repository::commerce_status_history_repository::{CommerceStatusHistoryRepository, SqlxCommerceStatusHistoryRepository};
+use crate::db::mysql::commerces::repository::legal_entity_repository::{LegalEntityRepository, SqlxLegalEntityRepository};
+
+const COMMERCE_DB_INFO_SELECT: &str =
+    "SELECT c.id_commerce, c.alias, c.alias_type_id, le.legal_business_name, c.account_id,
+        a.account_number, a.bank_code, a.bank_id, le.ruc, c.commerce_status_id,
+        cs.status_name AS commerce_status_name, c.parent_commerce_id
+    FROM commerces c
+        INNER JOIN legal_entities le ON le.legal_entity_id = c.legal_entity_id
+        INNER JOIN accounts a ON a.account_id = c.account_id
+        INNER JOIN commerce_status cs ON cs.commerce_status_id = c.commerce_status_id";
 
 #[async_trait]
 pub trait CommerceRepository {
-    async fn find_commerce_by_ruc_or_alias<'a>(&self, ruc: &'a String, alias_value: &'a String)
-        -> Result<Option<CommerceEntity>, Error>;
-    async fn find_commerce_by_ruc_or_legal_business_name<'a>(&self, ruc: &'a String,
-                                                         legal_business_name: &'a String)
-        -> Result<Option<CommerceEntity>, Error>;
-    async fn create_commerce<'a>(&self, commerce_entity: &'a CommerceEntity, bank_code: &'a String,
-                             account_number: &'a String)
+    async fn find_active_commerce_by_alias(&self, alias_value: &str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn create_commerce<'a>(&self, commerce_entity: &'a CommerceEntity,
+                                 legal_entity_entity: &'a LegalEntityEntity, bank_id: &'a i64,
+                                 bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn find_commerce_by_id(&self, commerce_id: &i64)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn find_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn find_commerces<'a>(&self, filter: &'a CommerceFilter, cursor: &'a Option<i64>,
+                                limit: &'a i64)
+        -> Result<Vec<CommerceDbInfoWrapper>, Error>;
+    async fn find_commerce_branches(&self, parent_commerce_id: &i64)
+        -> Result<Vec<CommerceDbInfoWrapper>, Error>;
+    async fn count_commerces<'a>(&self, filter: &'a CommerceFilter) -> Result<i64, Error>;
+    async fn update_commerce<'a>(&self, commerce_entity: &'a CommerceEntity,
+                                 legal_entity_entity: &'a LegalEntityEntity)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn update_commerce_status<'a>(&self, commerce_id: &'a i64, current_status_id: &'a i64,
+                                        new_status_id: &'a i64, reason: &'a str,
+                                        changed_by: &'a str, cascade_to_branches: &'a bool)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn update_commerce_account<'a>(&self, commerce_id: &'a i64, bank_id: &'a i64,
+                                         bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn insert_commerce_account<'a>(&self, commerce_id: &'a i64, bank_id: &'a i64,
+                                         bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn delete_commerce_account<'a>(&self, commerce_id: &'a i64, account_id: &'a i64)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>;
+    async fn update_commerce_primary_account<'a>(&self, commerce_id: &'a i64, account_id: &'a i64)
         -> Result<Option<CommerceDbInfoWrapper>, Error>;
 
 }
@@ -25,24 +66,28 @@ pub trait CommerceRepository {
 pub struct SqlxCommerceRepository {
     pool: Arc<MySqlPool>,
     sqlx_account_repository: Arc<SqlxAccountRepository>,
-    sqlx_bank_repository: Arc<SqlxBankRepository>,
-    sqlx_commerce_status_repository: Arc<SqlxCommerceStatusRepository>
+    sqlx_commerce_status_repository: Arc<SqlxCommerceStatusRepository>,
+    sqlx_commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>,
+    sqlx_legal_entity_repository: Arc<SqlxLegalEntityRepository>
 }
 
 impl SqlxCommerceRepository {
     pub fn new(pool: Arc<MySqlPool>,
                sqlx_account_repository: Arc<SqlxAccountRepository>,
-               sqlx_bank_repository: Arc<SqlxBankRepository>,
-               sqlx_commerce_status_repository: Arc<SqlxCommerceStatusRepository>) -> Self {
+               sqlx_commerce_status_repository: Arc<SqlxCommerceStatusRepository>,
+               sqlx_commerce_status_history_repository: Arc<SqlxCommerceStatusHistoryRepository>,
+               sqlx_legal_entity_repository: Arc<SqlxLegalEntityRepository>) -> Self {
         Self { pool,
             sqlx_account_repository,
-            sqlx_bank_repository,
-            sqlx_commerce_status_repository
+            sqlx_commerce_status_repository,
+            sqlx_commerce_status_history_repository,
+            sqlx_legal_entity_repository
         }
     }
 
     async fn validate_account_entity_inserted_and_insert_commerce<'a>(&self,
                                                                       commerce_entity: &'a CommerceEntity,
+                                                                      legal_entity_entity: &'a LegalEntityEntity,
                                                                   account_entity: &'a Option<AccountEntity>)
         -> Result<Option<CommerceDbInfoWrapper>, Error> {
         match account_entity {
@@ -50,19 +95,26 @@ impl SqlxCommerceRepository {
             Some(account_inserted) => {
                 let mut tx = self.pool.begin().await?;
 
+                // Several aliases can point to the same legal entity, it is only created once
+                let legal_entity_stored = self.sqlx_legal_entity_repository
+                    .find_or_insert_legal_entity_tx(legal_entity_entity, &mut tx)
+                    .await?;
+
                 // Insert the commerce and get the inserted ID
                 info!("Inserting commerce_entity: {:?}", commerce_entity);
 
                 let insert_result = sqlx::query(
-                    "INSERT INTO commerces (alias, alias_type_id, legal_business_name, account_id,
-              ruc, commerce_status_id)
-     VALUES (?, ?, ?, ?, ?, 1)"
+                    "INSERT INTO commerces (alias, alias_type_id, legal_entity_id, account_id,
+              commerce_status_id, parent_commerce_id, created_at)
+     VALUES (?, ?, ?, ?, ?, ?, ?)"
                 )
                     .bind(&commerce_entity.alias)
-                    .bind(&commerce_entity.alias_type_id)
-                    .bind(&commerce_entity.legal_business_name)
-                    .bind(&account_inserted.account_id)
-                    .bind(&commerce_entity.ruc)
+                    .bind(commerce_entity.alias_type_id)
+                    .bind(legal_entity_stored.legal_entity_id)
+                    .bind(account_inserted.account_id)
+                    .bind(commerce_entity.commerce_status_id)
+                    .bind(commerce_entity.parent_commerce_id)
+                    .bind(Utc::now().naive_utc())
                     .execute(&mut *tx)
                     .await?;
 
@@ -77,9 +129,14 @@ impl SqlxCommerceRepository {
                     .await?
                     .unwrap();
 
+                self.sqlx_account_repository
+                    .link_account_to_commerce_tx(&commerce_entity_stored.id_commerce,
+                                                 &account_inserted.account_id, &mut tx)
+                    .await?;
+
                 let commerce_status_entity = self
                     .sqlx_commerce_status_repository
-                    .find_commerce_status_by_id_tx(&1, &mut tx)
+                    .find_commerce_status_by_id_tx(&commerce_entity_stored.commerce_status_id, &mut tx)
                     .await?;
 
                 tx.commit().await?;
@@ -88,14 +145,15 @@ impl SqlxCommerceRepository {
                     id_commerce: commerce_entity_stored.id_commerce,
                     alias: commerce_entity_stored.alias.clone(),
                     alias_type_id: commerce_entity_stored.alias_type_id,
-                    legal_business_name: commerce_entity_stored.legal_business_name.clone(),
+                    legal_business_name: legal_entity_stored.legal_business_name.clone(),
                     account_id: account_inserted.account_id,
                     account_number: account_inserted.account_number.clone(),
                     bank_code: account_inserted.bank_code.clone(),
                     bank_id: account_inserted.bank_id,
-                    ruc: commerce_entity_stored.ruc.clone(),
+                    ruc: legal_entity_stored.ruc.clone(),
                     commerce_status_id: commerce_entity_stored.commerce_status_id,
-                    commerce_status_name: commerce_status_entity.unwrap().status_name.clone()
+                    commerce_status_name: commerce_status_entity.unwrap().status_name.clone(),
+                    parent_commerce_id: commerce_entity_stored.parent_commerce_id
                 }))
             }
         }
@@ -104,48 +162,392 @@ impl SqlxCommerceRepository {
 
 #[async_trait]
 impl CommerceRepository for SqlxCommerceRepository {
-    async fn find_commerce_by_ruc_or_alias<'a>(&self, ruc: &'a String, alias_value: &'a String)
-        -> Result<Option<CommerceEntity>, Error> {
-        let alias_with_at_sign = format!("@{}", alias_value);
-        sqlx::query_as::<_, CommerceEntity>(
-            "SELECT * FROM commerces WHERE (ruc = ?
-                OR alias = ?) AND commerce_status_id = 1 FOR UPDATE"
-        )
-            .bind(ruc)
+    async fn find_active_commerce_by_alias(&self, alias_value: &str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let alias_with_at_sign = Commerce::canonical_alias(alias_value);
+        let query = format!("{} WHERE c.alias = ? AND c.commerce_status_id = 1
+            ORDER BY c.id_commerce ASC LIMIT 1", COMMERCE_DB_INFO_SELECT);
+        sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
             .bind(&alias_with_at_sign)
             .fetch_optional(&*self.pool)
             .await
     }
 
-    async fn find_commerce_by_ruc_or_legal_business_name<'a>(&self, ruc: &'a String,
-                                                         legal_business_name: &'a String)
-        -> Result<Option<CommerceEntity>, Error> {
-        sqlx::query_as::<_, CommerceEntity>(
-            "SELECT * FROM commerces WHERE (ruc = ?
-                OR legal_business_name = ?) AND commerce_status_id = 1 FOR UPDATE"
-        )
-            .bind(ruc)
-            .bind(legal_business_name)
+    async fn create_commerce<'a>(&self, commerce_entity: &'a CommerceEntity,
+                                 legal_entity_entity: &'a LegalEntityEntity, bank_id: &'a i64,
+                                 bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error>
+    {
+        let account_entity = self.sqlx_account_repository
+            .insert_new_account(account_number, bank_code, bank_id).await?;
+        self.validate_account_entity_inserted_and_insert_commerce(commerce_entity,
+                                                                  legal_entity_entity,
+                                                                  &account_entity).await
+    }
+
+    async fn find_commerce_by_id(&self, commerce_id: &i64)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
             .fetch_optional(&*self.pool)
             .await
     }
 
-    async fn create_commerce<'a>(&self, commerce_entity: &'a CommerceEntity, bank_code: &'a String,
-                             account_number: &'a String)
-        -> Result<Option<CommerceDbInfoWrapper>, Error>
-    {
-        let bank_entity = self.sqlx_bank_repository.find_bank_by_bank_code(bank_code)
+    async fn find_commerce_by_alias(&self, alias: &str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        // The alias is only unique among active commerces, an inactive or suspended commerce may
+        // still hold it, so the active commerce wins and then the latest one
+        let query = format!("{} WHERE c.alias = ?
+            ORDER BY (c.commerce_status_id = 1) DESC, c.id_commerce DESC LIMIT 1",
+                            COMMERCE_DB_INFO_SELECT);
+        sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(Commerce::canonical_alias(alias))
+            .fetch_optional(&*self.pool)
+            .await
+    }
+
+    async fn find_commerces<'a>(&self, filter: &'a CommerceFilter, cursor: &'a Option<i64>,
+                                limit: &'a i64)
+        -> Result<Vec<CommerceDbInfoWrapper>, Error> {
+        let mut query_builder = QueryBuilder::<MySql>::new(COMMERCE_DB_INFO_SELECT);
+        query_builder.push(" WHERE 1 = 1");
+        push_commerce_filters(&mut query_builder, filter);
+        if let Some(cursor) = cursor {
+            query_builder.push(" AND c.id_commerce > ").push_bind(*cursor);
+        }
+        query_builder.push(" ORDER BY c.id_commerce ASC LIMIT ").push_bind(*limit);
+        query_builder.build_query_as::<CommerceDbInfoWrapper>()
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn find_commerce_branches(&self, parent_commerce_id: &i64)
+        -> Result<Vec<CommerceDbInfoWrapper>, Error> {
+        let query = format!("{} WHERE c.parent_commerce_id = ? ORDER BY c.id_commerce ASC",
+                            COMMERCE_DB_INFO_SELECT);
+        sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(parent_commerce_id)
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn count_commerces<'a>(&self, filter: &'a CommerceFilter) -> Result<i64, Error> {
+        let mut query_builder = QueryBuilder::<MySql>::new(
+            "SELECT COUNT(*) FROM commerces c
+                INNER JOIN legal_entities le ON le.legal_entity_id = c.legal_entity_id
+                INNER JOIN accounts a ON a.account_id = c.account_id
+                INNER JOIN commerce_status cs ON cs.commerce_status_id = c.commerce_status_id
+            WHERE 1 = 1"
+        );
+        push_commerce_filters(&mut query_builder, filter);
+        query_builder.build_query_scalar::<i64>()
+            .fetch_one(&*self.pool)
+            .await
+    }
+
+    async fn update_commerce<'a>(&self, commerce_entity: &'a CommerceEntity,
+                                 legal_entity_entity: &'a LegalEntityEntity)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        info!("Updating commerce_entity: {:?}", commerce_entity);
+        let update_result = sqlx::query(
+            "UPDATE commerces SET alias = ?, alias_type_id = ? WHERE id_commerce = ?"
+        )
+            .bind(&commerce_entity.alias)
+            .bind(commerce_entity.alias_type_id)
+            .bind(commerce_entity.id_commerce)
+            .execute(&mut *tx)
+            .await?;
+        info!("Update completed, rows affected: {}", update_result.rows_affected());
+
+        // The legal business name is shared by every alias of the legal entity
+        self.sqlx_legal_entity_repository
+            .update_legal_business_name_by_commerce_id_tx(&commerce_entity.id_commerce,
+                                                          &legal_entity_entity.legal_business_name,
+                                                          &mut tx)
+            .await?;
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_entity.id_commerce)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
+    }
+
+    async fn update_commerce_status<'a>(&self, commerce_id: &'a i64, current_status_id: &'a i64,
+                                        new_status_id: &'a i64, reason: &'a str,
+                                        changed_by: &'a str, cascade_to_branches: &'a bool)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // The current status is part of the filter so a concurrent change is not overwritten
+        let update_result = sqlx::query(
+            "UPDATE commerces SET commerce_status_id = ?, status_reason = ?
+                WHERE id_commerce = ? AND commerce_status_id = ?"
+        )
+            .bind(new_status_id)
+            .bind(reason)
+            .bind(commerce_id)
+            .bind(current_status_id)
+            .execute(&mut *tx)
+            .await?;
+        info!("Status update completed, rows affected: {}", update_result.rows_affected());
+
+        if update_result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        self.sqlx_commerce_status_history_repository
+            .insert_commerce_status_history_tx(&CommerceStatusHistoryEntity {
+                history_id: 0,
+                id_commerce: *commerce_id,
+                previous_commerce_status_id: *current_status_id,
+                new_commerce_status_id: *new_status_id,
+                reason: reason.to_string(),
+                changed_by: changed_by.to_string(),
+                changed_at: Utc::now().naive_utc(),
+            }, &mut tx)
+            .await?;
+
+        if *cascade_to_branches {
+            // Only branches still in the parent's previous status follow it, others keep their own
+            let branch_ids = sqlx::query_scalar::<_, i64>(
+                "SELECT id_commerce FROM commerces
+                    WHERE parent_commerce_id = ? AND commerce_status_id = ? FOR UPDATE"
+            )
+                .bind(commerce_id)
+                .bind(current_status_id)
+                .fetch_all(&mut *tx)
+                .await?;
+            for branch_id in branch_ids {
+                sqlx::query(
+                    "UPDATE commerces SET commerce_status_id = ?, status_reason = ?
+                        WHERE id_commerce = ?"
+                )
+                    .bind(new_status_id)
+                    .bind(reason)
+                    .bind(branch_id)
+                    .execute(&mut *tx)
+                    .await?;
+                self.sqlx_commerce_status_history_repository
+                    .insert_commerce_status_history_tx(&CommerceStatusHistoryEntity {
+                        history_id: 0,
+                        id_commerce: branch_id,
+                        previous_commerce_status_id: *current_status_id,
+                        new_commerce_status_id: *new_status_id,
+                        reason: reason.to_string(),
+                        changed_by: changed_by.to_string(),
+                        changed_at: Utc::now().naive_utc(),
+                    }, &mut tx)
+                    .await?;
+                info!("Status of branch {} changed along with commerce {}", branch_id, commerce_id);
+            }
+        }
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
+            .fetch_optional(&mut *tx)
             .await?;
 
-        match bank_entity {
-            Some(bank_entity) => {
-                let account_entity = self.sqlx_account_repository
-                    .insert_new_account(account_number, bank_code, &bank_entity.bank_id).await?;
-                self.validate_account_entity_inserted_and_insert_commerce(commerce_entity,
-                                                                          &account_entity).await
-            },
-            None => Err(Error::ColumnNotFound(format!("Bank not found with bank_code: {}", bank_code)))
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
+    }
+
+    async fn update_commerce_account<'a>(&self, commerce_id: &'a i64, bank_id: &'a i64,
+                                         bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the commerce so two concurrent rotations cannot both supersede the same account.
+        // A commerce suspended meanwhile keeps its accounts until its banks are enabled again
+        let current_commerce_entity = match find_commerce_for_update_tx(commerce_id, &mut tx).await? {
+            Some(commerce_entity)
+            if commerce_entity.commerce_status_id != CommerceStatus::Suspended.id() => commerce_entity,
+            _ => {
+                tx.rollback().await?;
+                return Ok(None);
+            }
+        };
+
+        let account_entity = self.sqlx_account_repository
+            .insert_new_account_tx(account_number, bank_code, bank_id, &mut tx)
+            .await?
+            .ok_or(Error::RowNotFound)?;
+        info!("Inserted new account {} for commerce {}", account_entity.account_id, commerce_id);
+
+        sqlx::query("UPDATE commerces SET account_id = ? WHERE id_commerce = ?")
+            .bind(account_entity.account_id)
+            .bind(commerce_id)
+            .execute(&mut *tx)
+            .await?;
+        self.sqlx_account_repository
+            .link_account_to_commerce_tx(commerce_id, &account_entity.account_id, &mut tx)
+            .await?;
+
+        self.sqlx_account_repository
+            .unlink_account_from_commerce_tx(commerce_id, &current_commerce_entity.account_id, &mut tx)
+            .await?;
+        self.sqlx_account_repository
+            .supersede_account_tx(&current_commerce_entity.account_id, &mut tx)
+            .await?;
+        info!("Account {} superseded for commerce {}", current_commerce_entity.account_id,
+            commerce_id);
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
+    }
+
+    async fn insert_commerce_account<'a>(&self, commerce_id: &'a i64, bank_id: &'a i64,
+                                         bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        if find_commerce_for_update_tx(commerce_id, &mut tx).await?.is_none() {
+            tx.rollback().await?;
+            return Ok(None);
         }
+
+        let account_entity = self.sqlx_account_repository
+            .insert_new_account_tx(account_number, bank_code, bank_id, &mut tx)
+            .await?
+            .ok_or(Error::RowNotFound)?;
+        self.sqlx_account_repository
+            .link_account_to_commerce_tx(commerce_id, &account_entity.account_id, &mut tx)
+            .await?;
+        info!("Account {} added to commerce {}", account_entity.account_id, commerce_id);
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
     }
+
+    async fn delete_commerce_account<'a>(&self, commerce_id: &'a i64, account_id: &'a i64)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // The primary account and the status are checked again under the lock, the account may
+        // have been promoted or the commerce suspended meanwhile
+        let cannot_be_removed = find_commerce_for_update_tx(commerce_id, &mut tx).await?
+            .is_none_or(|commerce_entity| commerce_entity.account_id == *account_id
+                || commerce_entity.commerce_status_id == CommerceStatus::Suspended.id());
+        if cannot_be_removed {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        let unlinked = self.sqlx_account_repository
+            .unlink_account_from_commerce_tx(commerce_id, account_id, &mut tx)
+            .await?;
+        if !unlinked {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+        self.sqlx_account_repository
+            .supersede_account_tx(account_id, &mut tx)
+            .await?;
+        info!("Account {} removed from commerce {}", account_id, commerce_id);
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
+    }
+
+    async fn update_commerce_primary_account<'a>(&self, commerce_id: &'a i64, account_id: &'a i64)
+        -> Result<Option<CommerceDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // Only an account already linked to the commerce can become its primary account
+        let update_result = sqlx::query(
+            "UPDATE commerces c SET c.account_id = ?
+                WHERE c.id_commerce = ? AND EXISTS (SELECT 1 FROM commerce_accounts ca
+                    WHERE ca.id_commerce = c.id_commerce AND ca.account_id = ?)"
+        )
+            .bind(account_id)
+            .bind(commerce_id)
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await?;
+        info!("Primary account update completed, rows affected: {}", update_result.rows_affected());
+
+        if update_result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        let query = format!("{} WHERE c.id_commerce = ?", COMMERCE_DB_INFO_SELECT);
+        let commerce_db_info_wrapper = sqlx::query_as::<_, CommerceDbInfoWrapper>(&query)
+            .bind(commerce_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(commerce_db_info_wrapper)
+    }
+}
+
+async fn find_commerce_for_update_tx(commerce_id: &i64, tx: &mut Transaction<'_, MySql>)
+    -> Result<Option<CommerceEntity>, Error> {
+    sqlx::query_as::<_, CommerceEntity>(
+        "SELECT * FROM commerces WHERE id_commerce = ? FOR UPDATE"
+    )
+        .bind(commerce_id)
+        .fetch_optional(&mut **tx)
+        .await
+}
+
+fn push_commerce_filters(query_builder: &mut QueryBuilder<'_, MySql>, filter: &CommerceFilter) {
+    if let Some(bank_code) = &filter.bank_code {
+        query_builder.push(" AND a.bank_code = ").push_bind(bank_code.clone());
+    }
+    if let Some(status_name) = &filter.status_name {
+        query_builder.push(" AND cs.status_name = ").push_bind(status_name.clone());
+    }
+    if let Some(alias_type) = filter.alias_type {
+        query_builder.push(" AND c.alias_type_id = ").push_bind(alias_type);
+    }
+    if let Some(ruc_prefix) = &filter.ruc_prefix {
+        query_builder.push(" AND le.ruc LIKE ")
+            .push_bind(format!("{}%", escape_like_pattern(ruc_prefix)));
+    }
+    if let Some(legal_business_name) = &filter.legal_business_name {
+        query_builder.push(" AND le.legal_business_name LIKE ")
+            .push_bind(format!("%{}%", escape_like_pattern(legal_business_name)));
+    }
+}
+
+fn escape_like_pattern(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
 }
 
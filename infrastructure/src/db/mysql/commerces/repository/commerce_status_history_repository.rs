@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{Error, MySql, MySqlPool, Transaction};
+use crate::db::mysql::commerces::entity::commerce_status_history_entity::CommerceStatusHistoryEntity;
+
+#[async_trait]
+pub trait CommerceStatusHistoryRepository {
+    async fn find_commerce_status_history_by_commerce_id(&self, commerce_id: &i64)
+        -> Result<Vec<CommerceStatusHistoryEntity>, Error>;
+
+    // Must run in the transaction that changes the commerce status
+    async fn insert_commerce_status_history_tx<'a>(&self,
+                                                   commerce_status_history_entity: &CommerceStatusHistoryEntity,
+                                                   tx: &mut Transaction<'a, MySql>)
+        -> Result<(), Error>;
+}
+
+pub struct SqlxCommerceStatusHistoryRepository {
+    pool: Arc<MySqlPool>,
+}
+
+impl SqlxCommerceStatusHistoryRepository {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CommerceStatusHistoryRepository for SqlxCommerceStatusHistoryRepository {
+    async fn find_commerce_status_history_by_commerce_id(&self, commerce_id: &i64)
+        -> Result<Vec<CommerceStatusHistoryEntity>, Error> {
+        sqlx::query_as::<_, CommerceStatusHistoryEntity>(
+            "SELECT * FROM commerce_status_history WHERE id_commerce = ?
+                ORDER BY changed_at ASC, history_id ASC"
+        )
+            .bind(commerce_id)
+            .fetch_all(&*self.pool)
+            .await
+    }
+
+    async fn insert_commerce_status_history_tx<'a>(&self,
+                                                   commerce_status_history_entity: &CommerceStatusHistoryEntity,
+                                                   tx: &mut Transaction<'a, MySql>)
+        -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO commerce_status_history (id_commerce, previous_commerce_status_id,
+                new_commerce_status_id, reason, changed_by, changed_at)
+                VALUES (?, ?, ?, ?, ?, ?)"
+        )
+            .bind(commerce_status_history_entity.id_commerce)
+            .bind(commerce_status_history_entity.previous_commerce_status_id)
+            .bind(commerce_status_history_entity.new_commerce_status_id)
+            .bind(&commerce_status_history_entity.reason)
+            .bind(&commerce_status_history_entity.changed_by)
+            .bind(commerce_status_history_entity.changed_at)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{Error, MySql, MySqlPool, Transaction};
+use crate::db::mysql::commerces::entity::legal_entity_entity::LegalEntityEntity;
+
+#[async_trait]
+pub trait LegalEntityRepository {
+    async fn find_legal_entity_by_ruc_or_legal_business_name<'a>(&self, ruc: &'a str,
+                                                                 legal_business_name: &'a str)
+        -> Result<Option<LegalEntityEntity>, Error>;
+
+    // The legal entity the commerce already belongs to is not taken into account
+    async fn find_legal_entity_by_ruc_or_legal_business_name_excluding<'a>(&self,
+                                                                           commerce_id: &'a i64,
+                                                                           ruc: &'a str,
+                                                                           legal_business_name: &'a str)
+        -> Result<Option<LegalEntityEntity>, Error>;
+
+    // Returns the legal entity with the given RUC, creating it when it does not exist yet
+    async fn find_or_insert_legal_entity_tx<'a>(&self, legal_entity_entity: &LegalEntityEntity,
+                                                tx: &mut Transaction<'a, MySql>)
+        -> Result<LegalEntityEntity, Error>;
+
+    async fn update_legal_business_name_by_commerce_id_tx<'a>(&self, commerce_id: &i64,
+                                                              legal_business_name: &str,
+                                                              tx: &mut Transaction<'a, MySql>)
+        -> Result<(), Error>;
+}
+
+pub struct SqlxLegalEntityRepository {
+    pool: Arc<MySqlPool>,
+}
+
+impl SqlxLegalEntityRepository {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LegalEntityRepository for SqlxLegalEntityRepository {
+    async fn find_legal_entity_by_ruc_or_legal_business_name<'a>(&self, ruc: &'a str,
+                                                                 legal_business_name: &'a str)
+        -> Result<Option<LegalEntityEntity>, Error> {
+        sqlx::query_as::<_, LegalEntityEntity>(
+            "SELECT * FROM legal_entities WHERE ruc = ? OR legal_business_name = ?"
+        )
+            .bind(ruc)
+            .bind(legal_business_name)
+            .fetch_optional(&*self.pool)
+            .await
+    }
+
+    async fn find_legal_entity_by_ruc_or_legal_business_name_excluding<'a>(&self,
+                                                                           commerce_id: &'a i64,
+                                                                           ruc: &'a str,
+                                                                           legal_business_name: &'a str)
+        -> Result<Option<LegalEntityEntity>, Error> {
+        sqlx::query_as::<_, LegalEntityEntity>(
+            "SELECT le.* FROM legal_entities le WHERE (le.ruc = ? OR le.legal_business_name = ?)
+                AND le.legal_entity_id <> (SELECT c.legal_entity_id FROM commerces c
+                    WHERE c.id_commerce = ?)"
+        )
+            .bind(ruc)
+            .bind(legal_business_name)
+            .bind(commerce_id)
+            .fetch_optional(&*self.pool)
+            .await
+    }
+
+    async fn find_or_insert_legal_entity_tx<'a>(&self, legal_entity_entity: &LegalEntityEntity,
+                                                tx: &mut Transaction<'a, MySql>)
+        -> Result<LegalEntityEntity, Error> {
+        let legal_entity_stored = sqlx::query_as::<_, LegalEntityEntity>(
+            "SELECT * FROM legal_entities WHERE ruc = ? FOR UPDATE"
+        )
+            .bind(&legal_entity_entity.ruc)
+            .fetch_optional(&mut **tx)
+            .await?;
+        if let Some(legal_entity_stored) = legal_entity_stored {
+            return Ok(legal_entity_stored);
+        }
+
+        sqlx::query(
+            "INSERT INTO legal_entities (ruc, legal_business_name) VALUES (?, ?)"
+        )
+            .bind(&legal_entity_entity.ruc)
+            .bind(&legal_entity_entity.legal_business_name)
+            .execute(&mut **tx)
+            .await?;
+
+        sqlx::query_as::<_, LegalEntityEntity>(
+            "SELECT * FROM legal_entities WHERE legal_entity_id = LAST_INSERT_ID()"
+        )
+            .fetch_one(&mut **tx)
+            .await
+    }
+
+    async fn update_legal_business_name_by_commerce_id_tx<'a>(&self, commerce_id: &i64,
+                                                              legal_business_name: &str,
+                                                              tx: &mut Transaction<'a, MySql>)
+        -> Result<(), Error> {
+        sqlx::query(
+            "UPDATE legal_entities le INNER JOIN commerces c ON c.legal_entity_id = le.legal_entity_id
+                SET le.legal_business_name = ? WHERE c.id_commerce = ?"
+        )
+            .bind(legal_business_name)
+            .bind(commerce_id)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::error;
+use application::port::db::countries::country_repository_port::CountryRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::country::Country;
+use crate::db::mysql::countries::entity::country_entity::CountryEntity;
+use crate::db::mysql::countries::repository::country_repository::{CountryRepository, SqlxCountryRepository};
+
+pub struct CountryRepositoryAdapter {
+    country_repository: Arc<SqlxCountryRepository>
+}
+
+impl CountryRepositoryAdapter {
+    pub fn new(country_repository: Arc<SqlxCountryRepository>) -> Self {
+        Self { country_repository }
+    }
+}
+
+#[async_trait]
+impl CountryRepositoryPort for CountryRepositoryAdapter {
+    async fn find_country_by_code(&self, country_code: &i32)
+        -> Result<Option<Country>, DatabaseError> {
+        match self.country_repository.find_country_by_code(country_code).await {
+            Ok(country_entity) => Ok(country_entity.as_ref().map(map_country_entity_to_model)),
+            Err(err) => {
+                error!("Unexpected error from database. Error is: {:?}", err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+}
+
+fn map_country_entity_to_model(country_entity: &CountryEntity) -> Country {
+    Country::new(
+        country_entity.country_id,
+        country_entity.country_name.clone(),
+        country_entity.country_code,
+        country_entity.dialling_code.clone()
+    )
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct CountryEntity {
+    #[sqlx(rename = "country_id")]
+    pub country_id: i64,
+    #[sqlx(rename = "country_name")]
+    pub country_name: String,
+    #[sqlx(rename = "country_code")]
+    pub country_code: i32,
+    #[sqlx(rename = "dialling_code")]
+    pub dialling_code: String,
+}
\ No newline at end of file
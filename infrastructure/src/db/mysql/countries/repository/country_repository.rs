@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{Error, MySqlPool};
+use crate::db::mysql::countries::entity::country_entity::CountryEntity;
+
+#[async_trait]
+pub trait CountryRepository {
+    async fn find_country_by_code(&self, country_code: &i32)
+        -> Result<Option<CountryEntity>, Error>;
+}
+
+pub struct SqlxCountryRepository {
+    pool: Arc<MySqlPool>
+}
+
+impl SqlxCountryRepository {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CountryRepository for SqlxCountryRepository {
+    async fn find_country_by_code(&self, country_code: &i32)
+        -> Result<Option<CountryEntity>, Error> {
+        sqlx::query_as::<_, CountryEntity>(
+            "SELECT country_id, country_name, country_code, dialling_code
+            FROM countries WHERE country_code = ?"
+        )
+            .bind(country_code)
+            .fetch_optional(&*self.pool)
+            .await
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::error;
+use application::port::db::stats::bank_stats_repository_port::BankStatsRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::bank_stats_filter::BankStatsFilter;
+use crate::db::mysql::stats::entity::bank_commerce_stats_entity::BankCommerceStatsEntity;
+use crate::db::mysql::stats::repository::bank_stats_repository::{BankStatsRepository, SqlxBankStatsRepository};
+
+pub struct BankStatsRepositoryAdapter {
+    bank_stats_repository: Arc<SqlxBankStatsRepository>
+}
+
+impl BankStatsRepositoryAdapter {
+    pub fn new(bank_stats_repository: Arc<SqlxBankStatsRepository>) -> Self {
+        Self { bank_stats_repository }
+    }
+}
+
+#[async_trait]
+impl BankStatsRepositoryPort for BankStatsRepositoryAdapter {
+    async fn find_bank_commerce_stats(&self, filter: &BankStatsFilter)
+        -> Result<Vec<BankCommerceStats>, DatabaseError> {
+        match self.bank_stats_repository
+            .find_bank_commerce_stats(&filter.created_from, &filter.created_to)
+            .await {
+            Ok(entities) => Ok(entities.iter().map(map_bank_commerce_stats_entity_to_model).collect()),
+            Err(err) => {
+                error!("Unexpected error from database. Error is: {:?}", err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+}
+
+fn map_bank_commerce_stats_entity_to_model(entity: &BankCommerceStatsEntity) -> BankCommerceStats {
+    BankCommerceStats::new(
+        entity.bank_code.clone(),
+        entity.bank_name.clone(),
+        entity.commerce_status.clone(),
+        entity.alias_type.clone(),
+        entity.registration_month.clone(),
+        entity.commerces
+    )
+}
\ No newline at end of file
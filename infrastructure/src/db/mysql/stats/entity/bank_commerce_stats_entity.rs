@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct BankCommerceStatsEntity {
+    #[sqlx(rename = "bank_code")]
+    pub bank_code: String,
+    #[sqlx(rename = "bank_name")]
+    pub bank_name: String,
+    #[sqlx(rename = "commerce_status")]
+    pub commerce_status: String,
+    #[sqlx(rename = "alias_type")]
+    pub alias_type: String,
+    #[sqlx(rename = "registration_month")]
+    pub registration_month: Option<String>,
+    #[sqlx(rename = "commerces")]
+    pub commerces: i64,
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Error, MySql, MySqlPool, QueryBuilder};
+use crate::db::mysql::stats::entity::bank_commerce_stats_entity::BankCommerceStatsEntity;
+
+const BANK_COMMERCE_STATS_SELECT: &str =
+    "SELECT b.bank_code, b.bank_name, cs.status_name AS commerce_status,
+        aty.description AS alias_type, DATE_FORMAT(c.created_at, '%Y-%m') AS registration_month,
+        COUNT(*) AS commerces
+        FROM commerces c
+        INNER JOIN accounts a ON a.account_id = c.account_id
+        INNER JOIN banks b ON b.bank_id = a.bank_id
+        INNER JOIN commerce_status cs ON cs.commerce_status_id = c.commerce_status_id
+        INNER JOIN alias_type aty ON aty.alias_type_id = c.alias_type_id
+        WHERE 1 = 1";
+
+#[async_trait]
+pub trait BankStatsRepository {
+    async fn find_bank_commerce_stats(&self, created_from: &Option<NaiveDate>,
+                                      created_to: &Option<NaiveDate>)
+        -> Result<Vec<BankCommerceStatsEntity>, Error>;
+}
+
+pub struct SqlxBankStatsRepository {
+    pool: Arc<MySqlPool>
+}
+
+impl SqlxBankStatsRepository {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BankStatsRepository for SqlxBankStatsRepository {
+    async fn find_bank_commerce_stats(&self, created_from: &Option<NaiveDate>,
+                                      created_to: &Option<NaiveDate>)
+        -> Result<Vec<BankCommerceStatsEntity>, Error> {
+        let mut query_builder = QueryBuilder::<MySql>::new(BANK_COMMERCE_STATS_SELECT);
+        if let Some(created_from) = created_from {
+            query_builder.push(" AND c.created_at >= ")
+                .push_bind(created_from.and_hms_opt(0, 0, 0));
+        }
+        // The end date is inclusive, so the range is closed at the start of the next day
+        if let Some(created_to) = created_to {
+            query_builder.push(" AND c.created_at < ")
+                .push_bind(created_to.succ_opt().and_then(|next_day| next_day.and_hms_opt(0, 0, 0)));
+        }
+        query_builder.push(" GROUP BY b.bank_code, b.bank_name, cs.status_name, aty.description,
+            DATE_FORMAT(c.created_at, '%Y-%m')
+            ORDER BY b.bank_code, registration_month, commerce_status, alias_type");
+        query_builder.build_query_as::<BankCommerceStatsEntity>()
+            .fetch_all(&*self.pool)
+            .await
+    }
+}
\ No newline at end of file
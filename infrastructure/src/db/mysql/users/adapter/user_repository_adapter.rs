@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::Error;
+use tracing::log::error;
+use application::port::db::users::user_repository_port::UserRepositoryPort;
+use domain::exception::database_error::DatabaseError;
+use domain::model::account::Account;
+use domain::model::user::User;
+use domain::model::user_status::UserStatus;
+use crate::db::mysql::users::entity::user_entity::UserEntity;
+use crate::db::mysql::users::entity::wrappers::user_db_info_wrapper::UserDbInfoWrapper;
+use crate::db::mysql::users::repository::user_repository::{SqlxUserRepository, UserRepository};
+
+pub struct UserRepositoryAdapter {
+    user_repository: Arc<SqlxUserRepository>
+}
+
+impl UserRepositoryAdapter {
+    pub fn new(user_repository: Arc<SqlxUserRepository>) -> Self {
+        Self {
+            user_repository
+        }
+    }
+
+    fn log_error_info(err: &Error) {
+        error!("Unexpected error from database. Error is: {:?}", err);
+    }
+}
+
+#[async_trait]
+impl UserRepositoryPort for UserRepositoryAdapter {
+    async fn create_user(&self, user: &User) -> Result<User, DatabaseError> {
+        let user_entity = map_user_to_entity(user);
+
+        match self.user_repository.create_user(&user_entity, &user.account.bank_id,
+                                               &user.account.bank_code,
+                                               &user.account.account_number).await {
+            Ok(Some(user_db_info_wrapper)) => Ok(map_user_db_info_wrapper_to_model(&user_db_info_wrapper)),
+            Ok(None) => {
+                error!("Unable to return user created.");
+                Err(DatabaseError::Unexpected(Box::from("Unable to return user created")))
+            },
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn user_does_not_exist_by_alias(&self, alias: &str) -> Result<bool, DatabaseError> {
+        match self.user_repository.find_user_by_alias(alias).await {
+            Ok(Some(_)) => Ok(false),
+            Ok(None) => Ok(true),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+
+    async fn find_user_by_alias(&self, alias: &str) -> Result<Option<User>, DatabaseError> {
+        match self.user_repository.find_user_db_info_by_alias(alias).await {
+            Ok(user_db_info_wrapper) => Ok(user_db_info_wrapper.as_ref()
+                .map(map_user_db_info_wrapper_to_model)),
+            Err(err) => {
+                Self::log_error_info(&err);
+                Err(DatabaseError::Unexpected(err.into()))
+            }
+        }
+    }
+}
+
+fn map_user_to_entity(user: &User) -> UserEntity {
+    UserEntity {
+        user_id: user.user_id,
+        alias: user.alias.clone(),
+        alias_type_id: user.alias_type,
+        country_code: user.country,
+        account_id: user.account.account_id,
+        user_status_id: user.user_status.status_id,
+    }
+}
+
+fn map_user_db_info_wrapper_to_model(user_db_info_wrapper: &UserDbInfoWrapper) -> User {
+    User {
+        user_id: user_db_info_wrapper.user_id,
+        alias_type: user_db_info_wrapper.alias_type_id,
+        alias: user_db_info_wrapper.alias.clone(),
+        country: user_db_info_wrapper.country_code,
+        account: Account {
+            account_id: user_db_info_wrapper.account_id,
+            account_number: user_db_info_wrapper.account_number.clone(),
+            bank_code: user_db_info_wrapper.bank_code.clone(),
+            bank_id: user_db_info_wrapper.bank_id
+        },
+        user_status: UserStatus::new(user_db_info_wrapper.user_status_id,
+                                     user_db_info_wrapper.user_status_name.clone())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct UserEntity {
+    #[sqlx(rename = "user_id")]
+    pub user_id: i64,
+    #[sqlx(rename = "alias")]
+    pub alias: String,
+    #[sqlx(rename = "alias_type_id")]
+    pub alias_type_id: i64,
+    #[sqlx(rename = "country_code")]
+    pub country_code: i32,
+    #[sqlx(rename = "account_id")]
+    pub account_id: i64,
+    #[sqlx(rename = "user_status_id")]
+    pub user_status_id: i64,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct UserDbInfoWrapper {
+    pub user_id: i64,
+    pub alias: String,
+    pub alias_type_id: i64,
+    pub country_code: i32,
+    pub account_id: i64,
+    pub account_number: String,
+    pub bank_code: String,
+    pub bank_id: i64,
+    pub user_status_id: i64,
+    pub user_status_name: String
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Error, MySqlPool};
+use tracing::info;
+use crate::db::mysql::commerces::repository::account_repository::{AccountRepository, SqlxAccountRepository};
+use crate::db::mysql::users::entity::user_entity::UserEntity;
+use crate::db::mysql::users::entity::wrappers::user_db_info_wrapper::UserDbInfoWrapper;
+
+const USER_DB_INFO_SELECT: &str =
+    "SELECT u.user_id, u.alias, u.alias_type_id, u.country_code, u.account_id, a.account_number,
+        a.bank_code, a.bank_id, u.user_status_id, us.status_name AS user_status_name
+    FROM users u
+        INNER JOIN accounts a ON a.account_id = u.account_id
+        INNER JOIN user_status us ON us.user_status_id = u.user_status_id";
+
+#[async_trait]
+pub trait UserRepository {
+    async fn find_user_by_alias(&self, alias: &str) -> Result<Option<UserEntity>, Error>;
+    async fn find_user_db_info_by_alias(&self, alias: &str)
+        -> Result<Option<UserDbInfoWrapper>, Error>;
+    async fn create_user<'a>(&self, user_entity: &'a UserEntity, bank_id: &'a i64,
+                             bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<UserDbInfoWrapper>, Error>;
+}
+
+pub struct SqlxUserRepository {
+    pool: Arc<MySqlPool>,
+    sqlx_account_repository: Arc<SqlxAccountRepository>
+}
+
+impl SqlxUserRepository {
+    pub fn new(pool: Arc<MySqlPool>,
+               sqlx_account_repository: Arc<SqlxAccountRepository>) -> Self {
+        Self {
+            pool,
+            sqlx_account_repository
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqlxUserRepository {
+    async fn find_user_by_alias(&self, alias: &str) -> Result<Option<UserEntity>, Error> {
+        sqlx::query_as::<_, UserEntity>(
+            "SELECT user_id, alias, alias_type_id, country_code, account_id, user_status_id
+            FROM users WHERE alias = ?"
+        )
+            .bind(alias)
+            .fetch_optional(&*self.pool)
+            .await
+    }
+
+    async fn find_user_db_info_by_alias(&self, alias: &str)
+        -> Result<Option<UserDbInfoWrapper>, Error> {
+        sqlx::query_as::<_, UserDbInfoWrapper>(
+            &format!("{} WHERE u.alias = ?", USER_DB_INFO_SELECT)
+        )
+            .bind(alias)
+            .fetch_optional(&*self.pool)
+            .await
+    }
+
+    async fn create_user<'a>(&self, user_entity: &'a UserEntity, bank_id: &'a i64,
+                             bank_code: &'a str, account_number: &'a str)
+        -> Result<Option<UserDbInfoWrapper>, Error> {
+        let mut tx = self.pool.begin().await?;
+        let account_inserted = self.sqlx_account_repository
+            .insert_new_account_tx(account_number, bank_code, bank_id, &mut tx)
+            .await?
+            .ok_or(Error::RowNotFound)?;
+
+        info!("Inserting user_entity: {:?}", user_entity);
+        let insert_result = sqlx::query(
+            "INSERT INTO users (alias, alias_type_id, country_code, account_id, user_status_id,
+              created_at)
+     VALUES (?, ?, ?, ?, ?, ?)"
+        )
+            .bind(&user_entity.alias)
+            .bind(user_entity.alias_type_id)
+            .bind(user_entity.country_code)
+            .bind(account_inserted.account_id)
+            .bind(user_entity.user_status_id)
+            .bind(Utc::now().naive_utc())
+            .execute(&mut *tx)
+            .await?;
+        info!("Insert completed, rows affected: {}", insert_result.rows_affected());
+
+        let user_stored = sqlx::query_as::<_, UserDbInfoWrapper>(
+            &format!("{} WHERE u.user_id = LAST_INSERT_ID()", USER_DB_INFO_SELECT)
+        )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(user_stored)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::commerces::resolve_creditor_alias_use_case::ResolveCreditorAliasUseCase;
+use domain::model::creditor_user_info::CreditorUserInfo;
+use domain::model::generic_response::GenericResponse;
+use domain::model::status_creditor_user_info::StatusCreditorUserInfo;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+
+pub struct AliasController<RC: ResolveCreditorAliasUseCase> {
+    resolve_creditor_alias_use_case: Arc<RC>
+}
+
+impl<RC: ResolveCreditorAliasUseCase> AliasController<RC> {
+    pub fn new(resolve_creditor_alias_use_case: Arc<RC>) -> Self {
+        Self {
+            resolve_creditor_alias_use_case
+        }
+    }
+
+    pub async fn resolve_alias(&self, alias: String)
+        -> Result<(Status, Json<CreditorUserInfo>), (Status, Json<GenericResponse>)> {
+        match self.resolve_creditor_alias_use_case
+            .process(StatusCreditorUserInfo::new(alias)).await {
+            Ok(creditor_user_info) => Ok((Status::Ok, Json::from(creditor_user_info))),
+            Err(error_resolving_alias) => map_errors_to_responses(error_resolving_alias)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::banks::list_bank_certificates_use_case::ListBankCertificatesUseCase;
+use application::use_case::banks::upload_bank_certificate_use_case::UploadBankCertificateUseCase;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::banks::dto::bank_certificate_upload_dto::BankCertificateUploadDto;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::calling_bank::CallingBank;
+
+pub struct BankCertificateController<UC, LC>
+where
+    UC: UploadBankCertificateUseCase,
+    LC: ListBankCertificatesUseCase
+{
+    upload_bank_certificate_use_case: Arc<UC>,
+    list_bank_certificates_use_case: Arc<LC>
+}
+
+impl<UC, LC> BankCertificateController<UC, LC>
+where
+    UC: UploadBankCertificateUseCase,
+    LC: ListBankCertificatesUseCase
+{
+    pub fn new(upload_bank_certificate_use_case: Arc<UC>,
+               list_bank_certificates_use_case: Arc<LC>) -> Self {
+        Self {
+            upload_bank_certificate_use_case,
+            list_bank_certificates_use_case
+        }
+    }
+
+    pub async fn upload_bank_certificate(&self, bank_code: String,
+                                         upload_dto: BankCertificateUploadDto,
+                                         calling_bank: CallingBank)
+        -> Result<(Status, Json<BankCertificate>), (Status, Json<GenericResponse>)> {
+        authorize_own_bank(&calling_bank, &bank_code)?;
+        let certificate_pem = match upload_dto.certificate_pem {
+            Some(certificate_pem) => certificate_pem,
+            None => {
+                let bank_error = BankError::not_valid_certificate();
+                let error_response = GenericResponse::new(
+                    String::from(bank_error.get_code()),
+                    String::from("ERROR"),
+                    String::from(bank_error.get_message())
+                );
+                return Err((Status::BadRequest, Json(error_response)));
+            }
+        };
+        match self.upload_bank_certificate_use_case
+            .process(bank_code, certificate_pem, upload_dto.cutover_hours)
+            .await
+        {
+            Ok(certificate) => Ok((Status::Created, Json::from(certificate))),
+            Err(error_uploading_certificate) => map_errors_to_responses(error_uploading_certificate)
+        }
+    }
+
+    pub async fn list_bank_certificates(&self, bank_code: String, calling_bank: CallingBank)
+        -> Result<(Status, Json<Vec<BankCertificate>>), (Status, Json<GenericResponse>)> {
+        authorize_own_bank(&calling_bank, &bank_code)?;
+        match self.list_bank_certificates_use_case.process(bank_code).await {
+            Ok(certificates) => Ok((Status::Ok, Json::from(certificates))),
+            Err(error_listing_certificates) => map_errors_to_responses(error_listing_certificates)
+        }
+    }
+}
+
+// A bank only manages its own mTLS certificates
+fn authorize_own_bank(calling_bank: &CallingBank, bank_code: &str)
+    -> Result<(), (Status, Json<GenericResponse>)> {
+    if calling_bank.0 == bank_code {
+        return Ok(());
+    }
+    let bank_error = BankError::access_to_another_bank();
+    let error_response = GenericResponse::new(
+        String::from(bank_error.get_code()),
+        String::from("ERROR"),
+        String::from(bank_error.get_message())
+    );
+    Err((Status::Forbidden, Json(error_response)))
+}
\ No newline at end of file
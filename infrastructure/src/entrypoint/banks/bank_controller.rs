@@ -0,0 +1,144 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::banks::change_bank_status_use_case::ChangeBankStatusUseCase;
+use application::use_case::banks::create_bank_use_case::CreateBankUseCase;
+use application::use_case::banks::get_bank_use_case::GetBankUseCase;
+use application::use_case::banks::list_banks_use_case::ListBanksUseCase;
+use application::use_case::banks::schedule_bank_status_change_use_case::ScheduleBankStatusChangeUseCase;
+use application::use_case::banks::update_bank_use_case::UpdateBankUseCase;
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
+use domain::model::bank_status_schedule::BankStatusSchedule;
+use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::banks::dto::bank_dto::BankDto;
+use crate::entrypoint::banks::dto::bank_status_schedule_dto::BankStatusScheduleDto;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::acting_client::ActingClient;
+
+pub struct BankController<CB, GB, LB, UB, SB, SS>
+where
+    CB: CreateBankUseCase,
+    GB: GetBankUseCase,
+    LB: ListBanksUseCase,
+    UB: UpdateBankUseCase,
+    SB: ChangeBankStatusUseCase,
+    SS: ScheduleBankStatusChangeUseCase
+{
+    create_bank_use_case: Arc<CB>,
+    get_bank_use_case: Arc<GB>,
+    list_banks_use_case: Arc<LB>,
+    update_bank_use_case: Arc<UB>,
+    change_bank_status_use_case: Arc<SB>,
+    schedule_bank_status_change_use_case: Arc<SS>
+}
+
+impl<CB, GB, LB, UB, SB, SS> BankController<CB, GB, LB, UB, SB, SS>
+where
+    CB: CreateBankUseCase,
+    GB: GetBankUseCase,
+    LB: ListBanksUseCase,
+    UB: UpdateBankUseCase,
+    SB: ChangeBankStatusUseCase,
+    SS: ScheduleBankStatusChangeUseCase
+{
+    pub fn new(create_bank_use_case: Arc<CB>, get_bank_use_case: Arc<GB>,
+               list_banks_use_case: Arc<LB>, update_bank_use_case: Arc<UB>,
+               change_bank_status_use_case: Arc<SB>,
+               schedule_bank_status_change_use_case: Arc<SS>) -> Self {
+        Self {
+            create_bank_use_case,
+            get_bank_use_case,
+            list_banks_use_case,
+            update_bank_use_case,
+            change_bank_status_use_case,
+            schedule_bank_status_change_use_case
+        }
+    }
+
+    pub async fn create_bank(&self, bank_dto: BankDto)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        let bank = bank_dto.to_domain().map_err(map_bank_dto_error)?;
+        match self.create_bank_use_case.process(bank).await {
+            Ok(bank_created) => Ok((Status::Created, Json::from(bank_created))),
+            Err(error_creating_bank) => map_errors_to_responses(error_creating_bank)
+        }
+    }
+
+    pub async fn get_bank(&self, bank_code: String)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        match self.get_bank_use_case.process(bank_code).await {
+            Ok(bank) => Ok((Status::Ok, Json::from(bank))),
+            Err(error_getting_bank) => map_errors_to_responses(error_getting_bank)
+        }
+    }
+
+    pub async fn list_banks(&self)
+        -> Result<(Status, Json<Vec<Bank>>), (Status, Json<GenericResponse>)> {
+        match self.list_banks_use_case.process().await {
+            Ok(banks) => Ok((Status::Ok, Json::from(banks))),
+            Err(error_listing_banks) => map_errors_to_responses(error_listing_banks)
+        }
+    }
+
+    pub async fn update_bank(&self, bank_code: String, bank_dto: BankDto)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        let bank = bank_dto.to_update_domain(&bank_code).map_err(map_bank_dto_error)?;
+        match self.update_bank_use_case.process(bank_code, bank).await {
+            Ok(updated_bank) => Ok((Status::Ok, Json::from(updated_bank))),
+            Err(error_updating_bank) => map_errors_to_responses(error_updating_bank)
+        }
+    }
+
+    pub async fn disable_bank(&self, bank_code: String, acting_client: ActingClient)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        self.change_bank_status(bank_code, BankStatus::Suspended, acting_client).await
+    }
+
+    pub async fn enable_bank(&self, bank_code: String, acting_client: ActingClient)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        self.change_bank_status(bank_code, BankStatus::Enabled, acting_client).await
+    }
+
+    pub async fn certify_bank(&self, bank_code: String, acting_client: ActingClient)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        self.change_bank_status(bank_code, BankStatus::Certified, acting_client).await
+    }
+
+    pub async fn offboard_bank(&self, bank_code: String, acting_client: ActingClient)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        self.change_bank_status(bank_code, BankStatus::Offboarded, acting_client).await
+    }
+
+    pub async fn schedule_bank_status_change(&self, bank_code: String,
+                                             schedule_dto: BankStatusScheduleDto,
+                                             acting_client: ActingClient)
+        -> Result<(Status, Json<BankStatusSchedule>), (Status, Json<GenericResponse>)> {
+        let (new_status, effective_at) = schedule_dto.to_domain().map_err(map_bank_dto_error)?;
+        match self.schedule_bank_status_change_use_case
+            .process(bank_code, new_status, effective_at, acting_client.0)
+            .await
+        {
+            Ok(schedule) => Ok((Status::Created, Json::from(schedule))),
+            Err(error_scheduling_status) => map_errors_to_responses(error_scheduling_status)
+        }
+    }
+
+    async fn change_bank_status(&self, bank_code: String, new_status: BankStatus,
+                                acting_client: ActingClient)
+        -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+        match self.change_bank_status_use_case.process(bank_code, new_status, acting_client.0).await {
+            Ok(bank) => Ok((Status::Ok, Json::from(bank))),
+            Err(error_changing_status) => map_errors_to_responses(error_changing_status)
+        }
+    }
+}
+
+fn map_bank_dto_error(bank_error: BankError) -> (Status, Json<GenericResponse>) {
+    let error_response = GenericResponse::new(
+        String::from(bank_error.get_code()),
+        String::from("ERROR"),
+        String::from(bank_error.get_message())
+    );
+    (Status::BadRequest, Json(error_response))
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct BankCertificateUploadDto {
+    #[serde(rename = "certificatePem")]
+    pub certificate_pem: Option<String>,
+
+    // Hours the previous certificates stay valid, up to 720. The service default is used when
+    // missing
+    #[serde(rename = "cutoverHours")]
+    pub cutover_hours: Option<u32>,
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use domain::exception::bank_error::BankError;
+use domain::model::bank::Bank;
+use domain::model::bank_status::BankStatus;
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct BankDto {
+    #[serde(rename = "bankName")]
+    pub bank_name: Option<String>,
+
+    #[serde(rename = "bankCode")]
+    pub bank_code: Option<String>,
+
+    #[serde(rename = "contactName")]
+    pub contact_name: Option<String>,
+
+    #[serde(rename = "contactMail")]
+    pub contact_mail: Option<String>,
+
+    #[serde(rename = "notificationMail")]
+    pub notification_mail: Option<String>,
+
+    #[serde(rename = "contactPhone")]
+    pub contact_phone: Option<String>,
+
+    #[serde(rename = "bankRuc")]
+    pub bank_ruc: Option<String>,
+}
+
+impl BankDto {
+    // Convert to domain model, the status is assigned by the bank services
+    pub fn to_domain(self) -> Result<Bank, BankError> {
+        Ok(Bank::new(
+            self.bank_name.ok_or_else(BankError::not_valid_bank_name)?,
+            self.bank_code.ok_or_else(BankError::not_valid_bank_code)?,
+            self.contact_name.ok_or_else(BankError::not_valid_contact_name)?,
+            self.contact_mail.ok_or_else(BankError::not_valid_bank_mail)?,
+            self.notification_mail.ok_or_else(BankError::not_valid_bank_mail)?,
+            self.contact_phone.ok_or_else(BankError::not_valid_bank_phone)?,
+            self.bank_ruc.ok_or_else(BankError::not_valid_bank_ruc)?,
+            BankStatus::Enabled,
+            String::new()
+        ))
+    }
+
+    // The bank code is taken from the path on updates, so it may be left out of the body
+    pub fn to_update_domain(self, bank_code: &str) -> Result<Bank, BankError> {
+        let bank_code = self.bank_code.clone().unwrap_or_else(|| bank_code.to_string());
+        BankDto { bank_code: Some(bank_code), ..self }.to_domain()
+    }
+}
\ No newline at end of file
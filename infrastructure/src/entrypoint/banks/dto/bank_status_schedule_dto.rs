@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use domain::exception::bank_error::BankError;
+use domain::model::bank_status::BankStatus;
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct BankStatusScheduleDto {
+    #[serde(rename = "status")]
+    pub status: Option<String>,
+
+    // RFC 3339 timestamp, e.g. 2025-09-01T09:00:00-05:00
+    #[serde(rename = "effectiveAt")]
+    pub effective_at: Option<String>,
+}
+
+impl BankStatusScheduleDto {
+    pub fn to_domain(&self) -> Result<(BankStatus, DateTime<Utc>), BankError> {
+        let new_status = self.status
+            .as_deref()
+            .and_then(|status| BankStatus::from_name(&status.trim().to_uppercase()))
+            .ok_or_else(BankError::not_valid_bank_status)?;
+        let effective_at = self.effective_at
+            .as_deref()
+            .and_then(|effective_at| DateTime::parse_from_rfc3339(effective_at.trim()).ok())
+            .ok_or_else(BankError::not_valid_effective_date)?;
+        Ok((new_status, effective_at.with_timezone(&Utc)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::commerces::add_commerce_account_use_case::AddCommerceAccountUseCase;
+use application::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+use application::use_case::commerces::promote_commerce_account_use_case::PromoteCommerceAccountUseCase;
+use application::use_case::commerces::remove_commerce_account_use_case::RemoveCommerceAccountUseCase;
+use domain::model::commerce::Commerce;
+use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::commerces::dto::account_dto::AccountDto;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::calling_bank::CallingBank;
+
+pub struct CommerceAccountController<AC, RC, PC, BA>
+where
+    AC: AddCommerceAccountUseCase,
+    RC: RemoveCommerceAccountUseCase,
+    PC: PromoteCommerceAccountUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    add_commerce_account_use_case: Arc<AC>,
+    remove_commerce_account_use_case: Arc<RC>,
+    promote_commerce_account_use_case: Arc<PC>,
+    authorize_bank_commerce_access_use_case: Arc<BA>
+}
+
+impl<AC, RC, PC, BA> CommerceAccountController<AC, RC, PC, BA>
+where
+    AC: AddCommerceAccountUseCase,
+    RC: RemoveCommerceAccountUseCase,
+    PC: PromoteCommerceAccountUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    pub fn new(add_commerce_account_use_case: Arc<AC>, remove_commerce_account_use_case: Arc<RC>,
+               promote_commerce_account_use_case: Arc<PC>,
+               authorize_bank_commerce_access_use_case: Arc<BA>) -> Self {
+        Self {
+            add_commerce_account_use_case,
+            remove_commerce_account_use_case,
+            promote_commerce_account_use_case,
+            authorize_bank_commerce_access_use_case
+        }
+    }
+
+    pub async fn add_commerce_account(&self, commerce_id: i64, account_dto: AccountDto,
+                                      calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        let account = account_dto.to_domain().map_err(|commerce_error| {
+            let error_response = GenericResponse::new(
+                String::from(commerce_error.get_code()),
+                String::from("ERROR"),
+                String::from(commerce_error.get_message())
+            );
+            (Status::BadRequest, Json(error_response))
+        })?;
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .authorize_account(&calling_bank.0, &account) {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.add_commerce_account_use_case.process(commerce_id, account).await {
+            Ok(commerce) => Ok((Status::Created, Json::from(commerce))),
+            Err(error_adding_account) => map_errors_to_responses(error_adding_account)
+        }
+    }
+
+    pub async fn remove_commerce_account(&self, commerce_id: i64, account_id: i64,
+                                                       calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.remove_commerce_account_use_case.process(commerce_id, account_id).await {
+            Ok(commerce) => Ok((Status::Ok, Json::from(commerce))),
+            Err(error_removing_account) => map_errors_to_responses(error_removing_account)
+        }
+    }
+
+    pub async fn promote_commerce_account(&self, commerce_id: i64, account_id: i64,
+                                                        calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.promote_commerce_account_use_case.process(commerce_id, account_id).await {
+            Ok(commerce) => Ok((Status::Ok, Json::from(commerce))),
+            Err(error_promoting_account) => map_errors_to_responses(error_promoting_account)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+use application::use_case::commerces::create_commerce_branch_use_case::CreateCommerceBranchUseCase;
+use application::use_case::commerces::list_commerce_branches_use_case::ListCommerceBranchesUseCase;
+use domain::model::commerce::Commerce;
+use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::commerces::dto::commerce_dto::CommerceDto;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::calling_bank::CallingBank;
+
+pub struct CommerceBranchController<BC, LC, BA>
+where
+    BC: CreateCommerceBranchUseCase,
+    LC: ListCommerceBranchesUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    create_commerce_branch_use_case: Arc<BC>,
+    list_commerce_branches_use_case: Arc<LC>,
+    authorize_bank_commerce_access_use_case: Arc<BA>
+}
+
+impl<BC, LC, BA> CommerceBranchController<BC, LC, BA>
+where
+    BC: CreateCommerceBranchUseCase,
+    LC: ListCommerceBranchesUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    pub fn new(create_commerce_branch_use_case: Arc<BC>,
+               list_commerce_branches_use_case: Arc<LC>,
+               authorize_bank_commerce_access_use_case: Arc<BA>) -> Self {
+        Self {
+            create_commerce_branch_use_case,
+            list_commerce_branches_use_case,
+            authorize_bank_commerce_access_use_case
+        }
+    }
+
+    pub async fn create_commerce_branch(&self, parent_commerce_id: i64, commerce_dto: CommerceDto,
+                                        calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        let branch = commerce_dto.to_branch_domain().map_err(|commerce_error| {
+            let error_response = GenericResponse::new(
+                String::from(commerce_error.get_code()),
+                String::from("ERROR"),
+                String::from(commerce_error.get_message())
+            );
+            (Status::BadRequest, Json(error_response))
+        })?;
+        // Both the parent and the branch settlement account must belong to the calling bank
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, parent_commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .authorize_account(&calling_bank.0, &branch.account) {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.create_commerce_branch_use_case.process(parent_commerce_id, branch).await {
+            Ok(branch_created) => Ok((Status::Created, Json::from(branch_created))),
+            Err(error_creating_branch) => map_errors_to_responses(error_creating_branch)
+        }
+    }
+
+    pub async fn list_commerce_branches(&self, parent_commerce_id: i64)
+        -> Result<(Status, Json<Vec<Commerce>>), (Status, Json<GenericResponse>)> {
+        match self.list_commerce_branches_use_case.process(parent_commerce_id).await {
+            Ok(branches) => Ok((Status::Ok, Json::from(branches))),
+            Err(error_listing_branches) => map_errors_to_responses(error_listing_branches)
+        }
+    }
+}
\ No newline at end of file
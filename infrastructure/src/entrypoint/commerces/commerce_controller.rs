@@ -1,26 +1,78 @@
-use std::error::Error;
 use rocket::{http::Status, serde::json::Json};
 use std::sync::Arc;
+use application::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+use application::use_case::commerces::change_commerce_account_use_case::ChangeCommerceAccountUseCase;
+use application::use_case::commerces::change_commerce_status_use_case::ChangeCommerceStatusUseCase;
 use application::use_case::commerces::create_commerce_use_case::CreateCommerceUseCase;
-use domain::exception::bank_error::BankError;
-use domain::exception::commerce_error::CommerceError;
-use domain::exception::database_error::DatabaseError;
+use application::use_case::commerces::get_commerce_status_history_use_case::GetCommerceStatusHistoryUseCase;
+use application::use_case::commerces::get_commerce_use_case::GetCommerceUseCase;
+use application::use_case::commerces::list_commerces_use_case::ListCommercesUseCase;
+use application::use_case::commerces::update_commerce_use_case::UpdateCommerceUseCase;
 use domain::model::commerce::Commerce;
+use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_status_history::CommerceStatusHistory;
 use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::commerces::dto::account_dto::AccountDto;
 use crate::entrypoint::commerces::dto::commerce_dto::CommerceDto;
+use crate::entrypoint::commerces::dto::commerce_filter_dto::CommerceFilterDto;
+use crate::entrypoint::commerces::dto::commerce_list_dto::CommerceListDto;
+use crate::entrypoint::commerces::dto::commerce_status_change_dto::CommerceStatusChangeDto;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::acting_client::ActingClient;
+use crate::entrypoint::guards::calling_bank::CallingBank;
 
-pub struct CommerceController<CC: CreateCommerceUseCase> {
-    create_commerce_use_case: Arc<CC>
+pub struct CommerceController<CC, GC, LC, UC, SC, HC, AC, BA>
+where
+    CC: CreateCommerceUseCase,
+    GC: GetCommerceUseCase,
+    LC: ListCommercesUseCase,
+    UC: UpdateCommerceUseCase,
+    SC: ChangeCommerceStatusUseCase,
+    HC: GetCommerceStatusHistoryUseCase,
+    AC: ChangeCommerceAccountUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    create_commerce_use_case: Arc<CC>,
+    get_commerce_use_case: Arc<GC>,
+    list_commerces_use_case: Arc<LC>,
+    update_commerce_use_case: Arc<UC>,
+    change_commerce_status_use_case: Arc<SC>,
+    get_commerce_status_history_use_case: Arc<HC>,
+    change_commerce_account_use_case: Arc<AC>,
+    authorize_bank_commerce_access_use_case: Arc<BA>
 }
 
-impl<CC: CreateCommerceUseCase> CommerceController<CC> {
-    pub fn new(create_commerce_use_case: Arc<CC>) -> Self {
+impl<CC, GC, LC, UC, SC, HC, AC, BA> CommerceController<CC, GC, LC, UC, SC, HC, AC, BA>
+where
+    CC: CreateCommerceUseCase,
+    GC: GetCommerceUseCase,
+    LC: ListCommercesUseCase,
+    UC: UpdateCommerceUseCase,
+    SC: ChangeCommerceStatusUseCase,
+    HC: GetCommerceStatusHistoryUseCase,
+    AC: ChangeCommerceAccountUseCase,
+    BA: AuthorizeBankCommerceAccessUseCase
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(create_commerce_use_case: Arc<CC>, get_commerce_use_case: Arc<GC>,
+               list_commerces_use_case: Arc<LC>, update_commerce_use_case: Arc<UC>,
+               change_commerce_status_use_case: Arc<SC>,
+               get_commerce_status_history_use_case: Arc<HC>,
+               change_commerce_account_use_case: Arc<AC>,
+               authorize_bank_commerce_access_use_case: Arc<BA>) -> Self {
         Self {
-            create_commerce_use_case
+            create_commerce_use_case,
+            get_commerce_use_case,
+            list_commerces_use_case,
+            update_commerce_use_case,
+            change_commerce_status_use_case,
+            get_commerce_status_history_use_case,
+            change_commerce_account_use_case,
+            authorize_bank_commerce_access_use_case
         }
     }
 
-    pub async fn create_commerce(&self, commerce_dto: CommerceDto)
+    pub async fn create_commerce(&self, commerce_dto: CommerceDto, calling_bank: CallingBank)
         -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
         let commerce = commerce_dto.to_domain().map_err(|commerce_error| {
             let error_response = GenericResponse::new(
@@ -30,6 +82,10 @@ impl<CC: CreateCommerceUseCase> CommerceController<CC> {
             );
             (Status::BadRequest, Json(error_response))
         })?;
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .authorize_account(&calling_bank.0, &commerce.account) {
+            return map_errors_to_responses(error_authorizing);
+        }
         match self.create_commerce_use_case.process(commerce).await {
             Ok(inserted_commerce) => Ok((Status::Created, Json::from(inserted_commerce))),
             Err(error_storing_commerce) => map_errors_to_responses(
@@ -37,35 +93,110 @@ impl<CC: CreateCommerceUseCase> CommerceController<CC> {
         }
     }
 
+    pub async fn get_commerce(&self, commerce_id: i64)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        match self.get_commerce_use_case.process(commerce_id).await {
+            Ok(commerce) => Ok((Status::Ok, Json::from(commerce))),
+            Err(error_getting_commerce) => map_errors_to_responses(error_getting_commerce)
+        }
+    }
 
-}
+    pub async fn list_commerces(&self, commerce_filter_dto: CommerceFilterDto)
+        -> Result<(Status, Json<CommerceListDto>), (Status, Json<GenericResponse>)> {
+        match self.list_commerces_use_case.process(commerce_filter_dto.to_domain(),
+                                                   commerce_filter_dto.cursor,
+                                                   commerce_filter_dto.limit).await {
+            Ok(commerce_page) => Ok((Status::Ok, Json::from(CommerceListDto::from_domain(commerce_page)))),
+            Err(error_listing_commerces) => map_errors_to_responses(error_listing_commerces)
+        }
+    }
 
+    pub async fn update_commerce(&self, commerce_id: i64, commerce_dto: CommerceDto,
+                                 calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        let commerce_update = commerce_dto.to_update_domain().map_err(|commerce_error| {
+            let error_response = GenericResponse::new(
+                String::from(commerce_error.get_code()),
+                String::from("ERROR"),
+                String::from(commerce_error.get_message())
+            );
+            (Status::BadRequest, Json(error_response))
+        })?;
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.update_commerce_use_case.process(commerce_id, commerce_update).await {
+            Ok(updated_commerce) => Ok((Status::Ok, Json::from(updated_commerce))),
+            Err(error_updating_commerce) => map_errors_to_responses(error_updating_commerce)
+        }
+    }
 
-fn map_errors_to_responses(error_storing_commerce: Box<dyn Error + Send + Sync>) -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
-    if let Some(commerce_error) = error_storing_commerce.downcast_ref::<CommerceError>() {
-        Err((Status::BadRequest, Json(GenericResponse::new(
-            String::from(commerce_error.get_code()),
-            String::from("ERROR"),
-            String::from(commerce_error.get_message())
-        ))))
-    } else if let Some(bank_error) = error_storing_commerce.downcast_ref::<BankError>() {
-        Err((Status::BadRequest, Json(GenericResponse::new(
-            String::from(bank_error.get_code()),
-            String::from("ERROR"),
-            String::from(bank_error.get_message())
-        ))))
-    } else if let Some(db_error) = error_storing_commerce.downcast_ref::<DatabaseError>() {
-        Err((Status::ServiceUnavailable, Json(GenericResponse::new(
-            String::from("ERR-UNKNOWN"),
-            String::from("ERROR"),
-            String::from(db_error.get_message())
-        ))))
-    } else {
-        // Unknown error type
-        Err((Status::InternalServerError, Json(GenericResponse::new(
-            String::from("INTERNAL-ERROR"),
-            String::from("ERROR"),
-            String::from("An unexpected error occurred")
-        ))))
+    pub async fn change_commerce_account(&self, commerce_id: i64, account_dto: AccountDto,
+                                         calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        let account = account_dto.to_domain().map_err(|commerce_error| {
+            let error_response = GenericResponse::new(
+                String::from(commerce_error.get_code()),
+                String::from("ERROR"),
+                String::from(commerce_error.get_message())
+            );
+            (Status::BadRequest, Json(error_response))
+        })?;
+        // The commerce and the account it moves to must both belong to the calling bank
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .authorize_account(&calling_bank.0, &account) {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.change_commerce_account_use_case.process(commerce_id, account).await {
+            Ok(updated_commerce) => Ok((Status::Ok, Json::from(updated_commerce))),
+            Err(error_changing_account) => map_errors_to_responses(error_changing_account)
+        }
+    }
+
+    pub async fn deactivate_commerce(&self, commerce_id: i64,
+                                     commerce_status_change_dto: CommerceStatusChangeDto,
+                                     acting_client: ActingClient, calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        self.change_commerce_status(commerce_id, CommerceStatus::Inactive,
+                                    commerce_status_change_dto, acting_client, calling_bank).await
+    }
+
+    pub async fn reactivate_commerce(&self, commerce_id: i64,
+                                     commerce_status_change_dto: CommerceStatusChangeDto,
+                                     acting_client: ActingClient, calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        self.change_commerce_status(commerce_id, CommerceStatus::Active,
+                                    commerce_status_change_dto, acting_client, calling_bank).await
+    }
+
+    async fn change_commerce_status(&self, commerce_id: i64, new_status: CommerceStatus,
+                                    commerce_status_change_dto: CommerceStatusChangeDto,
+                                    acting_client: ActingClient, calling_bank: CallingBank)
+        -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .process(&calling_bank.0, commerce_id).await {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.change_commerce_status_use_case
+            .process(commerce_id, new_status, commerce_status_change_dto.reason.unwrap_or_default(),
+                     acting_client.0)
+            .await {
+            Ok(commerce) => Ok((Status::Ok, Json::from(commerce))),
+            Err(error_changing_status) => map_errors_to_responses(error_changing_status)
+        }
     }
-}
\ No newline at end of file
+
+    pub async fn get_commerce_status_history(&self, commerce_id: i64)
+        -> Result<(Status, Json<Vec<CommerceStatusHistory>>), (Status, Json<GenericResponse>)> {
+        match self.get_commerce_status_history_use_case.process(commerce_id).await {
+            Ok(commerce_status_history) => Ok((Status::Ok, Json::from(commerce_status_history))),
+            Err(error_getting_history) => map_errors_to_responses(error_getting_history)
+        }
+    }
+
+}
@@ -1,5 +1,8 @@
 use std::fmt;
 use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use tracing::info;
+use domain::exception::commerce_error::CommerceError;
+use domain::model::account::Account;
 
 #[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
 pub struct AccountDto {
@@ -17,6 +20,29 @@ impl AccountDto {
             bank_code,
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommerceError> {
+        info!("Validating account_number'{}'", self.account_number);
+        if self.account_number.trim().is_empty() {
+            return Err(CommerceError::not_valid_account_format());
+        }
+        info!("Validating bank_code'{}'", self.bank_code);
+        if self.bank_code.trim().is_empty() {
+            return Err(CommerceError::bank_code_is_empty_or_null());
+        }
+        Ok(())
+    }
+
+    // Convert to domain model after validation, ids are assigned when the account is stored
+    pub fn to_domain(&self) -> Result<Account, CommerceError> {
+        self.validate()?;
+        Ok(Account::new(
+            0,
+            self.account_number.clone(),
+            self.bank_code.clone(),
+            0
+        ))
+    }
 }
 
 impl fmt::Display for AccountDto {
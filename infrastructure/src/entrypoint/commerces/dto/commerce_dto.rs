@@ -4,6 +4,7 @@ use domain::exception::commerce_error::CommerceError;
 use domain::model::account::Account;
 use domain::model::commerce::Commerce;
 use domain::model::commerce_status::CommerceStatus;
+use domain::model::commerce_update::CommerceUpdate;
 use crate::entrypoint::commerces::dto::account_dto::AccountDto;
 
 #[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
@@ -49,23 +50,23 @@ impl CommerceDto {
     fn validate(&self) -> Result<(), CommerceError> {
         // Validate required fields
         let commerce_alias_type = self.alias_type
-            .ok_or_else(|| CommerceError::not_valid_alias_type())?;
+            .ok_or_else(CommerceError::not_valid_alias_type)?;
 
         let alias = self.alias_value
             .as_ref()
-            .ok_or_else(|| CommerceError::not_valid_alias_format())?;
+            .ok_or_else(CommerceError::not_valid_alias_format)?;
 
         let commerce_legal_business_name = self.legal_business_name
             .as_ref()
-            .ok_or_else(|| CommerceError::not_valid_alias_format())?;
+            .ok_or_else(CommerceError::not_valid_alias_format)?;
 
         let account = self.commerce_bank_account
             .as_ref()
-            .ok_or_else(|| CommerceError::not_valid_account_format())?;
+            .ok_or_else(CommerceError::not_valid_account_format)?;
 
         let commerce_ruc = self.ruc
             .as_ref()
-            .ok_or_else(|| CommerceError::not_valid_ruc())?;
+            .ok_or_else(CommerceError::not_valid_ruc)?;
 
         // Now validate the values themselves
         info!("Validating commerce alias type: {}", commerce_alias_type);
@@ -75,7 +76,7 @@ impl CommerceDto {
         info!("Validating commerce legal business name: {}", alias);
         validate_null_string_value(commerce_legal_business_name, CommerceError::not_valid_legal_business)?;
         info!("Validating account_dto: {}", account);
-        validate_account(account)?;
+        account.validate()?;
         info!("Validating commerce ruc value: {}", commerce_ruc);
         validate_null_string_value(commerce_ruc, CommerceError::not_valid_ruc)?;
 
@@ -95,7 +96,55 @@ impl CommerceDto {
             self.legal_business_name.unwrap(),
             account_dto_to_domain(&self.commerce_bank_account.unwrap()),
             self.ruc.unwrap(),
-            CommerceStatus::new(String::from("")),
+            CommerceStatus::Active,
+        ))
+    }
+
+    // Conversion for branches, the RUC and legal business name are taken from the parent commerce
+    pub fn to_branch_domain(self) -> Result<Commerce, CommerceError> {
+        let alias_type = self.alias_type.ok_or_else(CommerceError::not_valid_alias_type)?;
+        validate_long_number(Some(alias_type))?;
+        let alias = self.alias_value.ok_or_else(CommerceError::not_valid_alias_format)?;
+        validate_null_string_value(&alias, CommerceError::not_valid_alias_format)?;
+        let account = self.commerce_bank_account
+            .ok_or_else(CommerceError::not_valid_account_format)?;
+        account.validate()?;
+
+        Ok(Commerce::new(
+            0,
+            alias,
+            alias_type,
+            String::new(),
+            account_dto_to_domain(&account),
+            String::new(),
+            CommerceStatus::Active,
+        ))
+    }
+
+    // Partial conversion for updates, only the fields sent are validated. The settlement account
+    // is not part of a commerce update, it is changed through PUT /commerces/<id>/account
+    pub fn to_update_domain(self) -> Result<CommerceUpdate, CommerceError> {
+        if self.commerce_bank_account.is_some() {
+            return Err(CommerceError::account_cannot_be_updated());
+        }
+        if let Some(alias_type) = self.alias_type {
+            validate_long_number(Some(alias_type))?;
+        }
+        if let Some(alias) = &self.alias_value {
+            validate_null_string_value(alias, CommerceError::not_valid_alias_format)?;
+        }
+        if let Some(legal_business_name) = &self.legal_business_name {
+            validate_null_string_value(legal_business_name, CommerceError::not_valid_legal_business)?;
+        }
+        if let Some(ruc) = &self.ruc {
+            validate_null_string_value(ruc, CommerceError::not_valid_ruc)?;
+        }
+
+        Ok(CommerceUpdate::new(
+            self.alias_value,
+            self.alias_type,
+            self.legal_business_name,
+            self.ruc,
         ))
     }
 }
@@ -103,22 +152,10 @@ impl CommerceDto {
 fn validate_long_number(number: Option<i64>) -> Result<(), CommerceError> {
     number.filter(|&id| id > 0)
         .map(|_| ())
-        .ok_or_else(|| CommerceError::not_valid_alias_type())
-}
-
-fn validate_account(account: &AccountDto) -> Result<(), CommerceError> {
-    // Validate account number
-    info!("Validating account_number'{}'", account.account_number);
-    validate_null_string_value(&account.account_number, CommerceError::not_valid_account_format)?;
-
-    // Validate bank code
-    info!("Validating bank_code'{}'", account.bank_code);
-    validate_null_string_value(&account.bank_code, CommerceError::bank_code_is_empty_or_null)?;
-
-    Ok(())
+        .ok_or_else(CommerceError::not_valid_alias_type)
 }
 
-fn validate_null_string_value<F>(value: &String, error_fn: F) -> Result<(), CommerceError>
+fn validate_null_string_value<F>(value: &str, error_fn: F) -> Result<(), CommerceError>
 where
     F: FnOnce() -> CommerceError
 {
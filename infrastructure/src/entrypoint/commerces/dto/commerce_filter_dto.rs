@@ -0,0 +1,38 @@
+use rocket::FromForm;
+use domain::model::commerce_filter::CommerceFilter;
+
+#[derive(Debug, Clone, FromForm)]
+pub struct CommerceFilterDto {
+    #[field(name = "bankCode")]
+    pub bank_code: Option<String>,
+
+    #[field(name = "status")]
+    pub status: Option<String>,
+
+    #[field(name = "aliasType")]
+    pub alias_type: Option<i64>,
+
+    #[field(name = "rucPrefix")]
+    pub ruc_prefix: Option<String>,
+
+    #[field(name = "legalBusinessName")]
+    pub legal_business_name: Option<String>,
+
+    #[field(name = "cursor")]
+    pub cursor: Option<i64>,
+
+    #[field(name = "limit")]
+    pub limit: Option<i64>,
+}
+
+impl CommerceFilterDto {
+    pub fn to_domain(&self) -> CommerceFilter {
+        CommerceFilter::new(
+            self.bank_code.clone(),
+            self.status.clone(),
+            self.alias_type,
+            self.ruc_prefix.clone(),
+            self.legal_business_name.clone(),
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use domain::model::commerce::Commerce;
+use domain::model::commerce_page::CommercePage;
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct CommerceListDto {
+    #[serde(rename = "commerces")]
+    pub commerces: Vec<Commerce>,
+
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<i64>,
+
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    #[serde(rename = "totalCount")]
+    pub total_count: i64,
+}
+
+impl CommerceListDto {
+    pub fn from_domain(commerce_page: CommercePage) -> Self {
+        Self {
+            count: commerce_page.commerces.len() as i64,
+            commerces: commerce_page.commerces,
+            next_cursor: commerce_page.next_cursor,
+            total_count: commerce_page.total_count,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct CommerceStatusChangeDto {
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+}
+
+impl CommerceStatusChangeDto {
+    pub fn new(reason: Option<String>) -> Self {
+        Self {
+            reason,
+        }
+    }
+}
\ No newline at end of file
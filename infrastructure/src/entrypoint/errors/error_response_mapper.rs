@@ -0,0 +1,74 @@
+use std::error::Error;
+use rocket::{http::Status, serde::json::Json};
+use domain::exception::bank_error::BankError;
+use domain::exception::commerce_error::CommerceError;
+use domain::exception::database_error::DatabaseError;
+use domain::exception::user_error::UserError;
+use domain::model::generic_response::GenericResponse;
+
+pub fn map_errors_to_responses<T>(error: Box<dyn Error + Send + Sync>) -> Result<(Status, Json<T>), (Status, Json<GenericResponse>)> {
+    if let Some(commerce_error) = error.downcast_ref::<CommerceError>() {
+        Err((map_commerce_error_to_status(commerce_error), Json(GenericResponse::new(
+            String::from(commerce_error.get_code()),
+            String::from("ERROR"),
+            String::from(commerce_error.get_message())
+        ))))
+    } else if let Some(bank_error) = error.downcast_ref::<BankError>() {
+        Err((map_bank_error_to_status(bank_error), Json(GenericResponse::new(
+            String::from(bank_error.get_code()),
+            String::from("ERROR"),
+            String::from(bank_error.get_message())
+        ))))
+    } else if let Some(user_error) = error.downcast_ref::<UserError>() {
+        Err((map_user_error_to_status(user_error), Json(GenericResponse::new(
+            String::from(user_error.get_code()),
+            String::from("ERROR"),
+            String::from(user_error.get_message())
+        ))))
+    } else if let Some(db_error) = error.downcast_ref::<DatabaseError>() {
+        Err((Status::ServiceUnavailable, Json(GenericResponse::new(
+            String::from("ERR-UNKNOWN"),
+            String::from("ERROR"),
+            db_error.get_message()
+        ))))
+    } else {
+        // Unknown error type
+        Err((Status::InternalServerError, Json(GenericResponse::new(
+            String::from("INTERNAL-ERROR"),
+            String::from("ERROR"),
+            String::from("An unexpected error occurred")
+        ))))
+    }
+}
+
+fn map_commerce_error_to_status(commerce_error: &CommerceError) -> Status {
+    match commerce_error {
+        CommerceError::NotFound(_) | CommerceError::AccountNotFound(_) => Status::NotFound,
+        CommerceError::AliasAlreadyExists(_) => Status::Conflict,
+        CommerceError::StatusTransitionNotAllowed(_) => Status::Conflict,
+        CommerceError::PrimaryAccountCannotBeRemoved(_) => Status::Conflict,
+        CommerceError::AccountsLockedWhileSuspended(_) => Status::Conflict,
+        CommerceError::BranchCannotHaveBranches(_) => Status::Conflict,
+        _ => Status::BadRequest
+    }
+}
+
+fn map_bank_error_to_status(bank_error: &BankError) -> Status {
+    match bank_error {
+        BankError::NotFound(_) => Status::NotFound,
+        BankError::BankCodeAlreadyExists(_) => Status::Conflict,
+        BankError::StatusTransitionNotAllowed(_) => Status::Conflict,
+        BankError::CertificateAlreadyRegistered(_) => Status::Conflict,
+        BankError::CommerceBelongsToAnotherBank(_) => Status::Forbidden,
+        BankError::AccessToAnotherBank(_) => Status::Forbidden,
+        _ => Status::BadRequest
+    }
+}
+
+fn map_user_error_to_status(user_error: &UserError) -> Status {
+    match user_error {
+        UserError::CreditorNotFound(_) | UserError::DebtorNotFound(_) => Status::NotFound,
+        UserError::AliasAlreadyExists(_) => Status::Conflict,
+        _ => Status::BadRequest
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use rocket::request::{FromRequest, Outcome, Request};
+
+const CLIENT_ID_HEADER: &str = "X-Client-Id";
+const ANONYMOUS_CLIENT: &str = "anonymous";
+
+// Identifies the client performing a request, used for audit purposes
+pub struct ActingClient(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ActingClient {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let client_id = request.headers()
+            .get_one(CLIENT_ID_HEADER)
+            .map(str::trim)
+            .filter(|client_id| !client_id.is_empty())
+            .unwrap_or(ANONYMOUS_CLIENT);
+        Outcome::Success(ActingClient(String::from(client_id)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use tracing::{error, warn};
+
+const API_KEY_HEADER: &str = "X-Api-Key";
+
+// API credentials issued to the participant banks, keyed by API key
+pub struct BankApiCredentials {
+    bank_codes_by_api_key: HashMap<String, String>
+}
+
+impl BankApiCredentials {
+    pub fn new(bank_codes_by_api_key: HashMap<String, String>) -> Self {
+        Self { bank_codes_by_api_key }
+    }
+
+    // Reads entries in the form "api_key:bank_code" separated by commas
+    pub fn from_config(credentials: &str) -> Self {
+        let bank_codes_by_api_key = credentials.split(',')
+            .filter_map(|entry| {
+                let (api_key, bank_code) = entry.split_once(':')?;
+                let (api_key, bank_code) = (api_key.trim(), bank_code.trim());
+                if api_key.is_empty() || bank_code.is_empty() {
+                    warn!("Ignoring malformed bank API credential entry");
+                    return None;
+                }
+                Some((String::from(api_key), String::from(bank_code)))
+            })
+            .collect();
+        Self::new(bank_codes_by_api_key)
+    }
+
+    pub fn find_bank_code(&self, api_key: &str) -> Option<&String> {
+        self.bank_codes_by_api_key.get(api_key)
+    }
+}
+
+// Participant bank performing a request, commerce writes are limited to its own commerces
+pub struct CallingBank(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for CallingBank {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(credentials) = request.rocket().state::<BankApiCredentials>() else {
+            error!("Bank API credentials are not configured");
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+        let bank_code = request.headers()
+            .get_one(API_KEY_HEADER)
+            .map(str::trim)
+            .and_then(|api_key| credentials.find_bank_code(api_key));
+        match bank_code {
+            Some(bank_code) => Outcome::Success(CallingBank(bank_code.clone())),
+            None => {
+                warn!("Request to {} without a valid bank API key", request.uri());
+                Outcome::Error((Status::Unauthorized, ()))
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use tracing::{error, warn};
+
+const OPERATOR_KEY_HEADER: &str = "X-Operator-Key";
+
+// API credentials issued to the directory operators, keyed by API key
+pub struct OperatorApiCredentials {
+    operators_by_api_key: HashMap<String, String>
+}
+
+impl OperatorApiCredentials {
+    pub fn new(operators_by_api_key: HashMap<String, String>) -> Self {
+        Self { operators_by_api_key }
+    }
+
+    // Reads entries in the form "api_key:operator" separated by commas
+    pub fn from_config(credentials: &str) -> Self {
+        let operators_by_api_key = credentials.split(',')
+            .filter_map(|entry| {
+                let (api_key, operator) = entry.split_once(':')?;
+                let (api_key, operator) = (api_key.trim(), operator.trim());
+                if api_key.is_empty() || operator.is_empty() {
+                    warn!("Ignoring malformed operator API credential entry");
+                    return None;
+                }
+                Some((String::from(api_key), String::from(operator)))
+            })
+            .collect();
+        Self::new(operators_by_api_key)
+    }
+
+    pub fn find_operator(&self, api_key: &str) -> Option<&String> {
+        self.operators_by_api_key.get(api_key)
+    }
+}
+
+// Directory operator performing a request, the bank registry is only managed by operators
+pub struct Operator(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Operator {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(credentials) = request.rocket().state::<OperatorApiCredentials>() else {
+            error!("Operator API credentials are not configured");
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+        let operator = request.headers()
+            .get_one(OPERATOR_KEY_HEADER)
+            .map(str::trim)
+            .and_then(|api_key| credentials.find_operator(api_key));
+        match operator {
+            Some(operator) => Outcome::Success(Operator(operator.clone())),
+            None => {
+                warn!("Request to {} without a valid operator API key", request.uri());
+                Outcome::Error((Status::Unauthorized, ()))
+            }
+        }
+    }
+}
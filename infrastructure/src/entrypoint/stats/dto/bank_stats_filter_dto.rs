@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use rocket::FromForm;
+use domain::exception::bank_error::BankError;
+use domain::model::bank_stats_filter::BankStatsFilter;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, FromForm)]
+pub struct BankStatsFilterDto {
+    // Creation dates in YYYY-MM-DD, both inclusive
+    #[field(name = "from")]
+    pub from: Option<String>,
+
+    #[field(name = "to")]
+    pub to: Option<String>,
+
+    // "csv" or "json", JSON when missing
+    #[field(name = "format")]
+    pub format: Option<String>,
+}
+
+impl BankStatsFilterDto {
+    pub fn to_domain(&self) -> Result<BankStatsFilter, BankError> {
+        Ok(BankStatsFilter::new(parse_date(&self.from)?, parse_date(&self.to)?))
+    }
+
+    pub fn is_csv(&self) -> bool {
+        self.format
+            .as_deref()
+            .is_some_and(|format| format.trim().eq_ignore_ascii_case("csv"))
+    }
+}
+
+fn parse_date(date: &Option<String>) -> Result<Option<NaiveDate>, BankError> {
+    match date.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(date) => NaiveDate::parse_from_str(date, DATE_FORMAT)
+            .map(Some)
+            .map_err(|_| BankError::not_valid_stats_date_range())
+    }
+}
\ No newline at end of file
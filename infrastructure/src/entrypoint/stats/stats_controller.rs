@@ -0,0 +1,77 @@
+use rocket::http::{ContentType, Status};
+use rocket::serde::json::Json;
+use rocket::Responder;
+use std::sync::Arc;
+use application::use_case::stats::get_bank_stats_use_case::GetBankStatsUseCase;
+use domain::model::bank_commerce_stats::BankCommerceStats;
+use domain::model::generic_response::GenericResponse;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::stats::dto::bank_stats_filter_dto::BankStatsFilterDto;
+
+const BANK_STATS_CSV_HEADER: &str =
+    "bank_code,bank_name,commerce_status,alias_type,registration_month,commerces";
+
+#[derive(Responder)]
+pub enum BankStatsResponse {
+    Json(Json<Vec<BankCommerceStats>>),
+    Csv((ContentType, String)),
+}
+
+pub struct StatsController<GS>
+where
+    GS: GetBankStatsUseCase
+{
+    get_bank_stats_use_case: Arc<GS>
+}
+
+impl<GS> StatsController<GS>
+where
+    GS: GetBankStatsUseCase
+{
+    pub fn new(get_bank_stats_use_case: Arc<GS>) -> Self {
+        Self {
+            get_bank_stats_use_case
+        }
+    }
+
+    pub async fn get_bank_stats(&self, filter_dto: BankStatsFilterDto)
+        -> Result<(Status, BankStatsResponse), (Status, Json<GenericResponse>)> {
+        let filter = filter_dto.to_domain().map_err(|bank_error| {
+            let error_response = GenericResponse::new(
+                String::from(bank_error.get_code()),
+                String::from("ERROR"),
+                String::from(bank_error.get_message())
+            );
+            (Status::BadRequest, Json(error_response))
+        })?;
+        match self.get_bank_stats_use_case.process(filter).await {
+            Ok(stats) if filter_dto.is_csv() =>
+                Ok((Status::Ok, BankStatsResponse::Csv((ContentType::CSV, to_csv(&stats))))),
+            Ok(stats) => Ok((Status::Ok, BankStatsResponse::Json(Json::from(stats)))),
+            Err(error_getting_stats) => map_errors_to_responses(error_getting_stats)
+                .map(|(status, stats)| (status, BankStatsResponse::Json(stats)))
+        }
+    }
+}
+
+fn to_csv(stats: &[BankCommerceStats]) -> String {
+    let mut csv = String::from(BANK_STATS_CSV_HEADER);
+    for row in stats {
+        csv.push_str(&format!("\r\n{},{},{},{},{},{}", csv_field(&row.bank_code),
+                              csv_field(&row.bank_name), csv_field(&row.commerce_status),
+                              csv_field(&row.alias_type),
+                              csv_field(row.registration_month.as_deref().unwrap_or_default()),
+                              row.commerces));
+    }
+    csv.push_str("\r\n");
+    csv
+}
+
+// Quotes the field when it contains a separator, a quote or a line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
\ No newline at end of file
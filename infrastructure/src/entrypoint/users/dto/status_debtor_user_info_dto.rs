@@ -0,0 +1,26 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use domain::exception::user_error::UserError;
+use domain::model::status_debtor_user_info::StatusDebtorUserInfo;
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct StatusDebtorUserInfoDto {
+    #[serde(rename = "aliasValue")]
+    pub alias_value: Option<String>,
+
+    // Optional, the calling bank is used and a different bank code is rejected
+    #[serde(rename = "bankCode")]
+    pub bank_code: Option<String>,
+
+    #[serde(rename = "countryCode")]
+    pub country_code: Option<i32>,
+}
+
+impl StatusDebtorUserInfoDto {
+    // Only missing fields are rejected here, formats are checked by the use case
+    pub fn to_domain(self) -> Result<StatusDebtorUserInfo, UserError> {
+        let alias_value = self.alias_value.ok_or_else(UserError::debtor_null)?;
+        let bank_code = self.bank_code.ok_or_else(UserError::bank_code_null_or_empty_for_debtor)?;
+        let country_code = self.country_code.ok_or_else(UserError::debtor_invalid_country_code)?;
+        Ok(StatusDebtorUserInfo::new(alias_value, bank_code, country_code))
+    }
+}
\ No newline at end of file
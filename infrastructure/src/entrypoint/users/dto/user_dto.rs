@@ -0,0 +1,47 @@
+use rocket::serde::{Deserialize as RocketDeserialize, Serialize as RocketSerialize};
+use domain::exception::user_error::UserError;
+use domain::model::account::Account;
+use domain::model::user::User;
+use domain::model::user_status::UserStatus;
+use crate::entrypoint::commerces::dto::account_dto::AccountDto;
+
+#[derive(Debug, Clone, RocketSerialize, RocketDeserialize)]
+pub struct UserDto {
+    #[serde(rename = "aliasValue")]
+    pub alias_value: Option<String>,
+
+    #[serde(rename = "aliasType")]
+    pub alias_type: Option<i64>,
+
+    #[serde(rename = "countryCode")]
+    pub country_code: Option<i32>,
+
+    #[serde(rename = "userBankAccount")]
+    pub user_bank_account: Option<AccountDto>,
+}
+
+impl UserDto {
+    // Convert to domain model, formats are checked by the validation use case
+    pub fn to_domain(self) -> Result<User, UserError> {
+        let alias_type = self.alias_type.ok_or_else(UserError::creditor_invalid_alias_type)?;
+        let alias = self.alias_value
+            .filter(|alias| !alias.trim().is_empty())
+            .ok_or_else(UserError::creditor_invalid_alias)?;
+        let country = self.country_code.ok_or_else(UserError::creditor_invalid_country_code)?;
+        let account = self.user_bank_account
+            .filter(|account| !account.account_number.trim().is_empty())
+            .ok_or_else(UserError::creditor_null)?;
+        if account.bank_code.trim().is_empty() {
+            return Err(UserError::bank_code_error_value());
+        }
+
+        Ok(User::new(
+            0,
+            alias_type,
+            alias,
+            country,
+            Account::new(0, account.account_number, account.bank_code, 0),
+            UserStatus::active(),
+        ))
+    }
+}
\ No newline at end of file
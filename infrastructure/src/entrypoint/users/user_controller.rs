@@ -0,0 +1,86 @@
+use rocket::{http::Status, serde::json::Json};
+use std::sync::Arc;
+use application::use_case::commerces::authorize_bank_commerce_access_use_case::AuthorizeBankCommerceAccessUseCase;
+use application::use_case::users::check_debtor_status_use_case::CheckDebtorStatusUseCase;
+use application::use_case::users::create_user_use_case::CreateUserUseCase;
+use domain::exception::bank_error::BankError;
+use domain::exception::user_error::UserError;
+use domain::model::debtor_user_info::DebtorUserInfo;
+use domain::model::generic_response::GenericResponse;
+use domain::model::user::User;
+use crate::entrypoint::errors::error_response_mapper::map_errors_to_responses;
+use crate::entrypoint::guards::calling_bank::CallingBank;
+use crate::entrypoint::users::dto::status_debtor_user_info_dto::StatusDebtorUserInfoDto;
+use crate::entrypoint::users::dto::user_dto::UserDto;
+
+pub struct UserController<CU, CD, AB>
+where
+    CU: CreateUserUseCase,
+    CD: CheckDebtorStatusUseCase,
+    AB: AuthorizeBankCommerceAccessUseCase
+{
+    create_user_use_case: Arc<CU>,
+    check_debtor_status_use_case: Arc<CD>,
+    authorize_bank_commerce_access_use_case: Arc<AB>
+}
+
+impl<CU, CD, AB> UserController<CU, CD, AB>
+where
+    CU: CreateUserUseCase,
+    CD: CheckDebtorStatusUseCase,
+    AB: AuthorizeBankCommerceAccessUseCase
+{
+    pub fn new(create_user_use_case: Arc<CU>, check_debtor_status_use_case: Arc<CD>,
+               authorize_bank_commerce_access_use_case: Arc<AB>) -> Self {
+        Self {
+            create_user_use_case,
+            check_debtor_status_use_case,
+            authorize_bank_commerce_access_use_case
+        }
+    }
+
+    pub async fn create_user(&self, user_dto: UserDto, calling_bank: CallingBank)
+        -> Result<(Status, Json<User>), (Status, Json<GenericResponse>)> {
+        let user = user_dto.to_domain().map_err(map_user_dto_error)?;
+        // A bank can only register users whose account it holds
+        if let Err(error_authorizing) = self.authorize_bank_commerce_access_use_case
+            .authorize_account(&calling_bank.0, &user.account) {
+            return map_errors_to_responses(error_authorizing);
+        }
+        match self.create_user_use_case.process(user).await {
+            Ok(inserted_user) => Ok((Status::Created, Json::from(inserted_user))),
+            Err(error_storing_user) => map_errors_to_responses(error_storing_user)
+        }
+    }
+
+    // Only the bank holding the debtor's account can check it, so the debtor is always looked up
+    // under the calling bank
+    pub async fn check_debtor_status(&self, status_debtor_dto: StatusDebtorUserInfoDto,
+                                     calling_bank: CallingBank)
+        -> Result<(Status, Json<DebtorUserInfo>), (Status, Json<GenericResponse>)> {
+        if let Some(bank_code) = &status_debtor_dto.bank_code {
+            if bank_code.trim() != calling_bank.0 {
+                return map_errors_to_responses(BankError::commerce_belongs_to_another_bank().into());
+            }
+        }
+        let status_debtor_user_info = StatusDebtorUserInfoDto {
+            bank_code: Some(calling_bank.0),
+            ..status_debtor_dto
+        }
+            .to_domain()
+            .map_err(map_user_dto_error)?;
+        match self.check_debtor_status_use_case.process(status_debtor_user_info).await {
+            Ok(debtor_user_info) => Ok((Status::Ok, Json::from(debtor_user_info))),
+            Err(error_checking_debtor) => map_errors_to_responses(error_checking_debtor)
+        }
+    }
+}
+
+fn map_user_dto_error(user_error: UserError) -> (Status, Json<GenericResponse>) {
+    let error_response = GenericResponse::new(
+        String::from(user_error.get_code()),
+        String::from("ERROR"),
+        String::from(user_error.get_message())
+    );
+    (Status::BadRequest, Json(error_response))
+}
\ No newline at end of file
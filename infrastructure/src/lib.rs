@@ -1,3 +1,19 @@
+pub mod cache {
+    pub mod banks {
+        pub mod cached_bank_repository_adapter;
+
+        #[cfg(test)]
+        pub mod tests {
+            pub mod mocks;
+            pub mod cached_bank_repository_adapter_test;
+        }
+    }
+}
+
+pub mod certificates {
+    pub mod openssl_certificate_parser_adapter;
+}
+
 pub mod db {
     pub mod mysql {
         pub mod banks {
@@ -5,8 +21,10 @@ pub mod db {
                 pub mod bank_repository_adapter;
             }
             pub mod entity {
+                pub mod bank_certificate_entity;
                 pub mod bank_entity;
                 pub mod bank_status_entity;
+                pub mod bank_status_schedule_entity;
             }
             pub mod repository {
                 pub mod bank_repository;
@@ -14,32 +32,130 @@ pub mod db {
         }
         pub mod commerces {
             pub mod adapter {
+                pub mod alias_type_repository_adapter;
                 pub mod commerce_repository_adapter;
             }
             pub mod entity {
                 pub mod wrappers {
+                    pub mod commerce_account_db_info_wrapper;
                     pub mod commerce_db_info_wrapper;
                 }
                 pub mod account_entity;
                 pub mod alias_type_entity;
                 pub mod commerce_entity;
                 pub mod commerce_status_entity;
+                pub mod commerce_status_history_entity;
+                pub mod legal_entity_entity;
             }
             pub mod repository {
                 pub mod account_repository;
+                pub mod alias_type_repository;
                 pub mod commerce_repository;
                 pub mod commerce_status_repository;
+                pub mod commerce_status_history_repository;
+                pub mod legal_entity_repository;
+            }
+        }
+        pub mod countries {
+            pub mod adapter {
+                pub mod country_repository_adapter;
+            }
+            pub mod entity {
+                pub mod country_entity;
+            }
+            pub mod repository {
+                pub mod country_repository;
+            }
+        }
+        pub mod stats {
+            pub mod adapter {
+                pub mod bank_stats_repository_adapter;
+            }
+            pub mod entity {
+                pub mod bank_commerce_stats_entity;
+            }
+            pub mod repository {
+                pub mod bank_stats_repository;
+            }
+        }
+        pub mod users {
+            pub mod adapter {
+                pub mod user_repository_adapter;
+            }
+            pub mod entity {
+                pub mod wrappers {
+                    pub mod user_db_info_wrapper;
+                }
+                pub mod user_entity;
+            }
+            pub mod repository {
+                pub mod user_repository;
             }
         }
     }
 }
 
 pub mod entrypoint {
+    pub mod aliases {
+        pub mod alias_controller;
+    }
+    pub mod banks {
+        pub mod dto {
+            pub mod bank_certificate_upload_dto;
+            pub mod bank_dto;
+            pub mod bank_status_schedule_dto;
+        }
+        pub mod bank_certificate_controller;
+        pub mod bank_controller;
+    }
     pub mod commerces {
         pub mod dto {
             pub mod account_dto;
             pub mod commerce_dto;
+            pub mod commerce_filter_dto;
+            pub mod commerce_list_dto;
+            pub mod commerce_status_change_dto;
         }
+        pub mod commerce_account_controller;
+        pub mod commerce_branch_controller;
         pub mod commerce_controller;
     }
+    pub mod errors {
+        pub mod error_response_mapper;
+    }
+    pub mod guards {
+        pub mod acting_client;
+        pub mod calling_bank;
+        pub mod operator;
+    }
+    pub mod stats {
+        pub mod dto {
+            pub mod bank_stats_filter_dto;
+        }
+        pub mod stats_controller;
+    }
+    pub mod users {
+        pub mod dto {
+            pub mod status_debtor_user_info_dto;
+            pub mod user_dto;
+        }
+        pub mod user_controller;
+    }
+}
+
+pub mod notifications {
+    pub mod log_notification_adapter;
+    pub mod notification_adapter;
+    pub mod smtp_notification_adapter;
+
+    #[cfg(test)]
+    pub mod tests {
+        pub mod smtp_notification_adapter_test;
+    }
+}
+
+pub mod scheduler {
+    pub mod banks {
+        pub mod bank_status_scheduler;
+    }
 }
@@ -0,0 +1,29 @@
+use std::error::Error as StdError;
+use async_trait::async_trait;
+use tracing::info;
+use application::port::notifications::notification_port::NotificationPort;
+use domain::model::notification::Notification;
+
+// Writes notifications to the application log, used when no SMTP server is configured
+pub struct LogNotificationAdapter;
+
+impl LogNotificationAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for LogNotificationAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationPort for LogNotificationAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        info!("Notification to {} with subject '{}':\n{}", notification.recipient,
+            notification.subject, notification.body);
+        Ok(())
+    }
+}
\ No newline at end of file
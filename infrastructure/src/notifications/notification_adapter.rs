@@ -0,0 +1,22 @@
+use std::error::Error as StdError;
+use async_trait::async_trait;
+use application::port::notifications::notification_port::NotificationPort;
+use domain::model::notification::Notification;
+use crate::notifications::log_notification_adapter::LogNotificationAdapter;
+use crate::notifications::smtp_notification_adapter::SmtpNotificationAdapter;
+
+// Notification channel chosen at startup, SMTP when a server is configured
+pub enum NotificationAdapter {
+    Smtp(SmtpNotificationAdapter),
+    Log(LogNotificationAdapter),
+}
+
+#[async_trait]
+impl NotificationPort for NotificationAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        match self {
+            NotificationAdapter::Smtp(adapter) => adapter.send(notification).await,
+            NotificationAdapter::Log(adapter) => adapter.send(notification).await,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use std::error::Error as StdError;
+use std::time::Duration;
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use rocket::tokio::sync::mpsc::error::TrySendError;
+use rocket::tokio::sync::mpsc::{channel, Sender};
+use tracing::{error, info, warn};
+use application::port::notifications::notification_port::NotificationPort;
+use domain::model::notification::Notification;
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(15);
+const NOTIFICATION_QUEUE_CAPACITY: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub starttls: bool,
+}
+
+// Queues notifications and delivers them one by one in the background, so callers never wait
+// for the SMTP server. The queue is bounded, notifications that do not fit are dropped
+pub struct SmtpNotificationAdapter {
+    sender: Sender<Notification>
+}
+
+impl SmtpNotificationAdapter {
+    // Must be called inside the async runtime, the delivery worker is spawned on it
+    pub fn new(smtp_config: SmtpConfig) -> Result<Self, Box<dyn StdError + Send + Sync>> {
+        let from = smtp_config.from.parse::<Mailbox>()?;
+        let transport = build_transport(&smtp_config)?;
+        let (sender, mut receiver) = channel::<Notification>(NOTIFICATION_QUEUE_CAPACITY);
+        rocket::tokio::spawn(async move {
+            while let Some(notification) = receiver.recv().await {
+                match deliver(&transport, &from, &notification).await {
+                    Ok(()) => info!("Notification delivered to {}", notification.recipient),
+                    Err(err) => error!("Notification to {} could not be delivered. Error is: {:?}",
+                                       notification.recipient, err),
+                }
+            }
+        });
+        Ok(Self { sender })
+    }
+}
+
+#[async_trait]
+impl NotificationPort for SmtpNotificationAdapter {
+    async fn send(&self, notification: &Notification) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        enqueue(&self.sender, notification)
+    }
+}
+
+pub(crate) fn enqueue(sender: &Sender<Notification>, notification: &Notification)
+    -> Result<(), Box<dyn StdError + Send + Sync>> {
+    match sender.try_send(notification.clone()) {
+        Ok(()) => Ok(()),
+        Err(TrySendError::Full(_)) => Err(format!(
+            "SMTP delivery queue is full, notification to {} was dropped",
+            notification.recipient).into()),
+        Err(TrySendError::Closed(_)) => Err(Box::from("SMTP delivery worker is not running")),
+    }
+}
+
+// Credentials are only sent once the connection is encrypted
+pub(crate) fn build_transport(config: &SmtpConfig)
+    -> Result<AsyncSmtpTransport<Tokio1Executor>, Box<dyn StdError + Send + Sync>> {
+    if !config.starttls {
+        if config.username.is_some() {
+            warn!("SMTP_STARTTLS is disabled, the SMTP credentials will not be sent and \
+                   authenticated deliveries will fail");
+        }
+        return Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+            .port(config.port)
+            .timeout(Some(SMTP_TIMEOUT))
+            .build());
+    }
+
+    let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+        .port(config.port)
+        .timeout(Some(SMTP_TIMEOUT));
+    if let (Some(username), Some(password)) = (&config.username, &config.password) {
+        builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+    Ok(builder.build())
+}
+
+pub(crate) fn build_message(from: &Mailbox, notification: &Notification)
+    -> Result<Message, Box<dyn StdError + Send + Sync>> {
+    Ok(Message::builder()
+        .from(from.clone())
+        .to(notification.recipient.parse::<Mailbox>()?)
+        .subject(notification.subject.as_str())
+        .header(ContentType::TEXT_PLAIN)
+        .body(notification.body.clone())?)
+}
+
+async fn deliver(transport: &AsyncSmtpTransport<Tokio1Executor>, from: &Mailbox,
+                 notification: &Notification) -> Result<(), Box<dyn StdError + Send + Sync>> {
+    let message = build_message(from, notification)?;
+    transport.send(message).await?;
+    Ok(())
+}
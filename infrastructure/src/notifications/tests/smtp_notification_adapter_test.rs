@@ -0,0 +1,78 @@
+use lettre::message::Mailbox;
+use rocket::tokio::sync::mpsc::channel;
+use domain::model::notification::Notification;
+use crate::notifications::smtp_notification_adapter::{build_message, build_transport, enqueue,
+                                                      SmtpConfig};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_for_test(starttls: bool) -> SmtpConfig {
+        SmtpConfig {
+            host: String::from("smtp.example.com"),
+            port: 587,
+            username: Some(String::from("user")),
+            password: Some(String::from("secret")),
+            from: String::from("alias@example.com"),
+            starttls,
+        }
+    }
+
+    fn notification_for_test() -> Notification {
+        Notification::new(String::from("notifications@boa.com"), String::from("Commerce deactivated"),
+                          String::from("Commerce @aliasTest was deactivated"))
+    }
+
+    fn from_for_test() -> Mailbox {
+        "alias@example.com".parse().unwrap()
+    }
+
+    #[test]
+    fn test_build_message() {
+        let message = build_message(&from_for_test(), &notification_for_test()).unwrap();
+
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("From: alias@example.com\r\n"));
+        assert!(formatted.contains("To: notifications@boa.com\r\n"));
+        assert!(formatted.contains("Subject: Commerce deactivated\r\n"));
+        assert!(formatted.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(formatted.ends_with("Commerce @aliasTest was deactivated"));
+    }
+
+    #[test]
+    fn test_build_message_invalid_recipient() {
+        let mut notification = notification_for_test();
+        notification.recipient = String::from("not-an-email");
+
+        assert!(build_message(&from_for_test(), &notification).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_build_transport() {
+        assert!(build_transport(&config_for_test(true)).is_ok());
+        assert!(build_transport(&config_for_test(false)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_drops_notifications_when_the_queue_is_full() {
+        let (sender, mut receiver) = channel::<Notification>(1);
+
+        assert!(enqueue(&sender, &notification_for_test()).is_ok());
+        let error = enqueue(&sender, &notification_for_test()).unwrap_err();
+
+        assert!(error.to_string().contains("was dropped"));
+        assert_eq!(receiver.recv().await.unwrap(), notification_for_test());
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_worker_not_running() {
+        let (sender, receiver) = channel::<Notification>(1);
+        drop(receiver);
+
+        let error = enqueue(&sender, &notification_for_test()).unwrap_err();
+
+        assert!(error.to_string().contains("not running"));
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use tracing::{error, info};
+use application::use_case::banks::apply_due_bank_status_changes_use_case::ApplyDueBankStatusChangesUseCase;
+
+// Applies the scheduled bank status changes as they come due. The first check runs right away so
+// changes that came due while the service was down are not delayed.
+pub fn spawn_bank_status_scheduler<AU>(apply_due_bank_status_changes_use_case: Arc<AU>,
+                                       check_interval: Duration)
+where
+    AU: ApplyDueBankStatusChangesUseCase + Send + Sync + 'static
+{
+    rocket::tokio::spawn(async move {
+        let mut interval = rocket::tokio::time::interval(check_interval);
+        loop {
+            interval.tick().await;
+            match apply_due_bank_status_changes_use_case.process(Utc::now()).await {
+                Ok(0) => {},
+                Ok(applied_changes) => info!("Applied {} scheduled bank status changes",
+                                             applied_changes),
+                Err(err) => error!("Unable to apply scheduled bank status changes. Error is: {:?}",
+                                   err),
+            }
+        }
+    });
+}
\ No newline at end of file
@@ -8,32 +8,196 @@ use rocket::{State, http::Status, serde::json::Json};
 use sqlx::mysql::{MySqlPool, MySqlPoolOptions};
 use rocket::figment::Figment;
 use tracing_subscriber::FmtSubscriber;
-use tracing::{info, Level};
+use tracing::{error, info, Level};
+use application::port::db::commerces::alias_type_repository_port::AliasTypeRepositoryPort;
+use application::service::banks::apply_due_bank_status_changes_service::ApplyDueBankStatusChangesService;
+use application::service::banks::bank_code_format::{configure_bank_code_format, BankCodeFormat};
+use application::service::banks::change_bank_status_service::ChangeBankStatusService;
+use application::service::banks::create_bank_service::CreateBankService;
+use application::service::banks::get_bank_service::GetBankService;
+use application::service::banks::list_bank_certificates_service::ListBankCertificatesService;
+use application::service::banks::list_banks_service::ListBanksService;
+use application::service::banks::schedule_bank_status_change_service::ScheduleBankStatusChangeService;
+use application::service::banks::update_bank_service::UpdateBankService;
+use application::service::banks::upload_bank_certificate_service::UploadBankCertificateService;
+use application::service::commerces::add_commerce_account_service::AddCommerceAccountService;
+use application::service::commerces::alias_type_catalog::{configure_alias_type_catalog, AliasTypeCatalog};
+use application::service::commerces::authorize_bank_commerce_access_service::AuthorizeBankCommerceAccessService;
+use application::service::commerces::change_commerce_account_service::ChangeCommerceAccountService;
+use application::service::commerces::change_commerce_status_service::ChangeCommerceStatusService;
+use application::service::commerces::create_commerce_branch_service::CreateCommerceBranchService;
 use application::service::commerces::create_commerce_service::CreateCommerceService;
+use application::service::commerces::get_commerce_service::GetCommerceService;
+use application::service::commerces::get_commerce_status_history_service::GetCommerceStatusHistoryService;
+use application::service::commerces::list_commerce_branches_service::ListCommerceBranchesService;
+use application::service::commerces::list_commerces_service::ListCommercesService;
+use application::service::commerces::promote_commerce_account_service::PromoteCommerceAccountService;
+use application::service::commerces::remove_commerce_account_service::RemoveCommerceAccountService;
+use application::service::commerces::resolve_creditor_alias_service::ResolveCreditorAliasService;
+use application::service::commerces::update_commerce_service::UpdateCommerceService;
 use application::service::commerces::validate_commerce_to_store_service::ValidateCommerceToStoreService;
+use application::service::commerces::validate_commerce_to_update_service::ValidateCommerceToUpdateService;
+use application::service::notifications::notify_bank_service::NotifyBankService;
+use application::service::stats::get_bank_stats_service::GetBankStatsService;
+use application::service::users::check_debtor_status_service::CheckDebtorStatusService;
+use application::service::users::create_user_service::CreateUserService;
+use application::service::users::validate_user_to_store_service::ValidateUserToStoreService;
 
+use domain::model::bank::Bank;
+use domain::model::bank_certificate::BankCertificate;
+use domain::model::bank_status_schedule::BankStatusSchedule;
 use domain::model::commerce::Commerce;
+use domain::model::commerce_status_history::CommerceStatusHistory;
+use domain::model::creditor_user_info::CreditorUserInfo;
+use domain::model::debtor_user_info::DebtorUserInfo;
 use domain::model::generic_response::GenericResponse;
+use domain::model::user::User;
+use infrastructure::cache::banks::cached_bank_repository_adapter::CachedBankRepositoryAdapter;
+use infrastructure::certificates::openssl_certificate_parser_adapter::OpensslCertificateParserAdapter;
 use infrastructure::db::mysql::banks::adapter::bank_repository_adapter::BankRepositoryAdapter;
 use infrastructure::db::mysql::banks::repository::bank_repository::SqlxBankRepository;
+use infrastructure::db::mysql::commerces::adapter::alias_type_repository_adapter::AliasTypeRepositoryAdapter;
 use infrastructure::db::mysql::commerces::adapter::commerce_repository_adapter::CommerceRepositoryAdapter;
 use infrastructure::db::mysql::commerces::repository::account_repository::SqlxAccountRepository;
+use infrastructure::db::mysql::commerces::repository::alias_type_repository::SqlxAliasTypeRepository;
 use infrastructure::db::mysql::commerces::repository::commerce_repository::SqlxCommerceRepository;
 use infrastructure::db::mysql::commerces::repository::commerce_status_repository::SqlxCommerceStatusRepository;
+use infrastructure::db::mysql::commerces::repository::commerce_status_history_repository::SqlxCommerceStatusHistoryRepository;
+use infrastructure::db::mysql::commerces::repository::legal_entity_repository::SqlxLegalEntityRepository;
+use infrastructure::db::mysql::countries::adapter::country_repository_adapter::CountryRepositoryAdapter;
+use infrastructure::db::mysql::countries::repository::country_repository::SqlxCountryRepository;
+use infrastructure::db::mysql::stats::adapter::bank_stats_repository_adapter::BankStatsRepositoryAdapter;
+use infrastructure::db::mysql::stats::repository::bank_stats_repository::SqlxBankStatsRepository;
+use infrastructure::db::mysql::users::adapter::user_repository_adapter::UserRepositoryAdapter;
+use infrastructure::db::mysql::users::repository::user_repository::SqlxUserRepository;
+use infrastructure::entrypoint::aliases::alias_controller::AliasController;
+use infrastructure::entrypoint::banks::bank_certificate_controller::BankCertificateController;
+use infrastructure::entrypoint::banks::bank_controller::BankController;
+use infrastructure::entrypoint::banks::dto::bank_certificate_upload_dto::BankCertificateUploadDto;
+use infrastructure::entrypoint::banks::dto::bank_dto::BankDto;
+use infrastructure::entrypoint::banks::dto::bank_status_schedule_dto::BankStatusScheduleDto;
+use infrastructure::entrypoint::commerces::commerce_account_controller::CommerceAccountController;
+use infrastructure::entrypoint::commerces::commerce_branch_controller::CommerceBranchController;
 use infrastructure::entrypoint::commerces::commerce_controller::CommerceController;
+use infrastructure::entrypoint::commerces::dto::account_dto::AccountDto;
 use infrastructure::entrypoint::commerces::dto::commerce_dto::CommerceDto;
+use infrastructure::entrypoint::commerces::dto::commerce_filter_dto::CommerceFilterDto;
+use infrastructure::entrypoint::commerces::dto::commerce_list_dto::CommerceListDto;
+use infrastructure::entrypoint::commerces::dto::commerce_status_change_dto::CommerceStatusChangeDto;
+use infrastructure::entrypoint::guards::acting_client::ActingClient;
+use infrastructure::entrypoint::guards::calling_bank::{BankApiCredentials, CallingBank};
+use infrastructure::entrypoint::guards::operator::{Operator, OperatorApiCredentials};
+use infrastructure::entrypoint::stats::dto::bank_stats_filter_dto::BankStatsFilterDto;
+use infrastructure::entrypoint::stats::stats_controller::{BankStatsResponse, StatsController};
+use infrastructure::entrypoint::users::dto::status_debtor_user_info_dto::StatusDebtorUserInfoDto;
+use infrastructure::entrypoint::users::dto::user_dto::UserDto;
+use infrastructure::entrypoint::users::user_controller::UserController;
+use infrastructure::notifications::log_notification_adapter::LogNotificationAdapter;
+use infrastructure::notifications::notification_adapter::NotificationAdapter;
+use infrastructure::notifications::smtp_notification_adapter::{SmtpConfig, SmtpNotificationAdapter};
+use infrastructure::scheduler::banks::bank_status_scheduler::spawn_bank_status_scheduler;
 
-type BankRepo = BankRepositoryAdapter;
+const DEFAULT_BANK_CATALOG_REFRESH_SECONDS: u64 = 300;
+const DEFAULT_SMTP_PORT: u16 = 587;
+const DEFAULT_BANK_STATUS_SCHEDULER_SECONDS: u64 = 60;
+
+type BankRepo = CachedBankRepositoryAdapter<BankRepositoryAdapter>;
 type CommerceRepo = CommerceRepositoryAdapter;
+type CertificateParser = OpensslCertificateParserAdapter;
+type StatsRepo = BankStatsRepositoryAdapter;
+type UserRepo = UserRepositoryAdapter;
+type CountryRepo = CountryRepositoryAdapter;
+
+type NotifyService = NotifyBankService<BankRepo, NotificationAdapter>;
 
 type ValidateService = ValidateCommerceToStoreService<BankRepo, CommerceRepo>;
 
-type CreateService = CreateCommerceService<ValidateService, CommerceRepo>;
+type CreateService = CreateCommerceService<ValidateService, CommerceRepo, NotifyService>;
+
+type GetService = GetCommerceService<CommerceRepo>;
+
+type ListService = ListCommercesService<CommerceRepo>;
+
+type ValidateUpdateService = ValidateCommerceToUpdateService<CommerceRepo>;
+
+type UpdateService = UpdateCommerceService<ValidateUpdateService, CommerceRepo>;
+
+type ChangeStatusService = ChangeCommerceStatusService<BankRepo, CommerceRepo, NotifyService>;
+
+type StatusHistoryService = GetCommerceStatusHistoryService<CommerceRepo>;
+
+type ChangeAccountService = ChangeCommerceAccountService<BankRepo, CommerceRepo, NotifyService>;
+
+type AddAccountService = AddCommerceAccountService<BankRepo, CommerceRepo>;
+
+type RemoveAccountService = RemoveCommerceAccountService<CommerceRepo>;
+
+type PromoteAccountService = PromoteCommerceAccountService<BankRepo, CommerceRepo>;
+
+type CreateBranchService = CreateCommerceBranchService<ValidateService, CommerceRepo, NotifyService>;
+
+type ListBranchesService = ListCommerceBranchesService<CommerceRepo>;
+
+type AuthorizeBankAccessService = AuthorizeBankCommerceAccessService<CommerceRepo>;
+
+type ResolveAliasService = ResolveCreditorAliasService<BankRepo, CommerceRepo>;
+
+type BankCreateService = CreateBankService<BankRepo>;
+
+type BankGetService = GetBankService<BankRepo>;
+
+type BankListService = ListBanksService<BankRepo>;
+
+type BankUpdateService = UpdateBankService<BankRepo>;
+
+type BankChangeStatusService = ChangeBankStatusService<BankRepo>;
+
+type BankScheduleStatusService = ScheduleBankStatusChangeService<BankRepo>;
 
-type AppCommerceController = CommerceController<CreateService>;
+type BankUploadCertificateService = UploadBankCertificateService<BankRepo, CertificateParser>;
+
+type BankListCertificatesService = ListBankCertificatesService<BankRepo>;
+
+type BankStatsService = GetBankStatsService<StatsRepo>;
+
+type ValidateUserService = ValidateUserToStoreService<BankRepo, UserRepo, CountryRepo>;
+
+type CreateUserAppService = CreateUserService<ValidateUserService, UserRepo>;
+
+type CheckDebtorService = CheckDebtorStatusService<UserRepo, CountryRepo>;
+
+type AppCommerceController = CommerceController<CreateService, GetService, ListService,
+    UpdateService, ChangeStatusService, StatusHistoryService, ChangeAccountService,
+    AuthorizeBankAccessService>;
+
+type AppCommerceAccountController = CommerceAccountController<AddAccountService,
+    RemoveAccountService, PromoteAccountService, AuthorizeBankAccessService>;
+
+type AppCommerceBranchController = CommerceBranchController<CreateBranchService,
+    ListBranchesService, AuthorizeBankAccessService>;
+
+type AppAliasController = AliasController<ResolveAliasService>;
+
+type AppBankController = BankController<BankCreateService, BankGetService,
+    BankListService, BankUpdateService, BankChangeStatusService, BankScheduleStatusService>;
+
+type AppBankCertificateController = BankCertificateController<BankUploadCertificateService,
+    BankListCertificatesService>;
+
+type AppStatsController = StatsController<BankStatsService>;
+
+type AppUserController = UserController<CreateUserAppService, CheckDebtorService,
+    AuthorizeBankAccessService>;
 
 struct AppState {
-    commerce_controller: AppCommerceController
+    commerce_controller: AppCommerceController,
+    commerce_account_controller: AppCommerceAccountController,
+    commerce_branch_controller: AppCommerceBranchController,
+    alias_controller: AppAliasController,
+    bank_controller: AppBankController,
+    bank_certificate_controller: AppBankCertificateController,
+    stats_controller: AppStatsController,
+    user_controller: AppUserController
 }
 
 async fn create_db_pool(figment: &Figment) -> Result<MySqlPool, sqlx::Error> {
@@ -53,6 +217,56 @@ async fn create_db_pool(figment: &Figment) -> Result<MySqlPool, sqlx::Error> {
         .await
 }
 
+fn bank_catalog_refresh_interval() -> Duration {
+    let seconds = env::var("BANK_CATALOG_REFRESH_SECONDS")
+        .ok()
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_BANK_CATALOG_REFRESH_SECONDS);
+    Duration::from_secs(seconds)
+}
+
+fn bank_status_scheduler_interval() -> Duration {
+    let seconds = env::var("BANK_STATUS_SCHEDULER_SECONDS")
+        .ok()
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_BANK_STATUS_SCHEDULER_SECONDS);
+    Duration::from_secs(seconds)
+}
+
+// Alias types come from the alias_type table, the built-in ones are kept when it cannot be read
+async fn configure_alias_types(alias_type_repository: &AliasTypeRepositoryAdapter) {
+    match alias_type_repository.find_alias_types().await {
+        Ok(alias_types) => {
+            info!("Loaded {} alias types", alias_types.len());
+            let alias_type_catalog = AliasTypeCatalog::new(alias_types)
+                .expect("alias_type table contains an invalid validation pattern");
+            configure_alias_type_catalog(alias_type_catalog);
+        },
+        Err(err) => error!("Alias types could not be loaded at startup. Error is: {:?}", err)
+    }
+}
+
+// Emails go through SMTP when SMTP_HOST is set, otherwise they are only written to the log
+fn create_notification_adapter() -> NotificationAdapter {
+    let Ok(host) = env::var("SMTP_HOST") else {
+        info!("SMTP_HOST is not set, bank notifications will only be logged");
+        return NotificationAdapter::Log(LogNotificationAdapter::new());
+    };
+    let smtp_config = SmtpConfig {
+        host,
+        port: env::var("SMTP_PORT")
+            .ok()
+            .and_then(|port| port.parse::<u16>().ok())
+            .unwrap_or(DEFAULT_SMTP_PORT),
+        username: env::var("SMTP_USERNAME").ok(),
+        password: env::var("SMTP_PASSWORD").ok(),
+        from: env::var("SMTP_FROM").expect("SMTP_FROM configuration missing"),
+        starttls: env::var("SMTP_STARTTLS").map(|starttls| starttls != "false").unwrap_or(true),
+    };
+    NotificationAdapter::Smtp(SmtpNotificationAdapter::new(smtp_config)
+        .expect("SMTP configuration is not valid"))
+}
+
 impl AppState {
     async fn new() -> Self {
         let figment = rocket::Config::figment();
@@ -65,26 +279,47 @@ impl AppState {
 
         let pool_arc = Arc::from(pool);
 
+        configure_alias_types(&AliasTypeRepositoryAdapter::new(Arc::from(
+            SqlxAliasTypeRepository::new(pool_arc.clone())))).await;
+
         let account_repo = SqlxAccountRepository::new(pool_arc.clone());
-        let bank_repo = SqlxBankRepository::new(pool_arc.clone());
         let commerce_status_repo = SqlxCommerceStatusRepository::new(
             pool_arc.clone());
         let account_repo_arc = Arc::from(account_repo);
-        let bank_repo_arc = Arc::from(bank_repo);
+        let commerce_status_history_repo = SqlxCommerceStatusHistoryRepository::new(
+            pool_arc.clone());
         let commerce_status_repo_arc = Arc::from(commerce_status_repo);
+        let commerce_status_history_repo_arc = Arc::from(commerce_status_history_repo);
+        let bank_repo = SqlxBankRepository::new(pool_arc.clone(),
+                                                commerce_status_history_repo_arc.clone());
+        let bank_repo_arc = Arc::from(bank_repo);
+        let legal_entity_repo = SqlxLegalEntityRepository::new(pool_arc.clone());
+        let legal_entity_repo_arc = Arc::from(legal_entity_repo);
 
         let commerce_repo = SqlxCommerceRepository::new(pool_arc.clone(),
-        account_repo_arc.clone(), bank_repo_arc.clone(), commerce_status_repo_arc.clone());
+        account_repo_arc.clone(), commerce_status_repo_arc.clone(),
+        commerce_status_history_repo_arc.clone(), legal_entity_repo_arc.clone());
         let commerce_repo_arc = Arc::from(commerce_repo);
 
-        let bank_repository_adapter = BankRepositoryAdapter::new(
+        let sql_bank_repository_adapter = BankRepositoryAdapter::new(
             bank_repo_arc.clone());
-        let bank_repository_adapter_arc = Arc::from(bank_repository_adapter);
+        let bank_repository_adapter_arc = Arc::from(CachedBankRepositoryAdapter::new(
+            Arc::from(sql_bank_repository_adapter)));
+        if let Err(err) = bank_repository_adapter_arc.refresh().await {
+            error!("Bank catalog could not be loaded at startup. Error is: {:?}", err);
+        }
+        bank_repository_adapter_arc.clone().spawn_refresh(bank_catalog_refresh_interval());
         let commerce_repository_adapter = CommerceRepositoryAdapter::new(
-            commerce_repo_arc.clone());
+            commerce_repo_arc.clone(), account_repo_arc.clone(),
+            commerce_status_history_repo_arc.clone(), legal_entity_repo_arc.clone());
         let commerce_repository_adapter_arc = Arc::from(
             commerce_repository_adapter);
 
+        let notify_bank_use_case = NotifyBankService::new(
+            bank_repository_adapter_arc.clone(),
+            Arc::from(create_notification_adapter()));
+        let notify_bank_use_case_arc = Arc::from(notify_bank_use_case);
+
         let validate_commerce_to_store_use_case = ValidateCommerceToStoreService::new(
             bank_repository_adapter_arc.clone(),
             commerce_repository_adapter_arc.clone(),
@@ -96,23 +331,399 @@ impl AppState {
         let create_commerce_use_case = CreateCommerceService::new(
             validate_commerce_to_store_service_arc.clone(),
             commerce_repository_adapter_arc.clone(),
+            notify_bank_use_case_arc.clone(),
         );
         let create_commerce_use_case_arc = Arc::from(
             create_commerce_use_case);
 
+        let get_commerce_use_case = GetCommerceService::new(
+            commerce_repository_adapter_arc.clone());
+        let get_commerce_use_case_arc = Arc::from(get_commerce_use_case);
+
+        let list_commerces_use_case = ListCommercesService::new(
+            commerce_repository_adapter_arc.clone());
+        let list_commerces_use_case_arc = Arc::from(list_commerces_use_case);
+
+        let validate_commerce_to_update_use_case = ValidateCommerceToUpdateService::new(
+            commerce_repository_adapter_arc.clone());
+        let validate_commerce_to_update_service_arc = Arc::from(
+            validate_commerce_to_update_use_case);
+
+        let update_commerce_use_case = UpdateCommerceService::new(
+            validate_commerce_to_update_service_arc.clone(),
+            commerce_repository_adapter_arc.clone(),
+        );
+        let update_commerce_use_case_arc = Arc::from(update_commerce_use_case);
+
+        let change_commerce_status_use_case = ChangeCommerceStatusService::new(
+            bank_repository_adapter_arc.clone(), commerce_repository_adapter_arc.clone(),
+            notify_bank_use_case_arc.clone());
+        let change_commerce_status_use_case_arc = Arc::from(change_commerce_status_use_case);
+
+        let get_commerce_status_history_use_case = GetCommerceStatusHistoryService::new(
+            commerce_repository_adapter_arc.clone());
+        let get_commerce_status_history_use_case_arc = Arc::from(
+            get_commerce_status_history_use_case);
+
+        let change_commerce_account_use_case = ChangeCommerceAccountService::new(
+            bank_repository_adapter_arc.clone(),
+            commerce_repository_adapter_arc.clone(),
+            notify_bank_use_case_arc.clone(),
+        );
+        let change_commerce_account_use_case_arc = Arc::from(change_commerce_account_use_case);
+
+        let authorize_bank_commerce_access_use_case = AuthorizeBankCommerceAccessService::new(
+            commerce_repository_adapter_arc.clone());
+        let authorize_bank_commerce_access_use_case_arc = Arc::from(
+            authorize_bank_commerce_access_use_case);
+
         let commerce_controller = CommerceController::new(
-            create_commerce_use_case_arc.clone());
+            create_commerce_use_case_arc.clone(), get_commerce_use_case_arc.clone(),
+            list_commerces_use_case_arc.clone(), update_commerce_use_case_arc.clone(),
+            change_commerce_status_use_case_arc.clone(),
+            get_commerce_status_history_use_case_arc.clone(),
+            change_commerce_account_use_case_arc.clone(),
+            authorize_bank_commerce_access_use_case_arc.clone());
+
+        let add_commerce_account_use_case = AddCommerceAccountService::new(
+            bank_repository_adapter_arc.clone(),
+            commerce_repository_adapter_arc.clone(),
+        );
+        let add_commerce_account_use_case_arc = Arc::from(add_commerce_account_use_case);
+
+        let remove_commerce_account_use_case = RemoveCommerceAccountService::new(
+            commerce_repository_adapter_arc.clone());
+        let remove_commerce_account_use_case_arc = Arc::from(remove_commerce_account_use_case);
+
+        let promote_commerce_account_use_case = PromoteCommerceAccountService::new(
+            bank_repository_adapter_arc.clone(),
+            commerce_repository_adapter_arc.clone(),
+        );
+        let promote_commerce_account_use_case_arc = Arc::from(promote_commerce_account_use_case);
+
+        let commerce_account_controller = CommerceAccountController::new(
+            add_commerce_account_use_case_arc.clone(), remove_commerce_account_use_case_arc.clone(),
+            promote_commerce_account_use_case_arc.clone(),
+            authorize_bank_commerce_access_use_case_arc.clone());
+
+        let create_commerce_branch_use_case = CreateCommerceBranchService::new(
+            validate_commerce_to_store_service_arc.clone(),
+            commerce_repository_adapter_arc.clone(),
+            notify_bank_use_case_arc.clone(),
+        );
+        let create_commerce_branch_use_case_arc = Arc::from(create_commerce_branch_use_case);
+
+        let list_commerce_branches_use_case = ListCommerceBranchesService::new(
+            commerce_repository_adapter_arc.clone());
+        let list_commerce_branches_use_case_arc = Arc::from(list_commerce_branches_use_case);
+
+        let commerce_branch_controller = CommerceBranchController::new(
+            create_commerce_branch_use_case_arc.clone(),
+            list_commerce_branches_use_case_arc.clone(),
+            authorize_bank_commerce_access_use_case_arc.clone());
+
+        let resolve_creditor_alias_use_case = ResolveCreditorAliasService::new(
+            bank_repository_adapter_arc.clone(),
+            commerce_repository_adapter_arc.clone());
+        let resolve_creditor_alias_use_case_arc = Arc::from(
+            resolve_creditor_alias_use_case);
+
+        let alias_controller = AliasController::new(
+            resolve_creditor_alias_use_case_arc.clone());
+
+        let create_bank_use_case = CreateBankService::new(bank_repository_adapter_arc.clone());
+        let create_bank_use_case_arc = Arc::from(create_bank_use_case);
+
+        let get_bank_use_case = GetBankService::new(bank_repository_adapter_arc.clone());
+        let get_bank_use_case_arc = Arc::from(get_bank_use_case);
+
+        let list_banks_use_case = ListBanksService::new(bank_repository_adapter_arc.clone());
+        let list_banks_use_case_arc = Arc::from(list_banks_use_case);
+
+        let update_bank_use_case = UpdateBankService::new(bank_repository_adapter_arc.clone());
+        let update_bank_use_case_arc = Arc::from(update_bank_use_case);
+
+        let change_bank_status_use_case = ChangeBankStatusService::new(
+            bank_repository_adapter_arc.clone());
+        let change_bank_status_use_case_arc = Arc::from(change_bank_status_use_case);
+
+        let schedule_bank_status_change_use_case = ScheduleBankStatusChangeService::new(
+            bank_repository_adapter_arc.clone());
+        let schedule_bank_status_change_use_case_arc = Arc::from(
+            schedule_bank_status_change_use_case);
+
+        let apply_due_bank_status_changes_use_case = ApplyDueBankStatusChangesService::new(
+            bank_repository_adapter_arc.clone(), change_bank_status_use_case_arc.clone());
+        spawn_bank_status_scheduler(Arc::from(apply_due_bank_status_changes_use_case),
+                                    bank_status_scheduler_interval());
+
+        let bank_controller = BankController::new(
+            create_bank_use_case_arc.clone(), get_bank_use_case_arc.clone(),
+            list_banks_use_case_arc.clone(), update_bank_use_case_arc.clone(),
+            change_bank_status_use_case_arc.clone(),
+            schedule_bank_status_change_use_case_arc.clone());
+
+        let certificate_parser_arc = Arc::from(OpensslCertificateParserAdapter::new());
+
+        let upload_bank_certificate_use_case = UploadBankCertificateService::new(
+            bank_repository_adapter_arc.clone(), certificate_parser_arc.clone());
+        let upload_bank_certificate_use_case_arc = Arc::from(upload_bank_certificate_use_case);
+
+        let list_bank_certificates_use_case = ListBankCertificatesService::new(
+            bank_repository_adapter_arc.clone());
+        let list_bank_certificates_use_case_arc = Arc::from(list_bank_certificates_use_case);
+
+        let bank_certificate_controller = BankCertificateController::new(
+            upload_bank_certificate_use_case_arc.clone(),
+            list_bank_certificates_use_case_arc.clone());
+
+        let bank_stats_repo = SqlxBankStatsRepository::new(pool_arc.clone());
+        let bank_stats_repository_adapter_arc = Arc::from(BankStatsRepositoryAdapter::new(
+            Arc::from(bank_stats_repo)));
+
+        let get_bank_stats_use_case = GetBankStatsService::new(
+            bank_stats_repository_adapter_arc.clone());
+        let get_bank_stats_use_case_arc = Arc::from(get_bank_stats_use_case);
+
+        let stats_controller = StatsController::new(get_bank_stats_use_case_arc.clone());
+
+        let user_repo = SqlxUserRepository::new(pool_arc.clone(), account_repo_arc.clone());
+        let user_repository_adapter_arc = Arc::from(UserRepositoryAdapter::new(
+            Arc::from(user_repo)));
+
+        let country_repo = SqlxCountryRepository::new(pool_arc.clone());
+        let country_repository_adapter_arc = Arc::from(CountryRepositoryAdapter::new(
+            Arc::from(country_repo)));
+
+        let validate_user_to_store_use_case = ValidateUserToStoreService::new(
+            bank_repository_adapter_arc.clone(), user_repository_adapter_arc.clone(),
+            country_repository_adapter_arc.clone());
+        let validate_user_to_store_use_case_arc = Arc::from(validate_user_to_store_use_case);
+
+        let create_user_use_case = CreateUserService::new(
+            validate_user_to_store_use_case_arc.clone(), user_repository_adapter_arc.clone());
+        let create_user_use_case_arc = Arc::from(create_user_use_case);
+
+        let check_debtor_status_use_case = CheckDebtorStatusService::new(
+            user_repository_adapter_arc.clone(), country_repository_adapter_arc.clone());
+        let check_debtor_status_use_case_arc = Arc::from(check_debtor_status_use_case);
+
+        let user_controller = UserController::new(create_user_use_case_arc.clone(),
+                                                  check_debtor_status_use_case_arc.clone(),
+                                                  authorize_bank_commerce_access_use_case_arc.clone());
 
         Self {
-            commerce_controller
+            commerce_controller,
+            commerce_account_controller,
+            commerce_branch_controller,
+            alias_controller,
+            bank_controller,
+            bank_certificate_controller,
+            stats_controller,
+            user_controller
         }
 
     }
 }
 #[post("/commerces", format = "json", data = "<commerce>")]
-async fn store_commerce(state: &State<AppState>, commerce: Json<CommerceDto>)
+async fn store_commerce(state: &State<AppState>, commerce: Json<CommerceDto>,
+                        calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.create_commerce(commerce.into_inner(), calling_bank).await
+}
+
+#[get("/commerces?<filter..>")]
+async fn list_commerces(state: &State<AppState>, filter: CommerceFilterDto)
+    -> Result<(Status, Json<CommerceListDto>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.list_commerces(filter).await
+}
+
+#[get("/commerces/<commerce_id>")]
+async fn get_commerce(state: &State<AppState>, commerce_id: i64)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.get_commerce(commerce_id).await
+}
+
+#[patch("/commerces/<commerce_id>", format = "json", data = "<commerce>")]
+async fn update_commerce(state: &State<AppState>, commerce_id: i64, commerce: Json<CommerceDto>,
+                         calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.update_commerce(commerce_id, commerce.into_inner(), calling_bank).await
+}
+
+#[put("/commerces/<commerce_id>/account", format = "json", data = "<account>")]
+async fn change_commerce_account(state: &State<AppState>, commerce_id: i64, account: Json<AccountDto>,
+                                 calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.change_commerce_account(commerce_id, account.into_inner(), calling_bank)
+        .await
+}
+
+#[post("/commerces/<commerce_id>/accounts", format = "json", data = "<account>")]
+async fn add_commerce_account(state: &State<AppState>, commerce_id: i64, account: Json<AccountDto>,
+                              calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_account_controller.add_commerce_account(commerce_id, account.into_inner(),
+                                                           calling_bank).await
+}
+
+#[delete("/commerces/<commerce_id>/accounts/<account_id>")]
+async fn remove_commerce_account(state: &State<AppState>, commerce_id: i64, account_id: i64,
+                                 calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_account_controller.remove_commerce_account(commerce_id, account_id, calling_bank)
+        .await
+}
+
+#[post("/commerces/<commerce_id>/accounts/<account_id>/primary")]
+async fn promote_commerce_account(state: &State<AppState>, commerce_id: i64, account_id: i64,
+                                  calling_bank: CallingBank)
     -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
-    state.commerce_controller.create_commerce(commerce.into_inner()).await
+    state.commerce_account_controller.promote_commerce_account(commerce_id, account_id, calling_bank)
+        .await
+}
+
+#[post("/commerces/<commerce_id>/branches", format = "json", data = "<commerce>")]
+async fn create_commerce_branch(state: &State<AppState>, commerce_id: i64, commerce: Json<CommerceDto>,
+                                calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_branch_controller.create_commerce_branch(commerce_id, commerce.into_inner(),
+                                                            calling_bank).await
+}
+
+#[get("/commerces/<commerce_id>/branches")]
+async fn list_commerce_branches(state: &State<AppState>, commerce_id: i64)
+    -> Result<(Status, Json<Vec<Commerce>>), (Status, Json<GenericResponse>)> {
+    state.commerce_branch_controller.list_commerce_branches(commerce_id).await
+}
+
+#[post("/commerces/<commerce_id>/deactivate", format = "json", data = "<status_change>")]
+async fn deactivate_commerce(state: &State<AppState>, commerce_id: i64,
+                             status_change: Json<CommerceStatusChangeDto>, acting_client: ActingClient,
+                             calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.deactivate_commerce(commerce_id, status_change.into_inner(),
+                                                  acting_client, calling_bank).await
+}
+
+#[post("/commerces/<commerce_id>/reactivate", format = "json", data = "<status_change>")]
+async fn reactivate_commerce(state: &State<AppState>, commerce_id: i64,
+                             status_change: Json<CommerceStatusChangeDto>, acting_client: ActingClient,
+                             calling_bank: CallingBank)
+    -> Result<(Status, Json<Commerce>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.reactivate_commerce(commerce_id, status_change.into_inner(),
+                                                  acting_client, calling_bank).await
+}
+
+#[get("/commerces/<commerce_id>/history")]
+async fn get_commerce_status_history(state: &State<AppState>, commerce_id: i64)
+    -> Result<(Status, Json<Vec<CommerceStatusHistory>>), (Status, Json<GenericResponse>)> {
+    state.commerce_controller.get_commerce_status_history(commerce_id).await
+}
+
+#[get("/aliases/<alias>")]
+async fn resolve_alias(state: &State<AppState>, alias: String)
+    -> Result<(Status, Json<CreditorUserInfo>), (Status, Json<GenericResponse>)> {
+    state.alias_controller.resolve_alias(alias).await
+}
+
+#[post("/banks", format = "json", data = "<bank>")]
+async fn create_bank(state: &State<AppState>, bank: Json<BankDto>, _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.create_bank(bank.into_inner()).await
+}
+
+#[get("/banks")]
+async fn list_banks(state: &State<AppState>)
+    -> Result<(Status, Json<Vec<Bank>>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.list_banks().await
+}
+
+#[get("/banks/<bank_code>")]
+async fn get_bank(state: &State<AppState>, bank_code: String)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.get_bank(bank_code).await
+}
+
+#[put("/banks/<bank_code>", format = "json", data = "<bank>")]
+async fn update_bank(state: &State<AppState>, bank_code: String, bank: Json<BankDto>,
+                     _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.update_bank(bank_code, bank.into_inner()).await
+}
+
+#[post("/banks/<bank_code>/disable")]
+async fn disable_bank(state: &State<AppState>, bank_code: String, acting_client: ActingClient,
+                      _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.disable_bank(bank_code, acting_client).await
+}
+
+#[post("/banks/<bank_code>/enable")]
+async fn enable_bank(state: &State<AppState>, bank_code: String, acting_client: ActingClient,
+                     _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.enable_bank(bank_code, acting_client).await
+}
+
+#[post("/banks/<bank_code>/certify")]
+async fn certify_bank(state: &State<AppState>, bank_code: String, acting_client: ActingClient,
+                      _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.certify_bank(bank_code, acting_client).await
+}
+
+#[post("/banks/<bank_code>/offboard")]
+async fn offboard_bank(state: &State<AppState>, bank_code: String, acting_client: ActingClient,
+                       _operator: Operator)
+    -> Result<(Status, Json<Bank>), (Status, Json<GenericResponse>)> {
+    state.bank_controller.offboard_bank(bank_code, acting_client).await
+}
+
+#[post("/banks/<bank_code>/status-schedules", format = "json", data = "<schedule>")]
+async fn schedule_bank_status_change(state: &State<AppState>, bank_code: String,
+                                     schedule: Json<BankStatusScheduleDto>,
+                                     acting_client: ActingClient, _operator: Operator)
+    -> Result<(Status, Json<BankStatusSchedule>), (Status, Json<GenericResponse>)> {
+    state.bank_controller
+        .schedule_bank_status_change(bank_code, schedule.into_inner(), acting_client)
+        .await
+}
+
+#[put("/banks/<bank_code>/certificate", format = "json", data = "<certificate>")]
+async fn upload_bank_certificate(state: &State<AppState>, bank_code: String,
+                                 certificate: Json<BankCertificateUploadDto>,
+                                 calling_bank: CallingBank)
+    -> Result<(Status, Json<BankCertificate>), (Status, Json<GenericResponse>)> {
+    state.bank_certificate_controller
+        .upload_bank_certificate(bank_code, certificate.into_inner(), calling_bank)
+        .await
+}
+
+#[get("/banks/<bank_code>/certificates")]
+async fn list_bank_certificates(state: &State<AppState>, bank_code: String,
+                                calling_bank: CallingBank)
+    -> Result<(Status, Json<Vec<BankCertificate>>), (Status, Json<GenericResponse>)> {
+    state.bank_certificate_controller.list_bank_certificates(bank_code, calling_bank).await
+}
+
+#[get("/stats/banks?<filter..>")]
+async fn get_bank_stats(state: &State<AppState>, filter: BankStatsFilterDto)
+    -> Result<(Status, BankStatsResponse), (Status, Json<GenericResponse>)> {
+    state.stats_controller.get_bank_stats(filter).await
+}
+
+#[post("/users", format = "json", data = "<user>")]
+async fn store_user(state: &State<AppState>, user: Json<UserDto>, calling_bank: CallingBank)
+    -> Result<(Status, Json<User>), (Status, Json<GenericResponse>)> {
+    state.user_controller.create_user(user.into_inner(), calling_bank).await
+}
+
+#[post("/status/debtor", format = "json", data = "<status_debtor>")]
+async fn check_debtor_status(state: &State<AppState>, status_debtor: Json<StatusDebtorUserInfoDto>,
+                             calling_bank: CallingBank)
+    -> Result<(Status, Json<DebtorUserInfo>), (Status, Json<GenericResponse>)> {
+    state.user_controller.check_debtor_status(status_debtor.into_inner(), calling_bank).await
 }
 
 #[launch]
@@ -129,12 +740,35 @@ async fn rocket() -> _ {
     info!("Initializing app...");
 
 
+    if let Ok(bank_code_pattern) = env::var("BANK_CODE_PATTERN") {
+        let bank_code_format = BankCodeFormat::new(&bank_code_pattern)
+            .expect("BANK_CODE_PATTERN is not a valid regular expression");
+        configure_bank_code_format(bank_code_format);
+        info!("Bank codes validated with pattern {}", bank_code_pattern);
+    }
+
     let state = AppState::new().await;
     let config = rocket::Config::figment()
         .merge(("port", 8008))
         .merge(("address", "0.0.0.0"));
 
+    // Entries in the form "api_key:bank_code" separated by commas
+    let bank_api_credentials = BankApiCredentials::from_config(
+        &env::var("BANK_API_CREDENTIALS").unwrap_or_default());
+    // Entries in the form "api_key:operator" separated by commas
+    let operator_api_credentials = OperatorApiCredentials::from_config(
+        &env::var("OPERATOR_API_CREDENTIALS").unwrap_or_default());
+
     rocket::custom(config)
         .manage(state)
-        .mount("/api", routes![store_commerce])
+        .manage(bank_api_credentials)
+        .manage(operator_api_credentials)
+        .mount("/api", routes![store_commerce, list_commerces, get_commerce, update_commerce,
+            change_commerce_account, add_commerce_account, remove_commerce_account,
+            promote_commerce_account, create_commerce_branch, list_commerce_branches,
+            deactivate_commerce, reactivate_commerce, get_commerce_status_history, resolve_alias,
+            create_bank, list_banks, get_bank, update_bank, disable_bank, enable_bank,
+            certify_bank, offboard_bank, schedule_bank_status_change, upload_bank_certificate,
+            list_bank_certificates, get_bank_stats, store_user,
+            check_debtor_status])
 }